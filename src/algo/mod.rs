@@ -0,0 +1,7 @@
+pub mod mos_algorithm;
+pub mod order_statistics;
+pub mod range_kth;
+
+pub use self::mos_algorithm::{mo_block_size, mos_algorithm};
+pub use self::order_statistics::{count_less_than, range_kth_smallest};
+pub use self::range_kth::RangeKth;
@@ -0,0 +1,204 @@
+//! This module contains Mo's algorithm for answering offline
+//! range queries by moving a window over the array.
+
+/// Return the block size used to order the queries of Mo's
+/// algorithm over an array of length `n`: about `sqrt(n)`.
+///
+/// # Examples
+/// ```
+/// use ralgo::algo::mo_block_size;
+/// assert_eq!(mo_block_size(100), 10);
+/// assert_eq!(mo_block_size(0), 1);
+/// ```
+pub fn mo_block_size(n: usize) -> usize {
+    n.isqrt().max(1)
+}
+
+/// Answer range queries `[lo, hi]` (inclusive) over an array of
+/// length `n` by maintaining a state for a sliding window. The
+/// window is grown with `add(state, index)` and shrunk with
+/// `remove(state, index)`; the answer to a query is built from the
+/// state once the window matches its range. Answers are returned in
+/// the order of `queries`.
+///
+/// The queries are sorted by the block of their left end and then by
+/// their right end (alternating direction between blocks), so the
+/// window moves O((n + q) * sqrt(n)) times in total.
+///
+/// # Panics
+///
+/// If a query has `lo > hi` or `hi >= n`.
+///
+/// # Examples
+/// ```
+/// use ralgo::algo::mos_algorithm;
+///
+/// struct Sum(i64);
+/// impl From<&i64> for Sum {
+///     fn from(state: &i64) -> Sum {
+///         Sum(*state)
+///     }
+/// }
+///
+/// let array = [1, 2, 3, 4, 5];
+/// let sums: Vec<Sum> = mos_algorithm(
+///     array.len(),
+///     &[(0, 4), (1, 2), (3, 3)],
+///     0,
+///     |sum: &mut i64, i| *sum += array[i],
+///     |sum: &mut i64, i| *sum -= array[i],
+/// );
+/// assert_eq!(sums.iter().map(|s| s.0).collect::<Vec<_>>(), vec![15, 5, 4]);
+/// ```
+pub fn mos_algorithm<S, Q>(
+    n: usize,
+    queries: &[(usize, usize)],
+    initial_state: S,
+    add: impl Fn(&mut S, usize),
+    remove: impl Fn(&mut S, usize),
+) -> Vec<Q>
+where
+    Q: for<'a> From<&'a S>,
+{
+    for &(lo, hi) in queries {
+        assert!(lo <= hi && hi < n, "invalid query [{}, {}]", lo, hi);
+    }
+    let block = mo_block_size(n);
+    let mut order: Vec<usize> = (0..queries.len()).collect();
+    order.sort_by_key(|&i| {
+        let (lo, hi) = queries[i];
+        let b = lo / block;
+        (b, if b.is_multiple_of(2) { hi } else { n - hi })
+    });
+
+    let mut state = initial_state;
+    let mut answers: Vec<Option<Q>> = (0..queries.len()).map(|_| None).collect();
+    // The current window is `[lo, hi)`.
+    let (mut lo, mut hi) = (0, 0);
+    for i in order {
+        let (q_lo, q_hi) = queries[i];
+        while hi <= q_hi {
+            add(&mut state, hi);
+            hi += 1;
+        }
+        while lo > q_lo {
+            lo -= 1;
+            add(&mut state, lo);
+        }
+        while hi > q_hi + 1 {
+            hi -= 1;
+            remove(&mut state, hi);
+        }
+        while lo < q_lo {
+            remove(&mut state, lo);
+            lo += 1;
+        }
+        answers[i] = Some(Q::from(&state));
+    }
+    answers.into_iter().map(|a| a.unwrap()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mo_block_size, mos_algorithm};
+    use graph::random::XorShift;
+    use std::cell::Cell;
+
+    struct Distinct {
+        count: Vec<usize>,
+        distinct: usize,
+    }
+
+    impl From<&Distinct> for usize {
+        fn from(state: &Distinct) -> usize {
+            state.distinct
+        }
+    }
+
+    struct Xor(i64);
+
+    impl From<&i64> for Xor {
+        fn from(state: &i64) -> Xor {
+            Xor(*state)
+        }
+    }
+
+    fn random_queries(rng: &mut XorShift, n: usize, q: usize) -> Vec<(usize, usize)> {
+        (0..q)
+            .map(|_| {
+                let (a, b) = (rng.next_below(n), rng.next_below(n));
+                (a.min(b), a.max(b))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn distinct_counts_match_naive() {
+        let mut rng = XorShift::new(83);
+        let array: Vec<usize> = (0..200).map(|_| rng.next_below(20)).collect();
+        let queries = random_queries(&mut rng, array.len(), 300);
+        let answers: Vec<usize> = mos_algorithm(
+            array.len(),
+            &queries,
+            Distinct {
+                count: vec![0; 20],
+                distinct: 0,
+            },
+            |s: &mut Distinct, i| {
+                s.count[array[i]] += 1;
+                if s.count[array[i]] == 1 {
+                    s.distinct += 1;
+                }
+            },
+            |s: &mut Distinct, i| {
+                s.count[array[i]] -= 1;
+                if s.count[array[i]] == 0 {
+                    s.distinct -= 1;
+                }
+            },
+        );
+        for (&(lo, hi), &answer) in queries.iter().zip(answers.iter()) {
+            let mut values = array[lo..=hi].to_vec();
+            values.sort();
+            values.dedup();
+            assert_eq!(answer, values.len());
+        }
+    }
+
+    #[test]
+    fn range_xor_works() {
+        let array = [5, 1, 7, 3, 3, 9];
+        let queries = [(0, 5), (2, 4), (3, 4), (5, 5), (0, 0)];
+        let answers: Vec<Xor> = mos_algorithm(
+            array.len(),
+            &queries,
+            0,
+            |s: &mut i64, i| *s ^= array[i],
+            |s: &mut i64, i| *s ^= array[i],
+        );
+        let answers: Vec<i64> = answers.iter().map(|x| x.0).collect();
+        assert_eq!(answers, vec![5 ^ 1 ^ 7 ^ 9, 7, 0, 9, 5]);
+
+        let none: Vec<Xor> = mos_algorithm(0, &[], 0, |_: &mut i64, _| {}, |_: &mut i64, _| {});
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn window_moves_are_bounded() {
+        let mut rng = XorShift::new(89);
+        let (n, q) = (2000, 2000);
+        let queries = random_queries(&mut rng, n, q);
+        let moves = Cell::new(0usize);
+        let _: Vec<Xor> = mos_algorithm(
+            n,
+            &queries,
+            0,
+            |_: &mut i64, _| moves.set(moves.get() + 1),
+            |_: &mut i64, _| moves.set(moves.get() + 1),
+        );
+        let bound = 2 * (n + q) * mo_block_size(n);
+        assert!(moves.get() <= bound, "{} > {}", moves.get(), bound);
+        // A naive scan would move about n / 3 per query.
+        assert!(moves.get() < q * n / 3);
+    }
+}
@@ -0,0 +1,163 @@
+//! This module contains order statistics queries over
+//! subarrays of an integer array.
+
+use data_structures::Fenwick;
+use sort::mergesort_clone;
+
+/// Answer offline queries `(lo, hi, x)`: how many elements of
+/// `array[lo..=hi]` are strictly less than `x`. Answers are returned
+/// in the order of `queries`.
+///
+/// Every query is split into two prefix queries. The prefixes are
+/// processed by increasing length while a Fenwick tree over the
+/// compressed values counts the elements seen so far, so the total
+/// time is O((n + q) log n).
+///
+/// # Panics
+///
+/// If a query has `lo > hi` or `hi >= array.len()`.
+///
+/// # Examples
+/// ```
+/// use ralgo::algo::count_less_than;
+/// let array = [5, 1, 4, 2, 3];
+/// assert_eq!(count_less_than(&array, &[(0, 4, 3), (1, 3, 5), (2, 2, 4)]), vec![2, 3, 0]);
+/// ```
+pub fn count_less_than(array: &[i64], queries: &[(usize, usize, i64)]) -> Vec<usize> {
+    for &(lo, hi, _) in queries {
+        assert!(
+            lo <= hi && hi < array.len(),
+            "invalid query [{}, {}]",
+            lo,
+            hi
+        );
+    }
+    let mut values = array.to_vec();
+    values.sort_unstable();
+    values.dedup();
+
+    // Events `(prefix length, query index, sign)`.
+    let mut events = Vec::with_capacity(2 * queries.len());
+    for (i, &(lo, hi, _)) in queries.iter().enumerate() {
+        events.push((lo, i, -1));
+        events.push((hi + 1, i, 1));
+    }
+    events.sort_unstable();
+
+    let mut fenwick = Fenwick::new(values.len());
+    let mut counts = vec![0i64; queries.len()];
+    let mut len = 0;
+    for (prefix, i, sign) in events {
+        while len < prefix {
+            let rank = values.partition_point(|&v| v < array[len]);
+            fenwick.add(rank, 1);
+            len += 1;
+        }
+        let below = values.partition_point(|&v| v < queries[i].2);
+        counts[i] += sign * fenwick.prefix_sum(below);
+    }
+    counts.into_iter().map(|c| c as usize).collect()
+}
+
+/// Return the `k`-th smallest element of `array[lo..=hi]`, counting
+/// from 0 as `RangeKth::kth_smallest` does: `k = 0` gives the minimum
+/// of the range and `k = hi - lo` its maximum.
+///
+/// This is the naive one-shot method: the range is copied and sorted
+/// with mergesort, in O(m log m) time and O(m) memory for a range of
+/// length `m`, and nothing is kept between calls. To answer many
+/// queries on the same array, preprocess it once with `RangeKth`.
+///
+/// # Panics
+///
+/// If `lo > hi`, `hi >= array.len()` or `k > hi - lo`.
+///
+/// # Examples
+/// ```
+/// use ralgo::algo::range_kth_smallest;
+/// let array = [5, 1, 4, 2, 3];
+/// assert_eq!(range_kth_smallest(&array, 0, 2, 1), 4);
+/// assert_eq!(range_kth_smallest(&array, 1, 4, 3), 4);
+/// ```
+pub fn range_kth_smallest(array: &[i64], lo: usize, hi: usize, k: usize) -> i64 {
+    assert!(
+        lo <= hi && hi < array.len(),
+        "invalid range [{}, {}]",
+        lo,
+        hi
+    );
+    assert!(
+        k <= hi - lo,
+        "k = {} is out of range for a range of length {}",
+        k,
+        hi - lo + 1
+    );
+    let mut range = array[lo..=hi].to_vec();
+    mergesort_clone(&mut range);
+    range[k]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_less_than, range_kth_smallest};
+    use algo::RangeKth;
+    use graph::random::XorShift;
+
+    #[test]
+    fn count_less_than_matches_naive() {
+        let mut rng = XorShift::new(131);
+        for &n in [1, 2, 7, 40].iter() {
+            let array: Vec<i64> = (0..n).map(|_| rng.next_below(20) as i64 - 10).collect();
+            let queries: Vec<(usize, usize, i64)> = (0..200)
+                .map(|_| {
+                    let (a, b) = (rng.next_below(n), rng.next_below(n));
+                    (a.min(b), a.max(b), rng.next_below(24) as i64 - 12)
+                })
+                .collect();
+            let expected: Vec<usize> = queries
+                .iter()
+                .map(|&(lo, hi, x)| array[lo..=hi].iter().filter(|&&v| v < x).count())
+                .collect();
+            assert_eq!(count_less_than(&array, &queries), expected);
+        }
+        assert_eq!(count_less_than(&[], &[]), vec![]);
+    }
+
+    #[test]
+    fn kth_smallest_extremes_work() {
+        let mut rng = XorShift::new(137);
+        let array: Vec<i64> = (0..30).map(|_| rng.next_below(100) as i64 - 50).collect();
+        for _ in 0..100 {
+            let (a, b) = (rng.next_below(30), rng.next_below(30));
+            let (lo, hi) = (a.min(b), a.max(b));
+            let range = &array[lo..=hi];
+            assert_eq!(
+                range_kth_smallest(&array, lo, hi, 0),
+                *range.iter().min().unwrap()
+            );
+            assert_eq!(
+                range_kth_smallest(&array, lo, hi, hi - lo),
+                *range.iter().max().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn kth_smallest_matches_naive() {
+        let array = [3, 3, -1, 7, 0, 3, 9, 2];
+        let mut range = array[1..=6].to_vec();
+        range.sort();
+        let kth = RangeKth::new(&array);
+        for (k, &x) in range.iter().enumerate() {
+            assert_eq!(range_kth_smallest(&array, 1, 6, k), x);
+            assert_eq!(kth.kth_smallest(1, 6, k), x);
+        }
+        assert_eq!(range_kth_smallest(&array, 4, 4, 0), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn kth_smallest_rejects_k_past_the_range() {
+        range_kth_smallest(&[1, 2, 3], 0, 2, 3);
+    }
+}
@@ -0,0 +1,139 @@
+//! This module contains range k-th smallest queries answered
+//! online with a persistent segment tree.
+
+use data_structures::PersistentSegTree;
+
+/// Preprocessed array answering "the k-th smallest element of
+/// `array[lo..=hi]`" queries. Version `i` of a persistent segment
+/// tree over the compressed values counts the occurrences of every
+/// value among the first `i` elements, so the counts for a range are
+/// the difference of two versions and a binary search over the values
+/// finds the k-th smallest one.
+///
+/// Preprocessing takes O(n log n) time and memory, a query takes
+/// O(log² n).
+///
+/// # Examples
+/// ```
+/// use ralgo::algo::RangeKth;
+/// let kth = RangeKth::new(&[5, 1, 4, 2, 3]);
+/// assert_eq!(kth.kth_smallest(0, 4, 0), 1);
+/// assert_eq!(kth.kth_smallest(0, 2, 1), 4);
+/// assert_eq!(kth.kth_smallest(2, 4, 2), 4);
+/// ```
+pub struct RangeKth {
+    values: Vec<i64>,
+    counts: PersistentSegTree,
+}
+
+impl RangeKth {
+    /// Preprocess `array` for queries.
+    pub fn new(array: &[i64]) -> RangeKth {
+        let mut values = array.to_vec();
+        values.sort_unstable();
+        values.dedup();
+
+        let mut counts = PersistentSegTree::new(&vec![0; values.len()]);
+        for (version, x) in array.iter().enumerate() {
+            let rank = values.binary_search(x).unwrap();
+            let count = counts.query(version, rank, rank);
+            counts.update(version, rank, count + 1);
+        }
+        RangeKth { values, counts }
+    }
+
+    /// Return the `k`-th smallest element of `array[lo..=hi]`,
+    /// counting from 0: `k = 0` gives the minimum of the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `lo` - the first index of the range;
+    /// * `hi` - the last index of the range;
+    /// * `k` - the rank of the element within the range.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi`, `hi` is out of range or `k > hi - lo`.
+    ///
+    pub fn kth_smallest(&self, lo: usize, hi: usize, k: usize) -> i64 {
+        assert!(
+            lo <= hi && hi + 1 < self.counts.n_versions(),
+            "invalid range [{}, {}]",
+            lo,
+            hi
+        );
+        assert!(
+            k <= hi - lo,
+            "k = {} is out of range for a range of length {}",
+            k,
+            hi - lo + 1
+        );
+        let count_up_to =
+            |rank: usize| self.counts.query(hi + 1, 0, rank) - self.counts.query(lo, 0, rank);
+        // The answer is the smallest rank with more than `k` elements
+        // of the range not greater than it.
+        let (mut left, mut right) = (0, self.values.len() - 1);
+        while left < right {
+            let mid = (left + right) / 2;
+            if count_up_to(mid) > k as i64 {
+                right = mid;
+            } else {
+                left = mid + 1;
+            }
+        }
+        self.values[left]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeKth;
+    use graph::random::XorShift;
+
+    #[test]
+    fn whole_array_minimum_works() {
+        let array = [7, -3, 12, 0, -3, 5];
+        let kth = RangeKth::new(&array);
+        let mut sorted = array.to_vec();
+        sorted.sort();
+        assert_eq!(kth.kth_smallest(0, array.len() - 1, 0), sorted[0]);
+        for (k, &x) in sorted.iter().enumerate() {
+            assert_eq!(kth.kth_smallest(0, array.len() - 1, k), x);
+        }
+    }
+
+    #[test]
+    fn uniform_array_works() {
+        let kth = RangeKth::new(&[4; 9]);
+        for lo in 0..9 {
+            for hi in lo..9 {
+                for k in 0..=hi - lo {
+                    assert_eq!(kth.kth_smallest(lo, hi, k), 4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_queries_match_sorted_subarray() {
+        let mut rng = XorShift::new(197);
+        for &n in [1, 2, 10, 60].iter() {
+            let array: Vec<i64> = (0..n).map(|_| rng.next_below(30) as i64 - 15).collect();
+            let kth = RangeKth::new(&array);
+            for _ in 0..300 {
+                let (a, b) = (rng.next_below(n), rng.next_below(n));
+                let (lo, hi) = (a.min(b), a.max(b));
+                let mut range = array[lo..=hi].to_vec();
+                range.sort();
+                let k = rng.next_below(range.len());
+                assert_eq!(kth.kth_smallest(lo, hi, k), range[k]);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn k_out_of_bounds_panics() {
+        RangeKth::new(&[3, 1, 2]).kth_smallest(0, 1, 2);
+    }
+}
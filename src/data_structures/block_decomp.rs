@@ -0,0 +1,159 @@
+//! This module defines an array split into blocks of about
+//! `sqrt(n)` elements, which supports range updates and range
+//! sums in O(sqrt(n)) time.
+
+use std::ops::Range;
+
+/// The array of integers decomposed into blocks. Every block keeps
+/// the sum of its elements and a pending addition that applies to
+/// all of them, so a range is handled element by element only at
+/// its partially covered ends.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::BlockArray;
+/// let mut array = BlockArray::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// array.range_update(2, 7, 10);
+/// array.point_update(0, -1);
+/// assert_eq!(array.range_sum(0, 8), 45 + 60 - 1);
+/// assert_eq!(array.range_sum(7, 8), 18 + 9);
+/// ```
+pub struct BlockArray {
+    data: Vec<i64>,
+    block: usize,
+    sum: Vec<i64>,
+    pending: Vec<i64>,
+}
+
+impl BlockArray {
+    /// Return a new instance of BlockArray holding `data`.
+    pub fn new(data: &[i64]) -> BlockArray {
+        let block = data.len().isqrt().max(1);
+        let sum = data.chunks(block).map(|c| c.iter().sum()).collect();
+        BlockArray {
+            data: data.to_vec(),
+            block,
+            sum,
+            pending: vec![0; data.len().div_ceil(block)],
+        }
+    }
+
+    /// Return the sum of the elements with indices in `[lo, hi]`
+    /// in O(sqrt(n)).
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_sum(&self, lo: usize, hi: usize) -> i64 {
+        let (head, blocks, tail) = self.split(lo, hi);
+        head.chain(tail)
+            .map(|i| self.data[i] + self.pending[i / self.block])
+            .sum::<i64>()
+            + blocks.map(|b| self.sum[b]).sum::<i64>()
+    }
+
+    /// Add `delta` to the element at `index` in O(1).
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of range.
+    ///
+    pub fn point_update(&mut self, index: usize, delta: i64) {
+        self.data[index] += delta;
+        self.sum[index / self.block] += delta;
+    }
+
+    /// Add `delta` to all elements with indices in `[lo, hi]`
+    /// in O(sqrt(n)).
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_update(&mut self, lo: usize, hi: usize, delta: i64) {
+        let (head, blocks, tail) = self.split(lo, hi);
+        for i in head.chain(tail) {
+            self.point_update(i, delta);
+        }
+        for b in blocks {
+            let len = self.block.min(self.data.len() - b * self.block);
+            self.pending[b] += delta;
+            self.sum[b] += delta * len as i64;
+        }
+    }
+
+    /// Split `[lo, hi]` into the elements before the first fully
+    /// covered block, the fully covered blocks and the elements
+    /// after them.
+    fn split(&self, lo: usize, hi: usize) -> (Range<usize>, Range<usize>, Range<usize>) {
+        assert!(lo <= hi, "empty range [{}, {}]", lo, hi);
+        assert!(hi < self.data.len(), "index {} is out of range", hi);
+        let first = lo.div_ceil(self.block);
+        let end = (hi + 1) / self.block;
+        if first >= end {
+            return (lo..hi + 1, 0..0, 0..0);
+        }
+        (lo..first * self.block, first..end, end * self.block..hi + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockArray;
+    use graph::random::XorShift;
+
+    #[test]
+    fn single_element_and_full_range_work() {
+        let data = [4, -2, 7, 0, 3];
+        let mut array = BlockArray::new(&data);
+        assert_eq!(array.range_sum(0, 4), 12);
+        for (i, &x) in data.iter().enumerate() {
+            assert_eq!(array.range_sum(i, i), x);
+        }
+        array.range_update(0, 4, 1);
+        assert_eq!(array.range_sum(0, 4), 17);
+        array.point_update(2, -7);
+        assert_eq!(array.range_sum(2, 2), 1);
+        assert_eq!(BlockArray::new(&[9]).range_sum(0, 0), 9);
+    }
+
+    #[test]
+    fn random_operations_match_naive() {
+        let mut rng = XorShift::new(97);
+        for &n in [1, 2, 10, 37, 100].iter() {
+            let mut naive: Vec<i64> = (0..n).map(|_| rng.next_below(100) as i64).collect();
+            let mut array = BlockArray::new(&naive);
+            for _ in 0..500 {
+                let (a, b) = (rng.next_below(n), rng.next_below(n));
+                let (lo, hi) = (a.min(b), a.max(b));
+                let delta = rng.next_below(21) as i64 - 10;
+                match rng.next_below(3) {
+                    0 => {
+                        array.point_update(lo, delta);
+                        naive[lo] += delta;
+                    }
+                    1 => {
+                        array.range_update(lo, hi, delta);
+                        for x in naive[lo..=hi].iter_mut() {
+                            *x += delta;
+                        }
+                    }
+                    _ => assert_eq!(array.range_sum(lo, hi), naive[lo..=hi].iter().sum()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn accesses_are_sqrt_bounded() {
+        let n = 10_000;
+        let array = BlockArray::new(&vec![1; n]);
+        let mut rng = XorShift::new(101);
+        for _ in 0..1000 {
+            let (a, b) = (rng.next_below(n), rng.next_below(n));
+            let (head, blocks, tail) = array.split(a.min(b), a.max(b));
+            assert!(head.len() + blocks.len() + tail.len() <= 3 * 100);
+        }
+    }
+}
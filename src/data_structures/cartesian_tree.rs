@@ -0,0 +1,131 @@
+//! This module contains the construction of the Cartesian tree
+//! of an array with a monotonic stack in O(n).
+
+/// Return the parent array of the Cartesian tree of `array`:
+/// `parent[i]` is the parent of position `i`, or `None` for the
+/// root. The tree is a binary tree whose in-order traversal gives
+/// the positions `0, 1, ..., n-1` and in which every element is
+/// not greater than its children, so the root is the position of
+/// the minimum. Of equal elements the leftmost is the ancestor.
+///
+/// The stack holds the right spine of the tree built so far. A new
+/// element pops every spine node greater than it, adopts the last
+/// popped one as its left child and becomes the right child of the
+/// remaining top. Every position is pushed and popped once.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::{build_cartesian_tree, cartesian_tree_root};
+/// let parent = build_cartesian_tree(&[3, 2, 6, 1, 9]);
+/// assert_eq!(parent, vec![Some(1), Some(3), Some(1), None, Some(3)]);
+/// assert_eq!(cartesian_tree_root(&parent), 3);
+/// ```
+pub fn build_cartesian_tree(array: &[i64]) -> Vec<Option<usize>> {
+    let mut parent = vec![None; array.len()];
+    let mut stack: Vec<usize> = Vec::new();
+    for (i, &x) in array.iter().enumerate() {
+        let mut last = None;
+        while let Some(&top) = stack.last() {
+            if array[top] <= x {
+                break;
+            }
+            last = stack.pop();
+        }
+        if let Some(child) = last {
+            parent[child] = Some(i);
+        }
+        if let Some(&top) = stack.last() {
+            parent[i] = Some(top);
+        }
+        stack.push(i);
+    }
+    parent
+}
+
+/// Return the root of a tree given by its parent array.
+///
+/// # Panics
+///
+/// If no node is a root.
+///
+pub fn cartesian_tree_root(parent: &[Option<usize>]) -> usize {
+    parent
+        .iter()
+        .position(Option::is_none)
+        .expect("the tree has no root")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_cartesian_tree, cartesian_tree_root};
+    use graph::random::XorShift;
+
+    /// Return the positions in in-order, recovering the children of
+    /// every node from the parent array.
+    fn in_order(parent: &[Option<usize>]) -> Vec<usize> {
+        let n = parent.len();
+        let (mut left, mut right) = (vec![None; n], vec![None; n]);
+        for (i, p) in parent.iter().enumerate() {
+            if let Some(p) = *p {
+                if i < p {
+                    assert!(left[p].is_none());
+                    left[p] = Some(i);
+                } else {
+                    assert!(right[p].is_none());
+                    right[p] = Some(i);
+                }
+            }
+        }
+        let mut order = Vec::new();
+        let mut stack = Vec::new();
+        let mut node = Some(cartesian_tree_root(parent));
+        while node.is_some() || !stack.is_empty() {
+            while let Some(v) = node {
+                stack.push(v);
+                node = left[v];
+            }
+            let v = stack.pop().unwrap();
+            order.push(v);
+            node = right[v];
+        }
+        order
+    }
+
+    #[test]
+    fn known_tree_works() {
+        let parent = build_cartesian_tree(&[3, 2, 6, 1, 9]);
+        assert_eq!(cartesian_tree_root(&parent), 3);
+        assert_eq!(in_order(&parent), vec![0, 1, 2, 3, 4]);
+        assert_eq!(build_cartesian_tree(&[]), vec![]);
+        assert_eq!(build_cartesian_tree(&[7]), vec![None]);
+    }
+
+    #[test]
+    fn monotone_arrays_give_paths() {
+        let parent = build_cartesian_tree(&[1, 2, 3, 4]);
+        assert_eq!(parent, vec![None, Some(0), Some(1), Some(2)]);
+        let parent = build_cartesian_tree(&[4, 3, 2, 1]);
+        assert_eq!(parent, vec![Some(1), Some(2), Some(3), None]);
+        let parent = build_cartesian_tree(&[5, 5, 5]);
+        assert_eq!(parent, vec![None, Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn random_trees_are_heap_ordered() {
+        let mut rng = XorShift::new(167);
+        for _ in 0..100 {
+            let n = 1 + rng.next_below(50);
+            let array: Vec<i64> = (0..n).map(|_| rng.next_below(20) as i64).collect();
+            let parent = build_cartesian_tree(&array);
+            for (i, p) in parent.iter().enumerate() {
+                if let Some(p) = *p {
+                    assert!(array[p] <= array[i]);
+                }
+            }
+            let root = cartesian_tree_root(&parent);
+            assert_eq!(array[root], *array.iter().min().unwrap());
+            assert_eq!(parent.iter().filter(|p| p.is_none()).count(), 1);
+            assert_eq!(in_order(&parent), (0..n).collect::<Vec<_>>());
+        }
+    }
+}
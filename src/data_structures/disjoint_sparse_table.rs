@@ -0,0 +1,144 @@
+//! This module defines the disjoint sparse table, which answers
+//! range queries for any associative operation in O(1).
+
+/// The static range query structure for an associative (but not
+/// necessarily idempotent) operation such as sum or XOR. For every
+/// level `h`, the array is cut into blocks of size `2^(h+1)`, and
+/// each position stores the combination from it to the middle of
+/// its block. Any range whose ends lie in different halves of such
+/// a block is then the combination of two stored values.
+///
+/// Building takes O(n log n) time and space.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::DisjointSparseTable;
+/// let table = DisjointSparseTable::new(&[5, 3, 6, 1, 2], |a, b| a ^ b);
+/// assert_eq!(table.query(1, 3), 3 ^ 6 ^ 1);
+/// assert_eq!(table.query(4, 4), 2);
+/// ```
+pub struct DisjointSparseTable {
+    data: Vec<i64>,
+    table: Vec<Vec<i64>>,
+    combine: fn(i64, i64) -> i64,
+}
+
+impl DisjointSparseTable {
+    /// Return a new instance of DisjointSparseTable.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the array to query;
+    /// * `combine` - an associative operation.
+    ///
+    pub fn new(data: &[i64], combine: fn(i64, i64) -> i64) -> DisjointSparseTable {
+        let n = data.len();
+        let mut table = Vec::new();
+        let mut half = 1;
+        while half < n {
+            let mut level = vec![0; n];
+            for start in (0..n).step_by(2 * half) {
+                let mid = start + half;
+                if mid >= n {
+                    break;
+                }
+                level[mid - 1] = data[mid - 1];
+                for i in (start..mid - 1).rev() {
+                    level[i] = combine(data[i], level[i + 1]);
+                }
+                level[mid] = data[mid];
+                for i in mid + 1..(start + 2 * half).min(n) {
+                    level[i] = combine(level[i - 1], data[i]);
+                }
+            }
+            table.push(level);
+            half *= 2;
+        }
+        DisjointSparseTable {
+            data: data.to_vec(),
+            table,
+            combine,
+        }
+    }
+
+    /// Return the combination of the elements with indices in
+    /// `[lo, hi]`, from left to right. Runs in O(1).
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn query(&self, lo: usize, hi: usize) -> i64 {
+        assert!(lo <= hi, "empty range [{}, {}]", lo, hi);
+        assert!(hi < self.data.len(), "index {} is out of range", hi);
+        if lo == hi {
+            return self.data[lo];
+        }
+        let level = (usize::BITS - 1 - (lo ^ hi).leading_zeros()) as usize;
+        (self.combine)(self.table[level][lo], self.table[level][hi])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisjointSparseTable;
+    use data_structures::Fenwick;
+    use graph::random::XorShift;
+
+    #[test]
+    fn xor_queries_work() {
+        let data = [1, 2, 4, 8, 16, 32, 64];
+        let table = DisjointSparseTable::new(&data, |a, b| a ^ b);
+        assert_eq!(table.query(0, 6), 127);
+        assert_eq!(table.query(2, 5), 60);
+        assert_eq!(table.query(3, 4), 24);
+        assert_eq!(table.query(6, 6), 64);
+    }
+
+    #[test]
+    fn single_element_works() {
+        let table = DisjointSparseTable::new(&[-7], |a, b| a + b);
+        assert_eq!(table.query(0, 0), -7);
+    }
+
+    #[test]
+    fn sums_match_fenwick() {
+        let mut rng = XorShift::new(47);
+        let data: Vec<i64> = (0..37).map(|_| rng.next_below(100) as i64 - 50).collect();
+        let table = DisjointSparseTable::new(&data, |a, b| a + b);
+        let mut fenwick = Fenwick::new(data.len());
+        for (i, &x) in data.iter().enumerate() {
+            fenwick.add(i, x);
+        }
+        for lo in 0..data.len() {
+            for hi in lo..data.len() {
+                assert_eq!(table.query(lo, hi), fenwick.range_sum(lo, hi));
+            }
+        }
+    }
+
+    #[test]
+    fn non_commutative_operation_matches_naive() {
+        // Composition of affine maps x -> a * x + b packed as a * 1000 + b.
+        fn compose(f: i64, g: i64) -> i64 {
+            let (a1, b1) = (f / 1000, f % 1000);
+            let (a2, b2) = (g / 1000, g % 1000);
+            (a1 * a2 % 997) * 1000 + (b1 * a2 + b2) % 997
+        }
+        let mut rng = XorShift::new(53);
+        for n in 1..40 {
+            let data: Vec<i64> = (0..n)
+                .map(|_| rng.next_below(997) as i64 * 1000 + rng.next_below(997) as i64)
+                .collect();
+            let table = DisjointSparseTable::new(&data, compose);
+            for lo in 0..n {
+                for hi in lo..n {
+                    let naive = data[lo + 1..=hi]
+                        .iter()
+                        .fold(data[lo], |acc, &x| compose(acc, x));
+                    assert_eq!(table.query(lo, hi), naive);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! This module defines the Euler tour tree: a forest of rooted
+//! trees stored as bracket sequences in treaps, supporting linking,
+//! cutting and path sums in O(log n) expected time.
+
+use graph::random::XorShift;
+
+const NONE: usize = usize::MAX;
+
+/// A token of the bracket sequence: node `2 v` opens vertex `v`
+/// and node `2 v + 1` closes it. Nodes form treaps keyed by their
+/// position in the sequence.
+#[derive(Clone)]
+struct Node {
+    left: usize,
+    right: usize,
+    parent: usize,
+    priority: u64,
+    size: usize,
+    // The value of the token and the sum over its subtree.
+    value: i64,
+    sum: i64,
+    // The depth change of the token (+1 or -1), the sum over its
+    // subtree, and the smallest prefix depth with the first token
+    // where it is reached.
+    delta: i64,
+    depth: i64,
+    min_depth: i64,
+    min_node: usize,
+}
+
+/// A forest of rooted trees over vertices 0, 1, ..., N-1, each
+/// carrying an integer value. Every tree is kept as its bracket
+/// sequence: a depth-first traversal from the root writing an
+/// opening token when it enters a vertex and a closing token when
+/// it leaves it, so every subtree is a contiguous segment. Linking
+/// inserts one sequence into another and cutting extracts one.
+///
+/// The opening token of `v` carries its value and the closing one
+/// the negated value, so the prefix sum up to the opening of `v` is
+/// the sum over the path from the root to `v`. The ancestor common
+/// to `u` and `v` is the parent of the shallowest vertex closed
+/// between their openings.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::EulerTourTree;
+/// let mut forest = EulerTourTree::new(4);
+/// for v in 0..4 {
+///     forest.set_value(v, 10 * v as i64);
+/// }
+/// forest.link(1, 0);
+/// forest.link(2, 1);
+/// forest.link(3, 1);
+/// assert_eq!(forest.find_root(3), 0);
+/// assert_eq!(forest.path_sum(2, 3), 20 + 10 + 30);
+/// forest.cut(1, 0);
+/// assert!(!forest.connected(0, 2));
+/// assert_eq!(forest.find_root(2), 1);
+/// ```
+pub struct EulerTourTree {
+    nodes: Vec<Node>,
+    parent: Vec<usize>,
+}
+
+impl EulerTourTree {
+    /// Return a forest of `n` single-vertex trees with zero values.
+    pub fn new(n: usize) -> EulerTourTree {
+        let mut rng = XorShift::new(0);
+        let nodes = (0..2 * n)
+            .map(|x| {
+                let delta = if x % 2 == 0 { 1 } else { -1 };
+                Node {
+                    left: NONE,
+                    right: NONE,
+                    parent: NONE,
+                    priority: rng.next_u64(),
+                    size: 1,
+                    value: 0,
+                    sum: 0,
+                    delta,
+                    depth: delta,
+                    min_depth: delta,
+                    min_node: x,
+                }
+            })
+            .collect();
+        let mut forest = EulerTourTree {
+            nodes,
+            parent: vec![NONE; n],
+        };
+        for v in 0..n {
+            forest.merge(2 * v, 2 * v + 1);
+        }
+        forest
+    }
+
+    /// Set the value of `vert` to `value`.
+    pub fn set_value(&mut self, vert: usize, value: i64) {
+        self.nodes[2 * vert].value = value;
+        self.nodes[2 * vert + 1].value = -value;
+        for x in [2 * vert, 2 * vert + 1].iter() {
+            let mut x = *x;
+            while x != NONE {
+                self.update(x);
+                x = self.nodes[x].parent;
+            }
+        }
+    }
+
+    /// Make `u` a child of `v`.
+    ///
+    /// # Panics
+    ///
+    /// If `u` is not the root of its tree or `u` and `v` are
+    /// already connected.
+    ///
+    pub fn link(&mut self, u: usize, v: usize) {
+        assert_eq!(self.parent[u], NONE, "{} is not a root", u);
+        assert!(
+            !self.connected(u, v),
+            "{} and {} are already connected",
+            u,
+            v
+        );
+        let tree = self.treap_root(2 * v);
+        let (before, after) = self.split(tree, self.position(2 * v) + 1);
+        let inserted = self.merge(before, self.treap_root(2 * u));
+        self.merge(inserted, after);
+        self.parent[u] = v;
+    }
+
+    /// Remove the edge between `u` and `v`; the lower of the two
+    /// becomes the root of a new tree.
+    ///
+    /// # Panics
+    ///
+    /// If `u` and `v` are not adjacent.
+    ///
+    pub fn cut(&mut self, u: usize, v: usize) {
+        let lower = if self.parent[u] == v {
+            u
+        } else if self.parent[v] == u {
+            v
+        } else {
+            panic!("{} and {} are not adjacent", u, v);
+        };
+        let (lo, hi) = (self.position(2 * lower), self.position(2 * lower + 1));
+        let tree = self.treap_root(2 * lower);
+        let (before, rest) = self.split(tree, lo);
+        let (_, after) = self.split(rest, hi - lo + 1);
+        self.merge(before, after);
+        self.parent[lower] = NONE;
+    }
+
+    /// Return the root of the tree containing `vert`.
+    pub fn find_root(&self, vert: usize) -> usize {
+        let mut x = self.treap_root(2 * vert);
+        while self.nodes[x].left != NONE {
+            x = self.nodes[x].left;
+        }
+        x / 2
+    }
+
+    /// Return `true` if `u` and `v` are in the same tree.
+    pub fn connected(&self, u: usize, v: usize) -> bool {
+        self.treap_root(2 * u) == self.treap_root(2 * v)
+    }
+
+    /// Return the sum of the values of the vertices on the path
+    /// between `u` and `v`, both included.
+    ///
+    /// # Panics
+    ///
+    /// If `u` and `v` are not connected.
+    ///
+    pub fn path_sum(&mut self, u: usize, v: usize) -> i64 {
+        assert!(self.connected(u, v), "{} and {} are not connected", u, v);
+        let (mut u, mut v) = (u, v);
+        if self.position(2 * u) > self.position(2 * v) {
+            std::mem::swap(&mut u, &mut v);
+        }
+        let (i, j) = (self.position(2 * u), self.position(2 * v));
+        let lca = if self.position(2 * u + 1) > j {
+            u
+        } else {
+            let tree = self.treap_root(2 * u);
+            let (before, rest) = self.split(tree, i);
+            let (middle, after) = self.split(rest, j - i + 1);
+            let closed = self.nodes[middle].min_node / 2;
+            let rest = self.merge(middle, after);
+            self.merge(before, rest);
+            self.parent[closed]
+        };
+        self.root_path_sum(u) + self.root_path_sum(v) - 2 * self.root_path_sum(lca)
+            + self.nodes[2 * lca].value
+    }
+
+    /// Return the sum of the values on the path from the root to `vert`.
+    fn root_path_sum(&self, vert: usize) -> i64 {
+        let mut x = 2 * vert;
+        let mut sum = self.sum(self.nodes[x].left) + self.nodes[x].value;
+        while self.nodes[x].parent != NONE {
+            let p = self.nodes[x].parent;
+            if self.nodes[p].right == x {
+                sum += self.sum(self.nodes[p].left) + self.nodes[p].value;
+            }
+            x = p;
+        }
+        sum
+    }
+
+    /// Return the root of the treap containing node `x`.
+    fn treap_root(&self, mut x: usize) -> usize {
+        while self.nodes[x].parent != NONE {
+            x = self.nodes[x].parent;
+        }
+        x
+    }
+
+    /// Return the position of node `x` in its sequence.
+    fn position(&self, mut x: usize) -> usize {
+        let mut pos = self.size(self.nodes[x].left);
+        while self.nodes[x].parent != NONE {
+            let p = self.nodes[x].parent;
+            if self.nodes[p].right == x {
+                pos += self.size(self.nodes[p].left) + 1;
+            }
+            x = p;
+        }
+        pos
+    }
+
+    /// Return the number of nodes in treap `x`.
+    fn size(&self, x: usize) -> usize {
+        if x == NONE {
+            0
+        } else {
+            self.nodes[x].size
+        }
+    }
+
+    /// Return the sum of the values in treap `x`.
+    fn sum(&self, x: usize) -> i64 {
+        if x == NONE {
+            0
+        } else {
+            self.nodes[x].sum
+        }
+    }
+
+    /// Recompute the aggregates of `x` from its children.
+    fn update(&mut self, x: usize) {
+        let Node {
+            left, right, delta, ..
+        } = self.nodes[x];
+        let mut size = 1;
+        let mut sum = self.nodes[x].value;
+        let (mut depth, mut min_depth, mut min_node) = (delta, delta, x);
+        if left != NONE {
+            let l = &self.nodes[left];
+            size += l.size;
+            sum += l.sum;
+            depth += l.depth;
+            if l.min_depth <= depth {
+                min_depth = l.min_depth;
+                min_node = l.min_node;
+            } else {
+                min_depth = depth;
+            }
+        }
+        if right != NONE {
+            let r = &self.nodes[right];
+            size += r.size;
+            sum += r.sum;
+            if depth + r.min_depth < min_depth {
+                min_depth = depth + r.min_depth;
+                min_node = r.min_node;
+            }
+            depth += r.depth;
+        }
+        let node = &mut self.nodes[x];
+        node.size = size;
+        node.sum = sum;
+        node.depth = depth;
+        node.min_depth = min_depth;
+        node.min_node = min_node;
+    }
+
+    /// Split treap `x` into its first `k` nodes and the rest.
+    fn split(&mut self, x: usize, k: usize) -> (usize, usize) {
+        if x == NONE {
+            return (NONE, NONE);
+        }
+        self.nodes[x].parent = NONE;
+        let left = self.nodes[x].left;
+        if k <= self.size(left) {
+            let (a, b) = self.split(left, k);
+            self.set_left(x, b);
+            (a, x)
+        } else {
+            let right = self.nodes[x].right;
+            let (a, b) = self.split(right, k - self.size(left) - 1);
+            self.set_right(x, a);
+            (x, b)
+        }
+    }
+
+    /// Concatenate treaps `a` and `b` and return the root.
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NONE {
+            return b;
+        }
+        if b == NONE {
+            return a;
+        }
+        if self.nodes[a].priority > self.nodes[b].priority {
+            let right = self.nodes[a].right;
+            let merged = self.merge(right, b);
+            self.set_right(a, merged);
+            a
+        } else {
+            let left = self.nodes[b].left;
+            let merged = self.merge(a, left);
+            self.set_left(b, merged);
+            b
+        }
+    }
+
+    /// Make `child` the left child of `x` and update `x`.
+    fn set_left(&mut self, x: usize, child: usize) {
+        self.nodes[x].left = child;
+        if child != NONE {
+            self.nodes[child].parent = x;
+        }
+        self.update(x);
+    }
+
+    /// Make `child` the right child of `x` and update `x`.
+    fn set_right(&mut self, x: usize, child: usize) {
+        self.nodes[x].right = child;
+        if child != NONE {
+            self.nodes[child].parent = x;
+        }
+        self.update(x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EulerTourTree;
+    use data_structures::LinkCutTree;
+    use graph::random::XorShift;
+
+    #[test]
+    fn path_link_and_cut_work() {
+        let mut forest = EulerTourTree::new(5);
+        for v in 1..5 {
+            forest.link(v, v - 1);
+        }
+        assert!((0..5).all(|v| forest.find_root(v) == 0));
+        forest.cut(2, 1);
+        assert_eq!(forest.find_root(1), 0);
+        assert_eq!(forest.find_root(2), 2);
+        assert_eq!(forest.find_root(4), 2);
+        assert!(forest.connected(0, 1));
+        assert!(forest.connected(3, 4));
+        assert!(!forest.connected(1, 2));
+        forest.link(2, 0);
+        assert_eq!(forest.find_root(4), 0);
+    }
+
+    #[test]
+    fn chain_path_sums_work() {
+        let n = 10;
+        let mut forest = EulerTourTree::new(n);
+        for v in 0..n {
+            forest.set_value(v, v as i64 + 1);
+        }
+        for v in 1..n {
+            forest.link(v, v - 1);
+        }
+        for u in 0..n {
+            for v in 0..n {
+                let (a, b) = (u.min(v) as i64, u.max(v) as i64);
+                assert_eq!(forest.path_sum(u, v), (a + 1..=b + 1).sum::<i64>());
+            }
+        }
+        forest.set_value(4, -100);
+        assert_eq!(forest.path_sum(3, 5), 4 - 100 + 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn linking_non_root_panics() {
+        let mut forest = EulerTourTree::new(3);
+        forest.link(1, 0);
+        forest.link(1, 2);
+    }
+
+    #[test]
+    fn random_operations_match_link_cut_tree() {
+        let mut rng = XorShift::new(199);
+        let n = 30;
+        let mut forest = EulerTourTree::new(n);
+        let mut reference = LinkCutTree::new(n);
+        let mut parent: Vec<Option<usize>> = vec![None; n];
+        for _ in 0..3000 {
+            let (u, v) = (rng.next_below(n), rng.next_below(n));
+            match rng.next_below(4) {
+                0 => {
+                    let value = rng.next_below(100) as i64 - 50;
+                    forest.set_value(u, value);
+                    reference.set_value(u, value);
+                }
+                1 => {
+                    if parent[u].is_none() && !reference.connected(u, v) {
+                        forest.link(u, v);
+                        reference.link(u, v);
+                        parent[u] = Some(v);
+                    }
+                }
+                2 => {
+                    if let Some(p) = parent[u] {
+                        forest.cut(u, p);
+                        reference.cut(u, p);
+                        parent[u] = None;
+                    }
+                }
+                _ => {
+                    assert_eq!(forest.connected(u, v), reference.connected(u, v));
+                    assert_eq!(forest.find_root(u), reference.find_root(u));
+                    if reference.connected(u, v) {
+                        assert_eq!(forest.path_sum(u, v), reference.path_aggregate(u, v));
+                    }
+                }
+            }
+        }
+    }
+}
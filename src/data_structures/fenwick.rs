@@ -0,0 +1,248 @@
+//! This module defines Fenwick (binary indexed) trees: the basic
+//! one with point updates and prefix sums, and two variants built
+//! on top of it that support adding a value to a whole range.
+
+/// The Fenwick tree over `n` integers, initially zero, supporting
+/// point updates and prefix sums in O(log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::Fenwick;
+/// let mut fenwick = Fenwick::new(5);
+/// fenwick.add(1, 3);
+/// fenwick.add(3, 4);
+/// assert_eq!(fenwick.prefix_sum(2), 3);
+/// assert_eq!(fenwick.range_sum(1, 3), 7);
+/// ```
+pub struct Fenwick {
+    tree: Vec<i64>,
+}
+
+impl Fenwick {
+    /// Return a new Fenwick tree of `n` zeros.
+    pub fn new(n: usize) -> Fenwick {
+        Fenwick {
+            tree: vec![0; n + 1],
+        }
+    }
+
+    /// Return the number of elements.
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// Return `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add `delta` to the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index >= self.len()`.
+    ///
+    pub fn add(&mut self, index: usize, delta: i64) {
+        assert!(index < self.len(), "index {} is out of range", index);
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Return the sum of the first `count` elements.
+    ///
+    /// # Panics
+    ///
+    /// If `count > self.len()`.
+    ///
+    pub fn prefix_sum(&self, count: usize) -> i64 {
+        assert!(count <= self.len(), "count {} is out of range", count);
+        let mut i = count;
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Return the sum of the elements with indices in `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi >= self.len()`.
+    ///
+    pub fn range_sum(&self, lo: usize, hi: usize) -> i64 {
+        assert!(lo <= hi, "empty range [{}, {}]", lo, hi);
+        self.prefix_sum(hi + 1) - self.prefix_sum(lo)
+    }
+}
+
+/// The Fenwick tree over the difference array: adds a value to
+/// a range and reads a single element in O(log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::FenwickRangeUpdate;
+/// let mut fenwick = FenwickRangeUpdate::new(5);
+/// fenwick.range_add(1, 3, 10);
+/// fenwick.range_add(3, 4, 1);
+/// assert_eq!(fenwick.point_query(0), 0);
+/// assert_eq!(fenwick.point_query(3), 11);
+/// ```
+pub struct FenwickRangeUpdate {
+    diff: Fenwick,
+}
+
+impl FenwickRangeUpdate {
+    /// Return a new instance over `n` zeros.
+    pub fn new(n: usize) -> FenwickRangeUpdate {
+        FenwickRangeUpdate {
+            diff: Fenwick::new(n),
+        }
+    }
+
+    /// Add `delta` to all elements with indices in `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_add(&mut self, lo: usize, hi: usize, delta: i64) {
+        assert!(lo <= hi, "empty range [{}, {}]", lo, hi);
+        assert!(hi < self.diff.len(), "index {} is out of range", hi);
+        self.diff.add(lo, delta);
+        if hi + 1 < self.diff.len() {
+            self.diff.add(hi + 1, -delta);
+        }
+    }
+
+    /// Return the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of range.
+    ///
+    pub fn point_query(&self, index: usize) -> i64 {
+        self.diff.prefix_sum(index + 1)
+    }
+}
+
+/// Two Fenwick trees that together support adding a value to
+/// a range and summing a range, both in O(log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::FenwickRangeQuery;
+/// let mut fenwick = FenwickRangeQuery::new(5);
+/// fenwick.range_add(1, 3, 10);
+/// fenwick.range_add(3, 4, 1);
+/// assert_eq!(fenwick.range_sum(0, 4), 32);
+/// assert_eq!(fenwick.range_sum(3, 3), 11);
+/// ```
+pub struct FenwickRangeQuery {
+    // With the difference array `d`, the sum of the first `k`
+    // elements is `k * sum(d[j]) - sum(j * d[j])` over `j < k`.
+    diff: Fenwick,
+    weighted_diff: Fenwick,
+}
+
+impl FenwickRangeQuery {
+    /// Return a new instance over `n` zeros.
+    pub fn new(n: usize) -> FenwickRangeQuery {
+        FenwickRangeQuery {
+            diff: Fenwick::new(n),
+            weighted_diff: Fenwick::new(n),
+        }
+    }
+
+    /// Add `delta` to all elements with indices in `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_add(&mut self, lo: usize, hi: usize, delta: i64) {
+        assert!(lo <= hi, "empty range [{}, {}]", lo, hi);
+        assert!(hi < self.diff.len(), "index {} is out of range", hi);
+        self.diff.add(lo, delta);
+        self.weighted_diff.add(lo, delta * lo as i64);
+        if hi + 1 < self.diff.len() {
+            self.diff.add(hi + 1, -delta);
+            self.weighted_diff.add(hi + 1, -delta * (hi + 1) as i64);
+        }
+    }
+
+    /// Return the sum of the elements with indices in `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_sum(&self, lo: usize, hi: usize) -> i64 {
+        assert!(lo <= hi, "empty range [{}, {}]", lo, hi);
+        self.prefix_sum(hi + 1) - self.prefix_sum(lo)
+    }
+
+    /// Return the sum of the first `count` elements.
+    fn prefix_sum(&self, count: usize) -> i64 {
+        count as i64 * self.diff.prefix_sum(count) - self.weighted_diff.prefix_sum(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fenwick, FenwickRangeQuery, FenwickRangeUpdate};
+    use graph::random::XorShift;
+
+    #[test]
+    fn point_updates_work() {
+        let mut fenwick = Fenwick::new(6);
+        for (i, &x) in [5, -2, 7, 0, 3, 1].iter().enumerate() {
+            fenwick.add(i, x);
+        }
+        assert_eq!(fenwick.prefix_sum(0), 0);
+        assert_eq!(fenwick.prefix_sum(6), 14);
+        assert_eq!(fenwick.range_sum(1, 4), 8);
+        assert_eq!(fenwick.range_sum(5, 5), 1);
+        assert!(Fenwick::new(0).is_empty());
+    }
+
+    #[test]
+    fn range_add_then_point_query_works() {
+        let mut fenwick = FenwickRangeUpdate::new(8);
+        fenwick.range_add(0, 7, 1);
+        fenwick.range_add(2, 5, 10);
+        fenwick.range_add(4, 7, -3);
+        fenwick.range_add(5, 5, 100);
+        let values: Vec<i64> = (0..8).map(|i| fenwick.point_query(i)).collect();
+        assert_eq!(values, vec![1, 1, 11, 11, 8, 108, -2, -2]);
+    }
+
+    #[test]
+    fn range_sums_match_naive() {
+        let mut rng = XorShift::new(37);
+        let n = 50;
+        let mut naive = vec![0i64; n];
+        let mut fenwick = FenwickRangeQuery::new(n);
+        let mut points = FenwickRangeUpdate::new(n);
+        for _ in 0..500 {
+            let a = rng.next_below(n);
+            let b = rng.next_below(n);
+            let (lo, hi) = (a.min(b), a.max(b));
+            if rng.next_below(2) == 0 {
+                let delta = rng.next_below(21) as i64 - 10;
+                fenwick.range_add(lo, hi, delta);
+                points.range_add(lo, hi, delta);
+                for x in naive[lo..=hi].iter_mut() {
+                    *x += delta;
+                }
+            } else {
+                assert_eq!(fenwick.range_sum(lo, hi), naive[lo..=hi].iter().sum());
+                assert_eq!(points.point_query(lo), naive[lo]);
+            }
+        }
+    }
+}
@@ -0,0 +1,333 @@
+//! This module defines the Fibonacci heap: a min-priority queue
+//! with amortized O(1) insert and decrease-key operations.
+
+const NONE: usize = usize::MAX;
+
+/// The handle of an element inserted into a `FibHeap`, used
+/// to decrease its key later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Handle(usize);
+
+/// A node of the heap. Siblings form circular doubly linked lists.
+struct Node<T> {
+    key: i64,
+    value: Option<T>,
+    parent: usize,
+    child: usize,
+    left: usize,
+    right: usize,
+    degree: usize,
+    marked: bool,
+}
+
+/// The min-priority queue of values with integer keys.
+/// Insert and decrease-key take O(1) amortized time,
+/// extract-min takes O(log n) amortized time.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::FibHeap;
+/// let mut heap = FibHeap::new();
+/// heap.insert(5, "five");
+/// let handle = heap.insert(7, "seven");
+/// heap.insert(3, "three");
+/// heap.decrease_key(handle, 1);
+/// assert_eq!(heap.extract_min(), Some((1, "seven")));
+/// assert_eq!(heap.extract_min(), Some((3, "three")));
+/// assert_eq!(heap.len(), 1);
+/// ```
+pub struct FibHeap<T> {
+    nodes: Vec<Node<T>>,
+    min: usize,
+    len: usize,
+}
+
+impl<T> FibHeap<T> {
+    /// Return a new empty heap.
+    pub fn new() -> FibHeap<T> {
+        FibHeap {
+            nodes: Vec::new(),
+            min: NONE,
+            len: 0,
+        }
+    }
+
+    /// Return the number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the heap has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the smallest key and its value without removing them.
+    pub fn peek_min(&self) -> Option<(i64, &T)> {
+        if self.min == NONE {
+            return None;
+        }
+        let node = &self.nodes[self.min];
+        Some((node.key, node.value.as_ref().unwrap()))
+    }
+
+    /// Add `value` with given key and return its handle.
+    pub fn insert(&mut self, key: i64, value: T) -> Handle {
+        let x = self.nodes.len();
+        self.nodes.push(Node {
+            key,
+            value: Some(value),
+            parent: NONE,
+            child: NONE,
+            left: x,
+            right: x,
+            degree: 0,
+            marked: false,
+        });
+        self.add_root(x);
+        self.len += 1;
+        Handle(x)
+    }
+
+    /// Remove and return an element with the smallest key.
+    pub fn extract_min(&mut self) -> Option<(i64, T)> {
+        let z = self.min;
+        if z == NONE {
+            return None;
+        }
+        while self.nodes[z].child != NONE {
+            let c = self.nodes[z].child;
+            self.remove_child(c, z);
+            self.add_root(c);
+        }
+        let next = self.nodes[z].right;
+        self.unlink(z);
+        self.min = if next == z { NONE } else { next };
+        if self.min != NONE {
+            self.consolidate();
+        }
+        self.len -= 1;
+        Some((self.nodes[z].key, self.nodes[z].value.take().unwrap()))
+    }
+
+    /// Lower the key of the element with given handle.
+    ///
+    /// # Panics
+    ///
+    /// If the element was already extracted or `new_key` is
+    /// greater than its current key.
+    ///
+    pub fn decrease_key(&mut self, handle: Handle, new_key: i64) {
+        let x = handle.0;
+        assert!(self.nodes[x].value.is_some(), "element is not in the heap");
+        assert!(new_key <= self.nodes[x].key, "key can not be increased");
+        self.nodes[x].key = new_key;
+        let y = self.nodes[x].parent;
+        if y != NONE && new_key < self.nodes[y].key {
+            self.cut(x, y);
+            self.cascading_cut(y);
+        }
+        if new_key < self.nodes[self.min].key {
+            self.min = x;
+        }
+    }
+
+    /// Remove `x` from its sibling list, leaving it a singleton.
+    fn unlink(&mut self, x: usize) {
+        let (left, right) = (self.nodes[x].left, self.nodes[x].right);
+        self.nodes[left].right = right;
+        self.nodes[right].left = left;
+        self.nodes[x].left = x;
+        self.nodes[x].right = x;
+    }
+
+    /// Insert the singleton `x` into the sibling list of `at`.
+    fn splice(&mut self, at: usize, x: usize) {
+        let right = self.nodes[at].right;
+        self.nodes[x].left = at;
+        self.nodes[x].right = right;
+        self.nodes[right].left = x;
+        self.nodes[at].right = x;
+    }
+
+    /// Put the singleton `x` into the root list.
+    fn add_root(&mut self, x: usize) {
+        self.nodes[x].parent = NONE;
+        self.nodes[x].marked = false;
+        if self.min == NONE {
+            self.min = x;
+        } else {
+            self.splice(self.min, x);
+            if self.nodes[x].key < self.nodes[self.min].key {
+                self.min = x;
+            }
+        }
+    }
+
+    /// Detach the child `x` from its parent `y`.
+    fn remove_child(&mut self, x: usize, y: usize) {
+        if self.nodes[x].right == x {
+            self.nodes[y].child = NONE;
+        } else if self.nodes[y].child == x {
+            self.nodes[y].child = self.nodes[x].right;
+        }
+        self.unlink(x);
+        self.nodes[y].degree -= 1;
+    }
+
+    /// Merge the roots until all of them have distinct degrees.
+    fn consolidate(&mut self) {
+        let mut roots = vec![self.min];
+        let mut x = self.nodes[self.min].right;
+        while x != self.min {
+            roots.push(x);
+            x = self.nodes[x].right;
+        }
+
+        let mut by_degree: Vec<usize> = Vec::new();
+        for mut x in roots {
+            self.unlink(x);
+            let mut degree = self.nodes[x].degree;
+            while degree < by_degree.len() && by_degree[degree] != NONE {
+                let mut y = by_degree[degree];
+                by_degree[degree] = NONE;
+                if self.nodes[y].key < self.nodes[x].key {
+                    std::mem::swap(&mut x, &mut y);
+                }
+                // Make `y` a child of `x`.
+                match self.nodes[x].child {
+                    NONE => self.nodes[x].child = y,
+                    child => self.splice(child, y),
+                }
+                self.nodes[y].parent = x;
+                self.nodes[y].marked = false;
+                self.nodes[x].degree += 1;
+                degree += 1;
+            }
+            if degree >= by_degree.len() {
+                by_degree.resize(degree + 1, NONE);
+            }
+            by_degree[degree] = x;
+        }
+
+        self.min = NONE;
+        for x in by_degree {
+            if x != NONE {
+                self.add_root(x);
+            }
+        }
+    }
+
+    /// Move `x` from the children of `y` to the root list.
+    fn cut(&mut self, x: usize, y: usize) {
+        self.remove_child(x, y);
+        self.add_root(x);
+    }
+
+    /// Cut marked ancestors of `y` that have lost a second child.
+    fn cascading_cut(&mut self, mut y: usize) {
+        loop {
+            let z = self.nodes[y].parent;
+            if z == NONE {
+                return;
+            }
+            if !self.nodes[y].marked {
+                self.nodes[y].marked = true;
+                return;
+            }
+            self.cut(y, z);
+            y = z;
+        }
+    }
+}
+
+impl<T> Default for FibHeap<T> {
+    fn default() -> FibHeap<T> {
+        FibHeap::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FibHeap;
+    use graph::random::XorShift;
+
+    #[test]
+    fn basic_operations_work() {
+        let mut heap = FibHeap::new();
+        assert_eq!(heap.extract_min(), None);
+        for &key in [5, 3, 8, 1, 9, 2].iter() {
+            heap.insert(key, key * 10);
+        }
+        assert_eq!(heap.peek_min(), Some((1, &10)));
+        let keys: Vec<i64> = std::iter::from_fn(|| heap.extract_min().map(|(k, _)| k)).collect();
+        assert_eq!(keys, vec![1, 2, 3, 5, 8, 9]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn decrease_key_makes_new_min() {
+        let mut heap = FibHeap::new();
+        let handles: Vec<_> = (0..20).map(|i| heap.insert(100 + i, i)).collect();
+        // Extracting builds trees, so later decreases cut non-root nodes.
+        assert_eq!(heap.extract_min(), Some((100, 0)));
+        heap.decrease_key(handles[17], 50);
+        assert_eq!(heap.peek_min(), Some((50, &17)));
+        heap.decrease_key(handles[9], 50);
+        heap.decrease_key(handles[12], -1);
+        assert_eq!(heap.extract_min(), Some((-1, 12)));
+        let (key, _) = heap.extract_min().unwrap();
+        assert_eq!(key, 50);
+        let (key, _) = heap.extract_min().unwrap();
+        assert_eq!(key, 50);
+        assert_eq!(heap.extract_min(), Some((101, 1)));
+        assert_eq!(heap.len(), 15);
+    }
+
+    #[test]
+    #[should_panic]
+    fn increasing_key_panics() {
+        let mut heap = FibHeap::new();
+        let handle = heap.insert(1, ());
+        heap.decrease_key(handle, 2);
+    }
+
+    #[test]
+    fn random_operations_match_sorted_vec() {
+        let mut rng = XorShift::new(73);
+        let mut heap = FibHeap::new();
+        let mut handles = Vec::new();
+        let mut keys: Vec<Option<i64>> = Vec::new();
+        for _ in 0..3000 {
+            match rng.next_below(3) {
+                0 => {
+                    let key = rng.next_below(1000) as i64;
+                    handles.push(heap.insert(key, keys.len()));
+                    keys.push(Some(key));
+                }
+                1 => {
+                    let expected = keys.iter().filter_map(|&k| k).min();
+                    match heap.extract_min() {
+                        None => assert_eq!(expected, None),
+                        Some((key, index)) => {
+                            assert_eq!(Some(key), expected);
+                            assert_eq!(keys[index], Some(key));
+                            keys[index] = None;
+                        }
+                    }
+                }
+                _ => {
+                    if !keys.is_empty() {
+                        let index = rng.next_below(keys.len());
+                        if let Some(key) = keys[index] {
+                            let new_key = key - rng.next_below(100) as i64;
+                            heap.decrease_key(handles[index], new_key);
+                            keys[index] = Some(new_key);
+                        }
+                    }
+                }
+            }
+            assert_eq!(heap.len(), keys.iter().filter(|k| k.is_some()).count());
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! This module defines a static centered interval tree over
+//! closed integer intervals.
+
+/// A node of the tree: the intervals containing `center`,
+/// once sorted by start and once by end.
+struct Node {
+    center: i64,
+    by_start: Vec<(i64, i64, usize)>,
+    by_end: Vec<(i64, i64, usize)>,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+/// The centered interval tree over closed intervals `[start, end]`
+/// tagged with ids. Every node stores the intervals containing its
+/// center, which is the median of the endpoints below it; intervals
+/// entirely to the left or to the right of the center go to the
+/// corresponding subtree. The tree has depth O(log n) and a query
+/// reporting `m` intervals runs in O(log n + m), not counting the
+/// sort of the result.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::IntervalTree;
+/// let tree = IntervalTree::new(vec![(1, 5, 0), (4, 8, 1), (10, 12, 2)]);
+/// assert_eq!(tree.stabbing_query(4), vec![0, 1]);
+/// assert_eq!(tree.stabbing_query(9), vec![]);
+/// assert_eq!(tree.overlap_query(6, 10), vec![1, 2]);
+/// ```
+pub struct IntervalTree {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+impl IntervalTree {
+    /// Return a new tree over `intervals` in O(n log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `intervals` - triples `(start, end, id)` of closed intervals.
+    ///
+    /// # Panics
+    ///
+    /// If some interval has `start > end`.
+    ///
+    pub fn new(intervals: Vec<(i64, i64, usize)>) -> IntervalTree {
+        for &(start, end, _) in &intervals {
+            assert!(start <= end, "invalid interval [{}, {}]", start, end);
+        }
+        let mut tree = IntervalTree {
+            nodes: Vec::new(),
+            root: None,
+        };
+        tree.root = tree.build(intervals);
+        tree
+    }
+
+    /// Return the sorted ids of all intervals containing `point`.
+    pub fn stabbing_query(&self, point: i64) -> Vec<usize> {
+        self.overlap_query(point, point)
+    }
+
+    /// Return the sorted ids of all intervals sharing
+    /// at least one point with `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi`.
+    ///
+    pub fn overlap_query(&self, lo: i64, hi: i64) -> Vec<usize> {
+        assert!(lo <= hi, "invalid range [{}, {}]", lo, hi);
+        let mut ids = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if hi < node.center {
+                let overlapping = node.by_start.iter().take_while(|&&(s, _, _)| s <= hi);
+                ids.extend(overlapping.map(|&(_, _, id)| id));
+                stack.extend(node.left);
+            } else if node.center < lo {
+                let overlapping = node.by_end.iter().take_while(|&&(_, e, _)| e >= lo);
+                ids.extend(overlapping.map(|&(_, _, id)| id));
+                stack.extend(node.right);
+            } else {
+                ids.extend(node.by_start.iter().map(|&(_, _, id)| id));
+                stack.extend(node.left);
+                stack.extend(node.right);
+            }
+        }
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Build the subtree over `intervals` and return its root.
+    fn build(&mut self, intervals: Vec<(i64, i64, usize)>) -> Option<usize> {
+        if intervals.is_empty() {
+            return None;
+        }
+        let mut endpoints: Vec<i64> = intervals.iter().flat_map(|&(s, e, _)| [s, e]).collect();
+        let mid = endpoints.len() / 2;
+        let center = *endpoints.select_nth_unstable(mid).1;
+
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let mut by_start = Vec::new();
+        for interval in intervals {
+            if interval.1 < center {
+                left.push(interval);
+            } else if center < interval.0 {
+                right.push(interval);
+            } else {
+                by_start.push(interval);
+            }
+        }
+        let mut by_end = by_start.clone();
+        by_start.sort_unstable_by_key(|&(s, _, _)| s);
+        by_end.sort_unstable_by_key(|&(_, e, _)| -e);
+
+        let left = self.build(left);
+        let right = self.build(right);
+        self.nodes.push(Node {
+            center,
+            by_start,
+            by_end,
+            left,
+            right,
+        });
+        Some(self.nodes.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalTree;
+    use graph::random::XorShift;
+
+    #[test]
+    fn stabbing_works() {
+        let tree = IntervalTree::new(vec![(0, 10, 0), (2, 6, 1), (5, 9, 2), (4, 5, 3)]);
+        assert_eq!(tree.stabbing_query(-1), vec![]);
+        assert_eq!(tree.stabbing_query(11), vec![]);
+        assert_eq!(tree.stabbing_query(5), vec![0, 1, 2, 3]);
+        assert_eq!(tree.stabbing_query(7), vec![0, 2]);
+        assert_eq!(tree.stabbing_query(10), vec![0]);
+        assert_eq!(IntervalTree::new(vec![]).stabbing_query(0), vec![]);
+    }
+
+    #[test]
+    fn overlap_differs_from_stabbing() {
+        let tree = IntervalTree::new(vec![(1, 3, 7), (6, 8, 8), (12, 20, 9)]);
+        assert_eq!(tree.stabbing_query(4), vec![]);
+        assert_eq!(tree.overlap_query(4, 5), vec![]);
+        assert_eq!(tree.overlap_query(3, 6), vec![7, 8]);
+        assert_eq!(tree.overlap_query(9, 11), vec![]);
+        assert_eq!(tree.overlap_query(0, 100), vec![7, 8, 9]);
+    }
+
+    #[test]
+    fn identical_intervals_work() {
+        let tree = IntervalTree::new((0..50).map(|id| (3, 7, id)).collect());
+        let all: Vec<usize> = (0..50).collect();
+        assert_eq!(tree.stabbing_query(3), all);
+        assert_eq!(tree.stabbing_query(7), all);
+        assert_eq!(tree.stabbing_query(8), vec![]);
+        assert_eq!(tree.overlap_query(7, 9), all);
+        assert_eq!(tree.overlap_query(-5, 2), vec![]);
+    }
+
+    #[test]
+    fn random_queries_match_naive() {
+        let mut rng = XorShift::new(139);
+        for _ in 0..20 {
+            let intervals: Vec<(i64, i64, usize)> = (0..rng.next_below(60))
+                .map(|id| {
+                    let start = rng.next_below(100) as i64 - 50;
+                    (start, start + rng.next_below(30) as i64, id)
+                })
+                .collect();
+            let tree = IntervalTree::new(intervals.clone());
+            for _ in 0..100 {
+                let lo = rng.next_below(120) as i64 - 60;
+                let hi = lo + rng.next_below(10) as i64;
+                let expected: Vec<usize> = intervals
+                    .iter()
+                    .filter(|&&(s, e, _)| s <= hi && lo <= e)
+                    .map(|&(_, _, id)| id)
+                    .collect();
+                assert_eq!(tree.overlap_query(lo, hi), expected);
+            }
+        }
+    }
+}
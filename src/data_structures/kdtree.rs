@@ -0,0 +1,198 @@
+//! This module defines a static two-dimensional k-d tree
+//! answering nearest neighbor queries.
+
+use std::collections::BinaryHeap;
+
+/// The k-d tree over a set of points on the integer plane. The
+/// points are stored in an implicit balanced tree: the median of
+/// every subarray is its root and splits it by the x coordinate on
+/// even levels and by the y coordinate on odd ones. Distances are
+/// squared Euclidean, so everything stays in integer arithmetic.
+///
+/// Queries on random points visit O(log n) nodes on average, but
+/// may degrade to O(n) on adversarial inputs. Ties in distance are
+/// broken in favor of the smaller point index.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::KdTree;
+/// let tree = KdTree::new(vec![[0, 0], [10, 0], [0, 10], [6, 6]]);
+/// assert_eq!(tree.nearest_neighbor([7, 8]), Some(3));
+/// assert_eq!(tree.k_nearest([1, 1], 2), vec![0, 3]);
+/// ```
+pub struct KdTree {
+    points: Vec<[i64; 2]>,
+    order: Vec<usize>,
+}
+
+impl KdTree {
+    /// Return a new tree over `points` in O(n log n). Query results
+    /// are indices into `points`.
+    pub fn new(points: Vec<[i64; 2]>) -> KdTree {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(&points, &mut order, 0);
+        KdTree { points, order }
+    }
+
+    /// Return the number of points in the tree.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Return `true` if the tree has no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Return the index of the point closest to `query`,
+    /// or `None` if the tree is empty.
+    pub fn nearest_neighbor(&self, query: [i64; 2]) -> Option<usize> {
+        self.k_nearest(query, 1).pop()
+    }
+
+    /// Return the indices of the `k` points closest to `query`
+    /// ordered by increasing distance. If the tree has fewer than
+    /// `k` points, all of them are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - the point in question;
+    /// * `k` - the number of neighbors to find.
+    ///
+    pub fn k_nearest(&self, query: [i64; 2], k: usize) -> Vec<usize> {
+        let mut best = BinaryHeap::new();
+        if k > 0 {
+            self.search(0, self.order.len(), 0, query, k, &mut best);
+        }
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|(_, index)| index)
+            .collect()
+    }
+
+    /// Visit the subtree over `order[lo..hi]` split by `axis`, keeping
+    /// the `k` best pairs `(distance, index)` in the max-heap `best`.
+    fn search(
+        &self,
+        lo: usize,
+        hi: usize,
+        axis: usize,
+        query: [i64; 2],
+        k: usize,
+        best: &mut BinaryHeap<(i64, usize)>,
+    ) {
+        if lo == hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let index = self.order[mid];
+        let point = self.points[index];
+        best.push((dist2(point, query), index));
+        if best.len() > k {
+            best.pop();
+        }
+
+        let diff = query[axis] - point[axis];
+        let (near, far) = if diff < 0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.search(near.0, near.1, 1 - axis, query, k, best);
+        if best.len() < k || diff * diff <= best.peek().unwrap().0 {
+            self.search(far.0, far.1, 1 - axis, query, k, best);
+        }
+    }
+}
+
+/// Arrange `order` so that every subarray has its median by `axis`
+/// in the middle, smaller points to the left and larger to the right.
+fn build(points: &[[i64; 2]], order: &mut [usize], axis: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by_key(mid, |&i| points[i][axis]);
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, 1 - axis);
+    build(points, &mut right[1..], 1 - axis);
+}
+
+/// Return the squared Euclidean distance between two points.
+fn dist2(a: [i64; 2], b: [i64; 2]) -> i64 {
+    let (dx, dy) = (a[0] - b[0], a[1] - b[1]);
+    dx * dx + dy * dy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dist2, KdTree};
+    use graph::random::XorShift;
+
+    #[test]
+    fn single_point_is_always_nearest() {
+        let tree = KdTree::new(vec![[3, -4]]);
+        assert_eq!(tree.nearest_neighbor([0, 0]), Some(0));
+        assert_eq!(tree.nearest_neighbor([1000, 1000]), Some(0));
+        assert_eq!(tree.k_nearest([0, 0], 5), vec![0]);
+        assert_eq!(KdTree::new(vec![]).nearest_neighbor([0, 0]), None);
+        assert_eq!(tree.k_nearest([0, 0], 0), vec![]);
+    }
+
+    #[test]
+    fn existing_point_is_found() {
+        let points = vec![[5, 5], [1, 9], [-3, 2], [8, -1], [0, 0], [7, 7]];
+        let tree = KdTree::new(points.clone());
+        for (i, &p) in points.iter().enumerate() {
+            assert_eq!(tree.nearest_neighbor(p), Some(i));
+        }
+    }
+
+    #[test]
+    fn equidistant_points_work() {
+        let points = vec![[1, 0], [0, 1], [-1, 0], [0, -1]];
+        let tree = KdTree::new(points.clone());
+        let nearest = tree.nearest_neighbor([0, 0]).unwrap();
+        assert_eq!(dist2(points[nearest], [0, 0]), 1);
+        let mut all = tree.k_nearest([0, 0], 4);
+        all.sort();
+        assert_eq!(all, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn grid_works() {
+        let points: Vec<[i64; 2]> = (0..100).map(|i| [10 * (i % 10), 10 * (i / 10)]).collect();
+        let tree = KdTree::new(points);
+        assert_eq!(tree.len(), 100);
+        assert_eq!(tree.nearest_neighbor([42, 67]), Some(74));
+        assert_eq!(tree.nearest_neighbor([-50, -50]), Some(0));
+        assert_eq!(tree.nearest_neighbor([91, 89]), Some(99));
+        assert_eq!(tree.k_nearest([22, 21], 3), vec![22, 23, 32]);
+    }
+
+    #[test]
+    fn random_queries_match_naive() {
+        let mut rng = XorShift::new(127);
+        for _ in 0..3 {
+            let mut coord = || rng.next_below(2001) as i64 - 1000;
+            let points: Vec<[i64; 2]> = (0..1000).map(|_| [coord(), coord()]).collect();
+            let tree = KdTree::new(points.clone());
+            for _ in 0..100 {
+                let query = [
+                    rng.next_below(2401) as i64 - 1200,
+                    rng.next_below(2401) as i64 - 1200,
+                ];
+                let k = 1 + rng.next_below(10);
+                let mut naive: Vec<(i64, usize)> = points
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &p)| (dist2(p, query), i))
+                    .collect();
+                naive.sort();
+                let expected: Vec<usize> = naive[..k].iter().map(|&(_, i)| i).collect();
+                assert_eq!(tree.k_nearest(query, k), expected);
+                assert_eq!(tree.nearest_neighbor(query), Some(naive[0].1));
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! This module defines a segment tree with lazy propagation that
+//! supports adding a value to a whole range and range maximums.
+
+/// The segment tree over an array of integers. A range addition
+/// stops at the O(log n) nodes covering the range and records the
+/// delta there. Additions commute, so the tags are never pushed
+/// down: the maximum of a subtree is the maximum of its children
+/// plus its own tag, and a query adds up the tags on its way down.
+///
+/// Mixing range additions with range assignments in one tree is
+/// more delicate: an assignment has to clear the pending additions
+/// below it, and an addition on top of a pending assignment has to
+/// be folded into the assigned value, so the tags must be pushed
+/// down and merged in the right order.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::LazyMaxSegTree;
+/// let mut tree = LazyMaxSegTree::new(&[1, 5, 3, 4, 2]);
+/// tree.range_add(2, 4, 10);
+/// assert_eq!(tree.range_max(0, 4), 14);
+/// assert_eq!(tree.range_max(0, 1), 5);
+/// ```
+pub struct LazyMaxSegTree {
+    n: usize,
+    max: Vec<i64>,
+    add: Vec<i64>,
+}
+
+impl LazyMaxSegTree {
+    /// Return a new tree holding `data`. Runs in O(n).
+    pub fn new(data: &[i64]) -> LazyMaxSegTree {
+        let n = data.len();
+        let mut tree = LazyMaxSegTree {
+            n,
+            max: vec![0; 4 * n.max(1)],
+            add: vec![0; 4 * n.max(1)],
+        };
+        if n > 0 {
+            tree.build(data, 1, 0, n);
+        }
+        tree
+    }
+
+    /// Add `delta` to every element with index in `[lo, hi]`
+    /// in O(log n).
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_add(&mut self, lo: usize, hi: usize, delta: i64) {
+        assert!(lo <= hi && hi < self.n, "invalid range [{}, {}]", lo, hi);
+        self.add_in(1, 0, self.n, lo, hi + 1, delta);
+    }
+
+    /// Return the maximum of the elements with indices in `[lo, hi]`
+    /// in O(log n).
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_max(&self, lo: usize, hi: usize) -> i64 {
+        assert!(lo <= hi && hi < self.n, "invalid range [{}, {}]", lo, hi);
+        self.max_in(1, 0, self.n, lo, hi + 1)
+    }
+
+    /// Build the subtree `node` over `data[lo..hi]`.
+    fn build(&mut self, data: &[i64], node: usize, lo: usize, hi: usize) {
+        if hi - lo == 1 {
+            self.max[node] = data[lo];
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.build(data, 2 * node, lo, mid);
+        self.build(data, 2 * node + 1, mid, hi);
+        self.max[node] = self.max[2 * node].max(self.max[2 * node + 1]);
+    }
+
+    /// Add `delta` on `[from, to)` within subtree `node` over `[lo, hi)`.
+    fn add_in(&mut self, node: usize, lo: usize, hi: usize, from: usize, to: usize, delta: i64) {
+        if to <= lo || hi <= from {
+            return;
+        }
+        if from <= lo && hi <= to {
+            self.max[node] += delta;
+            self.add[node] += delta;
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.add_in(2 * node, lo, mid, from, to, delta);
+        self.add_in(2 * node + 1, mid, hi, from, to, delta);
+        self.max[node] = self.max[2 * node].max(self.max[2 * node + 1]) + self.add[node];
+    }
+
+    /// Return the maximum over `[from, to)` within subtree `node`
+    /// over `[lo, hi)`, not counting the tags above `node`.
+    fn max_in(&self, node: usize, lo: usize, hi: usize, from: usize, to: usize) -> i64 {
+        if from <= lo && hi <= to {
+            return self.max[node];
+        }
+        let mid = (lo + hi) / 2;
+        let mut best = i64::MIN;
+        if from < mid {
+            best = best.max(self.max_in(2 * node, lo, mid, from, to));
+        }
+        if mid < to {
+            best = best.max(self.max_in(2 * node + 1, mid, hi, from, to));
+        }
+        best + self.add[node]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LazyMaxSegTree;
+    use graph::random::XorShift;
+
+    #[test]
+    fn large_addition_raises_max() {
+        let mut tree = LazyMaxSegTree::new(&[3, 8, -2, 6, 0, 5]);
+        assert_eq!(tree.range_max(0, 5), 8);
+        tree.range_add(2, 4, 1000);
+        assert_eq!(tree.range_max(0, 5), 1006);
+        assert_eq!(tree.range_max(2, 2), 998);
+        assert_eq!(tree.range_max(0, 1), 8);
+        assert_eq!(tree.range_max(5, 5), 5);
+    }
+
+    #[test]
+    fn overlapping_additions_work() {
+        let mut tree = LazyMaxSegTree::new(&[0; 8]);
+        tree.range_add(0, 5, 1);
+        tree.range_add(3, 7, 2);
+        tree.range_add(2, 3, -4);
+        let values: Vec<i64> = (0..8).map(|i| tree.range_max(i, i)).collect();
+        assert_eq!(values, vec![1, 1, -3, -1, 3, 3, 2, 2]);
+        assert_eq!(tree.range_max(0, 3), 1);
+        assert_eq!(tree.range_max(2, 3), -1);
+    }
+
+    #[test]
+    fn single_element_works() {
+        let mut tree = LazyMaxSegTree::new(&[-7]);
+        assert_eq!(tree.range_max(0, 0), -7);
+        tree.range_add(0, 0, 3);
+        tree.range_add(0, 0, 3);
+        assert_eq!(tree.range_max(0, 0), -1);
+    }
+
+    #[test]
+    fn random_operations_match_naive() {
+        let mut rng = XorShift::new(113);
+        let n = 50;
+        let mut naive: Vec<i64> = (0..n).map(|_| rng.next_below(1000) as i64 - 500).collect();
+        let mut tree = LazyMaxSegTree::new(&naive);
+        for _ in 0..10_000 {
+            let (a, b) = (rng.next_below(n), rng.next_below(n));
+            let (lo, hi) = (a.min(b), a.max(b));
+            if rng.next_below(2) == 0 {
+                let delta = rng.next_below(201) as i64 - 100;
+                tree.range_add(lo, hi, delta);
+                for x in naive[lo..=hi].iter_mut() {
+                    *x += delta;
+                }
+            } else {
+                assert_eq!(
+                    tree.range_max(lo, hi),
+                    *naive[lo..=hi].iter().max().unwrap()
+                );
+            }
+        }
+    }
+}
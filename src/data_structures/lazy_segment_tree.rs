@@ -0,0 +1,183 @@
+//! This module defines a segment tree with lazy propagation that
+//! supports assigning a value to a whole range and range sums.
+
+/// The segment tree over an array of integers. A range assignment
+/// stops at the O(log n) nodes covering the range and leaves
+/// a pending tag there, which is pushed down to the children only
+/// when a later assignment needs to descend below the node.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::LazySegTree;
+/// let mut tree = LazySegTree::new(&[1, 2, 3, 4, 5]);
+/// tree.range_assign(1, 3, 10);
+/// assert_eq!(tree.range_sum(0, 4), 1 + 30 + 5);
+/// assert_eq!(tree.range_sum(3, 3), 10);
+/// ```
+pub struct LazySegTree {
+    n: usize,
+    sum: Vec<i64>,
+    assign: Vec<Option<i64>>,
+}
+
+impl LazySegTree {
+    /// Return a new tree holding `data`. Runs in O(n).
+    pub fn new(data: &[i64]) -> LazySegTree {
+        let n = data.len();
+        let mut tree = LazySegTree {
+            n,
+            sum: vec![0; 4 * n.max(1)],
+            assign: vec![None; 4 * n.max(1)],
+        };
+        if n > 0 {
+            tree.build(data, 1, 0, n);
+        }
+        tree
+    }
+
+    /// Set every element with index in `[lo, hi]` to `val`
+    /// in O(log n).
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_assign(&mut self, lo: usize, hi: usize, val: i64) {
+        assert!(lo <= hi && hi < self.n, "invalid range [{}, {}]", lo, hi);
+        self.assign_in(1, 0, self.n, lo, hi + 1, val);
+    }
+
+    /// Return the sum of the elements with indices in `[lo, hi]`
+    /// in O(log n).
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_sum(&self, lo: usize, hi: usize) -> i64 {
+        assert!(lo <= hi && hi < self.n, "invalid range [{}, {}]", lo, hi);
+        self.sum_in(1, 0, self.n, lo, hi + 1)
+    }
+
+    /// Build the subtree `node` over `data[lo..hi]`.
+    fn build(&mut self, data: &[i64], node: usize, lo: usize, hi: usize) {
+        if hi - lo == 1 {
+            self.sum[node] = data[lo];
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.build(data, 2 * node, lo, mid);
+        self.build(data, 2 * node + 1, mid, hi);
+        self.sum[node] = self.sum[2 * node] + self.sum[2 * node + 1];
+    }
+
+    /// Set every element of subtree `node` over `[lo, hi)` to `val`.
+    fn apply(&mut self, node: usize, lo: usize, hi: usize, val: i64) {
+        self.sum[node] = val * (hi - lo) as i64;
+        self.assign[node] = Some(val);
+    }
+
+    /// Move the pending assignment of `node` to its children.
+    fn push_down(&mut self, node: usize, lo: usize, hi: usize) {
+        if let Some(val) = self.assign[node].take() {
+            let mid = (lo + hi) / 2;
+            self.apply(2 * node, lo, mid, val);
+            self.apply(2 * node + 1, mid, hi, val);
+        }
+    }
+
+    /// Assign `val` on `[from, to)` within subtree `node` over `[lo, hi)`.
+    fn assign_in(&mut self, node: usize, lo: usize, hi: usize, from: usize, to: usize, val: i64) {
+        if to <= lo || hi <= from {
+            return;
+        }
+        if from <= lo && hi <= to {
+            self.apply(node, lo, hi, val);
+            return;
+        }
+        self.push_down(node, lo, hi);
+        let mid = (lo + hi) / 2;
+        self.assign_in(2 * node, lo, mid, from, to, val);
+        self.assign_in(2 * node + 1, mid, hi, from, to, val);
+        self.sum[node] = self.sum[2 * node] + self.sum[2 * node + 1];
+    }
+
+    /// Return the sum over `[from, to)` within subtree `node` over `[lo, hi)`.
+    fn sum_in(&self, node: usize, lo: usize, hi: usize, from: usize, to: usize) -> i64 {
+        if to <= lo || hi <= from {
+            return 0;
+        }
+        if from <= lo && hi <= to {
+            return self.sum[node];
+        }
+        if let Some(val) = self.assign[node] {
+            // The whole subtree holds `val`, no need to descend.
+            return val * (to.min(hi) - from.max(lo)) as i64;
+        }
+        let mid = (lo + hi) / 2;
+        self.sum_in(2 * node, lo, mid, from, to) + self.sum_in(2 * node + 1, mid, hi, from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LazySegTree;
+    use graph::random::XorShift;
+
+    #[test]
+    fn assign_then_query_works() {
+        let mut tree = LazySegTree::new(&[5, -2, 7, 1, 0, 3]);
+        tree.range_assign(1, 4, 2);
+        assert_eq!(tree.range_sum(1, 4), 8);
+        assert_eq!(tree.range_sum(0, 5), 5 + 8 + 3);
+        tree.range_assign(0, 5, -1);
+        assert_eq!(tree.range_sum(0, 5), -6);
+    }
+
+    #[test]
+    fn overlapping_assignments_work() {
+        let mut tree = LazySegTree::new(&[0; 8]);
+        tree.range_assign(0, 5, 1);
+        tree.range_assign(3, 7, 2);
+        tree.range_assign(2, 3, 3);
+        let values: Vec<i64> = (0..8).map(|i| tree.range_sum(i, i)).collect();
+        assert_eq!(values, vec![1, 1, 3, 3, 2, 2, 2, 2]);
+        assert_eq!(tree.range_sum(0, 7), 16);
+    }
+
+    #[test]
+    fn single_element_assignment_works() {
+        let mut tree = LazySegTree::new(&[4]);
+        tree.range_assign(0, 0, 9);
+        assert_eq!(tree.range_sum(0, 0), 9);
+
+        let mut tree = LazySegTree::new(&[1, 2, 3]);
+        tree.range_assign(1, 1, -5);
+        assert_eq!(tree.range_sum(1, 1), -5);
+        assert_eq!(tree.range_sum(0, 2), -1);
+    }
+
+    #[test]
+    fn random_operations_match_naive() {
+        let mut rng = XorShift::new(101);
+        for &n in [1, 2, 3, 17, 64].iter() {
+            let mut naive: Vec<i64> = (0..n).map(|_| rng.next_below(50) as i64).collect();
+            let mut tree = LazySegTree::new(&naive);
+            for _ in 0..400 {
+                let (a, b) = (rng.next_below(n), rng.next_below(n));
+                let (lo, hi) = (a.min(b), a.max(b));
+                if rng.next_below(2) == 0 {
+                    let val = rng.next_below(21) as i64 - 10;
+                    tree.range_assign(lo, hi, val);
+                    for x in naive[lo..=hi].iter_mut() {
+                        *x = val;
+                    }
+                }
+                for (i, &x) in naive.iter().enumerate() {
+                    assert_eq!(tree.range_sum(i, i), x);
+                }
+                assert_eq!(tree.range_sum(lo, hi), naive[lo..=hi].iter().sum::<i64>());
+            }
+        }
+    }
+}
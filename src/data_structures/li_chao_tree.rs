@@ -0,0 +1,278 @@
+//! This module defines the Li Chao tree: a segment tree over
+//! integer abscissas that stores linear functions and returns
+//! the minimum of them at a point, as used by the convex hull
+//! trick in dynamic programming.
+
+/// A node of the tree; children are indices into the arena,
+/// zero meaning "no child" since the root is never a child.
+#[derive(Clone, Copy)]
+struct Node {
+    line: Option<(i64, i64)>,
+    left: usize,
+    right: usize,
+}
+
+/// Return the value of the line at `x`.
+fn eval((slope, intercept): (i64, i64), x: i64) -> i64 {
+    slope * x + intercept
+}
+
+/// The minimum of lines `y = slope * x + intercept` over integer
+/// `x` in `[x_min, x_max]`. Adding a line and querying take
+/// O(log C) where `C = x_max - x_min`; a segment takes O(log² C).
+/// Nodes are created on demand. Values must fit in `i64`.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::LiChaoTree;
+/// let mut tree = LiChaoTree::new(-10, 10);
+/// tree.add_line(1, 0);
+/// tree.add_line(-1, 2);
+/// assert_eq!(tree.min_at(-5), -5);
+/// assert_eq!(tree.min_at(5), -3);
+/// tree.add_segment(0, -100, 0, 0);
+/// assert_eq!(tree.min_at(0), -100);
+/// assert_eq!(tree.min_at(1), 1);
+/// ```
+pub struct LiChaoTree {
+    x_min: i64,
+    x_max: i64,
+    nodes: Vec<Node>,
+}
+
+impl LiChaoTree {
+    /// Return a new empty tree over `[x_min, x_max]`.
+    ///
+    /// # Panics
+    ///
+    /// If `x_min > x_max`.
+    ///
+    pub fn new(x_min: i64, x_max: i64) -> LiChaoTree {
+        assert!(x_min <= x_max, "empty range [{}, {}]", x_min, x_max);
+        LiChaoTree {
+            x_min,
+            x_max,
+            nodes: vec![Node {
+                line: None,
+                left: 0,
+                right: 0,
+            }],
+        }
+    }
+
+    /// Add the line `y = slope * x + intercept`.
+    pub fn add_line(&mut self, slope: i64, intercept: i64) {
+        self.insert(0, self.x_min, self.x_max, (slope, intercept));
+    }
+
+    /// Add the segment of the line `y = slope * x + intercept`
+    /// over `x` in `[lo, hi]`; the part outside `[x_min, x_max]`
+    /// is ignored.
+    pub fn add_segment(&mut self, slope: i64, intercept: i64, lo: i64, hi: i64) {
+        let lo = lo.max(self.x_min);
+        let hi = hi.min(self.x_max);
+        if lo <= hi {
+            self.insert_segment(0, self.x_min, self.x_max, lo, hi, (slope, intercept));
+        }
+    }
+
+    /// Return the minimum of all lines and segments at `x`,
+    /// or `i64::MAX` if none of them covers `x`.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is out of `[x_min, x_max]`.
+    ///
+    pub fn min_at(&self, x: i64) -> i64 {
+        assert!(
+            self.x_min <= x && x <= self.x_max,
+            "{} is out of range [{}, {}]",
+            x,
+            self.x_min,
+            self.x_max
+        );
+        let (mut lo, mut hi) = (self.x_min, self.x_max);
+        let mut node = 0;
+        let mut best = i64::MAX;
+        loop {
+            if let Some(line) = self.nodes[node].line {
+                best = best.min(eval(line, x));
+            }
+            let mid = midpoint(lo, hi);
+            let next = if x <= mid {
+                hi = mid;
+                self.nodes[node].left
+            } else {
+                lo = mid + 1;
+                self.nodes[node].right
+            };
+            if next == 0 {
+                return best;
+            }
+            node = next;
+        }
+    }
+
+    /// Return the child of `node` on given side, creating it if needed.
+    fn child(&mut self, node: usize, right: bool) -> usize {
+        let existing = if right {
+            self.nodes[node].right
+        } else {
+            self.nodes[node].left
+        };
+        if existing != 0 {
+            return existing;
+        }
+        self.nodes.push(Node {
+            line: None,
+            left: 0,
+            right: 0,
+        });
+        let created = self.nodes.len() - 1;
+        if right {
+            self.nodes[node].right = created;
+        } else {
+            self.nodes[node].left = created;
+        }
+        created
+    }
+
+    /// Add `line` to the subtree `node` over `[lo, hi]`. Every node
+    /// keeps the line that is lowest at its midpoint and pushes the
+    /// other one down to the only half where it may still win.
+    fn insert(&mut self, mut node: usize, mut lo: i64, mut hi: i64, mut line: (i64, i64)) {
+        loop {
+            let mid = midpoint(lo, hi);
+            let kept = match self.nodes[node].line {
+                None => {
+                    self.nodes[node].line = Some(line);
+                    return;
+                }
+                Some(kept) => kept,
+            };
+            if eval(line, mid) < eval(kept, mid) {
+                self.nodes[node].line = Some(line);
+                line = kept;
+            }
+            let kept = self.nodes[node].line.unwrap();
+            if lo == hi {
+                return;
+            }
+            if eval(line, lo) < eval(kept, lo) {
+                node = self.child(node, false);
+                hi = mid;
+            } else if eval(line, hi) < eval(kept, hi) {
+                node = self.child(node, true);
+                lo = mid + 1;
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Add `line` restricted to `[from, to]` to the subtree `node`
+    /// over `[lo, hi]`.
+    fn insert_segment(
+        &mut self,
+        node: usize,
+        lo: i64,
+        hi: i64,
+        from: i64,
+        to: i64,
+        line: (i64, i64),
+    ) {
+        if to < lo || hi < from {
+            return;
+        }
+        if from <= lo && hi <= to {
+            self.insert(node, lo, hi, line);
+            return;
+        }
+        let mid = midpoint(lo, hi);
+        let left = self.child(node, false);
+        self.insert_segment(left, lo, mid, from, to, line);
+        let right = self.child(node, true);
+        self.insert_segment(right, mid + 1, hi, from, to, line);
+    }
+}
+
+/// Return the midpoint of `[lo, hi]` rounded down, without overflow.
+fn midpoint(lo: i64, hi: i64) -> i64 {
+    lo + ((hi - lo) >> 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LiChaoTree;
+    use graph::random::XorShift;
+
+    #[test]
+    fn single_and_crossing_lines_work() {
+        let mut tree = LiChaoTree::new(-100, 100);
+        assert_eq!(tree.min_at(0), i64::MAX);
+        tree.add_line(2, 3);
+        assert_eq!(tree.min_at(-100), -197);
+        assert_eq!(tree.min_at(100), 203);
+        tree.add_line(-1, 0);
+        assert_eq!(tree.min_at(-2), -1);
+        assert_eq!(tree.min_at(-1), 1);
+        assert_eq!(tree.min_at(100), -100);
+    }
+
+    #[test]
+    fn parallel_lines_work() {
+        let mut tree = LiChaoTree::new(0, 1000);
+        for intercept in [5, -3, 8, -1, 0].iter() {
+            tree.add_line(7, *intercept);
+        }
+        assert_eq!(tree.min_at(0), -3);
+        assert_eq!(tree.min_at(1000), 6997);
+        assert_eq!(tree.min_at(500), 3497);
+    }
+
+    #[test]
+    fn segments_work() {
+        let mut tree = LiChaoTree::new(0, 10);
+        tree.add_segment(0, 5, 2, 4);
+        tree.add_segment(-1, 10, 3, 20);
+        assert_eq!(tree.min_at(1), i64::MAX);
+        assert_eq!(tree.min_at(2), 5);
+        assert_eq!(tree.min_at(4), 5);
+        assert_eq!(tree.min_at(5), 5);
+        assert_eq!(tree.min_at(10), 0);
+        tree.add_segment(0, 0, 11, 20);
+        assert_eq!(tree.min_at(10), 0);
+    }
+
+    #[test]
+    fn random_queries_match_brute_force() {
+        let mut rng = XorShift::new(43);
+        for _ in 0..20 {
+            let (x_min, x_max) = (-(rng.next_below(50) as i64), rng.next_below(50) as i64);
+            let mut tree = LiChaoTree::new(x_min, x_max);
+            let mut pieces = Vec::new();
+            for _ in 0..30 {
+                let slope = rng.next_below(21) as i64 - 10;
+                let intercept = rng.next_below(201) as i64 - 100;
+                if rng.next_below(2) == 0 {
+                    tree.add_line(slope, intercept);
+                    pieces.push((slope, intercept, i64::MIN, i64::MAX));
+                } else {
+                    let a = rng.next_below(120) as i64 - 60;
+                    let b = rng.next_below(120) as i64 - 60;
+                    tree.add_segment(slope, intercept, a.min(b), a.max(b));
+                    pieces.push((slope, intercept, a.min(b), a.max(b)));
+                }
+                for x in x_min..=x_max {
+                    let expected = pieces
+                        .iter()
+                        .filter(|&&(_, _, lo, hi)| lo <= x && x <= hi)
+                        .map(|&(k, b, _, _)| k * x + b)
+                        .min()
+                        .unwrap_or(i64::MAX);
+                    assert_eq!(tree.min_at(x), expected);
+                }
+            }
+        }
+    }
+}
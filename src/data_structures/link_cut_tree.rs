@@ -0,0 +1,348 @@
+//! This module defines the link-cut tree of Sleator and Tarjan:
+//! a forest of rooted trees supporting linking, cutting and path
+//! queries in O(log n) amortized time.
+
+const NONE: usize = usize::MAX;
+
+/// A forest of rooted trees over vertices 0, 1, ..., N-1, each
+/// carrying an integer value. Every tree is split into preferred
+/// paths stored in splay trees keyed by depth; the root of a splay
+/// tree keeps a "path-parent" pointer to the vertex above its path.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::LinkCutTree;
+/// let mut forest = LinkCutTree::new(4);
+/// for v in 0..4 {
+///     forest.set_value(v, 10 * v as i64);
+/// }
+/// forest.link(1, 0);
+/// forest.link(2, 1);
+/// forest.link(3, 1);
+/// assert_eq!(forest.find_root(3), 0);
+/// assert_eq!(forest.path_aggregate(2, 3), 20 + 10 + 30);
+/// forest.cut(1, 0);
+/// assert!(!forest.connected(0, 2));
+/// assert_eq!(forest.find_root(2), 1);
+/// ```
+pub struct LinkCutTree {
+    child: Vec<[usize; 2]>,
+    parent: Vec<usize>,
+    value: Vec<i64>,
+    sum: Vec<i64>,
+}
+
+impl LinkCutTree {
+    /// Return a forest of `n` single-vertex trees with zero values.
+    pub fn new(n: usize) -> LinkCutTree {
+        LinkCutTree {
+            child: vec![[NONE; 2]; n],
+            parent: vec![NONE; n],
+            value: vec![0; n],
+            sum: vec![0; n],
+        }
+    }
+
+    /// Set the value of `vert` to `value`.
+    pub fn set_value(&mut self, vert: usize, value: i64) {
+        self.access(vert);
+        self.value[vert] = value;
+        self.update(vert);
+    }
+
+    /// Make `u` a child of `v`.
+    ///
+    /// # Panics
+    ///
+    /// If `u` is not the root of its tree or `u` and `v` are
+    /// already connected.
+    ///
+    pub fn link(&mut self, u: usize, v: usize) {
+        assert_eq!(self.find_root(u), u, "{} is not a root", u);
+        assert!(
+            !self.connected(u, v),
+            "{} and {} are already connected",
+            u,
+            v
+        );
+        self.access(u);
+        self.parent[u] = v;
+    }
+
+    /// Remove the edge between `u` and `v`; the lower of the two
+    /// becomes the root of a new tree.
+    ///
+    /// # Panics
+    ///
+    /// If `u` and `v` are not adjacent.
+    ///
+    pub fn cut(&mut self, u: usize, v: usize) {
+        let lower = if self.tree_parent(u) == Some(v) {
+            u
+        } else if self.tree_parent(v) == Some(u) {
+            v
+        } else {
+            panic!("{} and {} are not adjacent", u, v);
+        };
+        self.access(lower);
+        let above = self.child[lower][0];
+        self.parent[above] = NONE;
+        self.child[lower][0] = NONE;
+        self.update(lower);
+    }
+
+    /// Return the root of the tree containing `vert`.
+    pub fn find_root(&mut self, vert: usize) -> usize {
+        self.access(vert);
+        let mut root = vert;
+        while self.child[root][0] != NONE {
+            root = self.child[root][0];
+        }
+        self.splay(root);
+        root
+    }
+
+    /// Return `true` if `u` and `v` are in the same tree.
+    pub fn connected(&mut self, u: usize, v: usize) -> bool {
+        u == v || self.find_root(u) == self.find_root(v)
+    }
+
+    /// Return the sum of the values of the vertices on the path
+    /// between `u` and `v`, both included.
+    ///
+    /// # Panics
+    ///
+    /// If `u` and `v` are not connected.
+    ///
+    pub fn path_aggregate(&mut self, u: usize, v: usize) -> i64 {
+        assert!(self.connected(u, v), "{} and {} are not connected", u, v);
+        self.access(u);
+        let lca = self.access(v);
+        self.root_path_sum(u) + self.root_path_sum(v) - 2 * self.root_path_sum(lca)
+            + self.value[lca]
+    }
+
+    /// Return the parent of `vert` in its tree, if any.
+    fn tree_parent(&mut self, vert: usize) -> Option<usize> {
+        self.access(vert);
+        let mut above = self.child[vert][0];
+        if above == NONE {
+            return None;
+        }
+        while self.child[above][1] != NONE {
+            above = self.child[above][1];
+        }
+        self.splay(above);
+        Some(above)
+    }
+
+    /// Return the sum of the values from the root of the tree to `vert`.
+    fn root_path_sum(&mut self, vert: usize) -> i64 {
+        self.access(vert);
+        self.sum[vert]
+    }
+
+    /// Return `true` if `x` is the root of its splay tree.
+    fn is_splay_root(&self, x: usize) -> bool {
+        let p = self.parent[x];
+        p == NONE || (self.child[p][0] != x && self.child[p][1] != x)
+    }
+
+    /// Recompute the path sum of the splay subtree of `x`.
+    fn update(&mut self, x: usize) {
+        let [left, right] = self.child[x];
+        self.sum[x] = self.value[x];
+        if left != NONE {
+            self.sum[x] += self.sum[left];
+        }
+        if right != NONE {
+            self.sum[x] += self.sum[right];
+        }
+    }
+
+    /// Rotate `x` above its splay tree parent.
+    fn rotate(&mut self, x: usize) {
+        let p = self.parent[x];
+        let g = self.parent[p];
+        let side = (self.child[p][1] == x) as usize;
+        let moved = self.child[x][1 - side];
+
+        if !self.is_splay_root(p) {
+            let p_side = (self.child[g][1] == p) as usize;
+            self.child[g][p_side] = x;
+        }
+        self.parent[x] = g;
+
+        self.child[x][1 - side] = p;
+        self.parent[p] = x;
+
+        self.child[p][side] = moved;
+        if moved != NONE {
+            self.parent[moved] = p;
+        }
+        self.update(p);
+        self.update(x);
+    }
+
+    /// Move `x` to the root of its splay tree.
+    fn splay(&mut self, x: usize) {
+        while !self.is_splay_root(x) {
+            let p = self.parent[x];
+            if !self.is_splay_root(p) {
+                let g = self.parent[p];
+                if (self.child[g][0] == p) == (self.child[p][0] == x) {
+                    self.rotate(p);
+                } else {
+                    self.rotate(x);
+                }
+            }
+            self.rotate(x);
+        }
+    }
+
+    /// Make the path from the root to `x` preferred, with `x`
+    /// at the root of its splay tree and nothing below it on the
+    /// path. Return the last vertex where the path was switched,
+    /// which after accessing `u` and then `v` is their lowest
+    /// common ancestor.
+    fn access(&mut self, x: usize) -> usize {
+        let mut last = NONE;
+        let mut y = x;
+        while y != NONE {
+            self.splay(y);
+            self.child[y][1] = last;
+            self.update(y);
+            last = y;
+            y = self.parent[y];
+        }
+        self.splay(x);
+        last
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkCutTree;
+    use graph::random::XorShift;
+    use graph::UnionFind;
+
+    /// Return the path from `v` up to the root of its tree.
+    fn path_to_root(parent: &[Option<usize>], mut v: usize) -> Vec<usize> {
+        let mut path = vec![v];
+        while let Some(p) = parent[v] {
+            path.push(p);
+            v = p;
+        }
+        path
+    }
+
+    #[test]
+    fn links_agree_with_union_find() {
+        let mut rng = XorShift::new(67);
+        let n = 50;
+        let mut forest = LinkCutTree::new(n);
+        let mut uf = UnionFind::new(n);
+        for _ in 0..200 {
+            let (u, v) = (rng.next_below(n), rng.next_below(n));
+            if rng.next_below(2) == 0 {
+                assert_eq!(forest.connected(u, v), uf.connected(u, v));
+            } else if !uf.connected(u, v) {
+                let root = forest.find_root(u);
+                forest.link(root, v);
+                uf.union(u, v);
+            }
+        }
+    }
+
+    #[test]
+    fn chain_aggregates_work() {
+        let n = 100;
+        let mut forest = LinkCutTree::new(n);
+        for v in 0..n {
+            forest.set_value(v, v as i64);
+        }
+        for v in 1..n {
+            forest.link(v, v - 1);
+        }
+        assert_eq!(forest.path_aggregate(0, n - 1), (n * (n - 1) / 2) as i64);
+        assert_eq!(
+            forest.path_aggregate(70, 30),
+            (30..=70).sum::<usize>() as i64
+        );
+        assert_eq!(forest.path_aggregate(5, 5), 5);
+        forest.set_value(50, 1000);
+        assert_eq!(forest.path_aggregate(49, 51), 49 + 1000 + 51);
+    }
+
+    #[test]
+    fn roots_after_cuts_work() {
+        let mut forest = LinkCutTree::new(6);
+        forest.link(1, 0);
+        forest.link(2, 1);
+        forest.link(3, 2);
+        forest.link(4, 1);
+        forest.link(5, 4);
+        forest.cut(1, 2);
+        assert_eq!(forest.find_root(3), 2);
+        assert_eq!(forest.find_root(5), 0);
+        forest.cut(0, 1);
+        assert_eq!(forest.find_root(5), 1);
+        assert_eq!(forest.find_root(0), 0);
+        forest.link(2, 5);
+        assert_eq!(forest.find_root(3), 1);
+        assert!(!forest.connected(0, 3));
+    }
+
+    #[test]
+    fn random_operations_match_naive_forest() {
+        let mut rng = XorShift::new(71);
+        let n = 30;
+        let mut forest = LinkCutTree::new(n);
+        let mut parent: Vec<Option<usize>> = vec![None; n];
+        let mut value = vec![0i64; n];
+        for _ in 0..2000 {
+            let (u, v) = (rng.next_below(n), rng.next_below(n));
+            let (root_u, root_v) = (
+                *path_to_root(&parent, u).last().unwrap(),
+                *path_to_root(&parent, v).last().unwrap(),
+            );
+            match rng.next_below(5) {
+                0 if root_u != root_v => {
+                    forest.link(root_u, v);
+                    parent[root_u] = Some(v);
+                }
+                1 => {
+                    if let Some(p) = parent[u] {
+                        forest.cut(p, u);
+                        parent[u] = None;
+                    }
+                }
+                2 => {
+                    let x = rng.next_below(100) as i64 - 50;
+                    forest.set_value(u, x);
+                    value[u] = x;
+                }
+                _ => {
+                    assert_eq!(forest.find_root(u), root_u);
+                    assert_eq!(forest.connected(u, v), root_u == root_v);
+                    if root_u == root_v {
+                        let up = path_to_root(&parent, u);
+                        let vp = path_to_root(&parent, v);
+                        let lca = *up.iter().find(|x| vp.contains(x)).unwrap();
+                        let sum: i64 = up
+                            .iter()
+                            .take_while(|&&x| x != lca)
+                            .map(|&x| value[x])
+                            .sum::<i64>()
+                            + vp.iter()
+                                .take_while(|&&x| x != lca)
+                                .map(|&x| value[x])
+                                .sum::<i64>()
+                            + value[lca];
+                        assert_eq!(forest.path_aggregate(u, v), sum);
+                    }
+                }
+            }
+        }
+    }
+}
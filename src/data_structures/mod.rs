@@ -0,0 +1,39 @@
+pub mod block_decomp;
+pub mod cartesian_tree;
+pub mod disjoint_sparse_table;
+pub mod ett;
+pub mod fenwick;
+pub mod fibonacci_heap;
+pub mod interval_tree;
+pub mod kdtree;
+pub mod lazy_max_segment_tree;
+pub mod lazy_segment_tree;
+pub mod li_chao_tree;
+pub mod link_cut_tree;
+pub mod monotonic_stack;
+pub mod persistent_segment_tree;
+pub mod segment_tree_beats;
+pub mod sliding_window;
+pub mod splay_tree;
+pub mod treap;
+
+pub use self::block_decomp::BlockArray;
+pub use self::cartesian_tree::{build_cartesian_tree, cartesian_tree_root};
+pub use self::disjoint_sparse_table::DisjointSparseTable;
+pub use self::ett::EulerTourTree;
+pub use self::fenwick::{Fenwick, FenwickRangeQuery, FenwickRangeUpdate};
+pub use self::fibonacci_heap::{FibHeap, Handle};
+pub use self::interval_tree::IntervalTree;
+pub use self::kdtree::KdTree;
+pub use self::lazy_max_segment_tree::LazyMaxSegTree;
+pub use self::lazy_segment_tree::LazySegTree;
+pub use self::li_chao_tree::LiChaoTree;
+pub use self::link_cut_tree::LinkCutTree;
+pub use self::monotonic_stack::{
+    largest_rectangle_in_histogram, next_greater, next_smaller, prev_greater, prev_smaller,
+};
+pub use self::persistent_segment_tree::PersistentSegTree;
+pub use self::segment_tree_beats::SegTreeBeats;
+pub use self::sliding_window::{sliding_window_max, sliding_window_min, SlidingWindowMin};
+pub use self::splay_tree::SplayTree;
+pub use self::treap::Treap;
@@ -0,0 +1,150 @@
+//! This module contains nearest greater and smaller element
+//! queries answered with a monotonic stack in O(n), and the
+//! largest rectangle in a histogram as an application.
+
+/// For every position, return the nearest position before it
+/// (in the order of `positions`) that is not popped by `pops`.
+fn nearest<I, F>(array: &[i64], positions: I, pops: F) -> Vec<Option<usize>>
+where
+    I: Iterator<Item = usize>,
+    F: Fn(i64, i64) -> bool,
+{
+    let mut nearest = vec![None; array.len()];
+    let mut stack: Vec<usize> = Vec::new();
+    for i in positions {
+        while stack.last().is_some_and(|&top| pops(array[top], array[i])) {
+            stack.pop();
+        }
+        nearest[i] = stack.last().cloned();
+        stack.push(i);
+    }
+    nearest
+}
+
+/// Return for every element the index of the nearest strictly
+/// greater element to its right, if any.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::next_greater;
+/// assert_eq!(
+///     next_greater(&[2, 1, 2, 4, 3]),
+///     vec![Some(3), Some(2), Some(3), None, None]
+/// );
+/// ```
+pub fn next_greater(array: &[i64]) -> Vec<Option<usize>> {
+    nearest(array, (0..array.len()).rev(), |top, x| top <= x)
+}
+
+/// Return for every element the index of the nearest strictly
+/// smaller element to its right, if any.
+pub fn next_smaller(array: &[i64]) -> Vec<Option<usize>> {
+    nearest(array, (0..array.len()).rev(), |top, x| top >= x)
+}
+
+/// Return for every element the index of the nearest strictly
+/// greater element to its left, if any.
+pub fn prev_greater(array: &[i64]) -> Vec<Option<usize>> {
+    nearest(array, 0..array.len(), |top, x| top <= x)
+}
+
+/// Return for every element the index of the nearest strictly
+/// smaller element to its left, if any.
+pub fn prev_smaller(array: &[i64]) -> Vec<Option<usize>> {
+    nearest(array, 0..array.len(), |top, x| top >= x)
+}
+
+/// Return the area of the largest axis-aligned rectangle that fits
+/// under the histogram with bars of width 1 and given heights.
+/// Negative heights are treated as empty bars. Runs in O(n).
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::largest_rectangle_in_histogram;
+/// assert_eq!(largest_rectangle_in_histogram(&[2, 1, 5, 6, 2, 3]), 10);
+/// ```
+pub fn largest_rectangle_in_histogram(heights: &[i64]) -> i64 {
+    let left = prev_smaller(heights);
+    let right = next_smaller(heights);
+    (0..heights.len())
+        .map(|i| {
+            let start = left[i].map_or(0, |l| l + 1);
+            let end = right[i].unwrap_or(heights.len());
+            heights[i] * (end - start) as i64
+        })
+        .fold(0, i64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        largest_rectangle_in_histogram, next_greater, next_smaller, prev_greater, prev_smaller,
+    };
+    use graph::random::XorShift;
+
+    #[test]
+    fn known_example_works() {
+        let array = [2, 1, 2, 4, 3];
+        assert_eq!(
+            next_greater(&array),
+            vec![Some(3), Some(2), Some(3), None, None]
+        );
+        assert_eq!(
+            next_smaller(&array),
+            vec![Some(1), None, None, Some(4), None]
+        );
+        assert_eq!(
+            prev_greater(&array),
+            vec![None, Some(0), None, None, Some(3)]
+        );
+        assert_eq!(
+            prev_smaller(&array),
+            vec![None, None, Some(1), Some(2), Some(2)]
+        );
+    }
+
+    #[test]
+    fn equal_elements_have_no_neighbors() {
+        let array = [7; 5];
+        assert_eq!(next_greater(&array), vec![None; 5]);
+        assert_eq!(prev_smaller(&array), vec![None; 5]);
+        assert_eq!(largest_rectangle_in_histogram(&array), 35);
+        assert_eq!(next_greater(&[]), vec![]);
+    }
+
+    #[test]
+    fn histogram_works() {
+        assert_eq!(largest_rectangle_in_histogram(&[2, 1, 5, 6, 2, 3]), 10);
+        assert_eq!(largest_rectangle_in_histogram(&[2, 4]), 4);
+        assert_eq!(largest_rectangle_in_histogram(&[1, 2, 3, 4, 5]), 9);
+        assert_eq!(largest_rectangle_in_histogram(&[]), 0);
+        assert_eq!(largest_rectangle_in_histogram(&[-3, 0]), 0);
+    }
+
+    #[test]
+    fn random_arrays_match_brute_force() {
+        let mut rng = XorShift::new(23);
+        for _ in 0..100 {
+            let array: Vec<i64> = (0..rng.next_below(30))
+                .map(|_| rng.next_below(5) as i64)
+                .collect();
+            let n = array.len();
+            let expected: Vec<Option<usize>> = (0..n)
+                .map(|i| (i + 1..n).find(|&j| array[j] > array[i]))
+                .collect();
+            assert_eq!(next_greater(&array), expected);
+            let expected: Vec<Option<usize>> = (0..n)
+                .map(|i| (0..i).rev().find(|&j| array[j] < array[i]))
+                .collect();
+            assert_eq!(prev_smaller(&array), expected);
+
+            let mut best = 0;
+            for i in 0..n {
+                for j in i + 1..=n {
+                    best = best.max(array[i..j].iter().min().unwrap() * (j - i) as i64);
+                }
+            }
+            assert_eq!(largest_rectangle_in_histogram(&array), best);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! This module defines a persistent segment tree of range sums:
+//! every update creates a new version and all versions remain
+//! available for queries.
+
+/// A node of the tree; children are indices into the arena.
+#[derive(Clone, Copy)]
+struct Node {
+    left: usize,
+    right: usize,
+    sum: i64,
+}
+
+/// The persistent segment tree over an array of integers. An update
+/// copies only the O(log n) nodes on the path to the changed leaf
+/// and shares all other nodes with the previous version, so `q`
+/// updates take O(n + q log n) space in total.
+///
+/// Persistent prefix counts are the basis of offline range k-th
+/// smallest queries: version `i` counting the values among the
+/// first `i` elements lets one binary search over the values.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::PersistentSegTree;
+/// let mut tree = PersistentSegTree::new(&[1, 2, 3, 4]);
+/// let v1 = tree.update(0, 1, 10);
+/// assert_eq!(tree.query(0, 0, 3), 10);
+/// assert_eq!(tree.query(v1, 0, 3), 18);
+/// ```
+pub struct PersistentSegTree {
+    n: usize,
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+}
+
+impl PersistentSegTree {
+    /// Return a new tree whose version 0 holds `data`. Runs in O(n).
+    pub fn new(data: &[i64]) -> PersistentSegTree {
+        let mut tree = PersistentSegTree {
+            n: data.len(),
+            nodes: Vec::with_capacity(2 * data.len()),
+            roots: Vec::new(),
+        };
+        let root = if data.is_empty() {
+            tree.push(0, 0, 0)
+        } else {
+            tree.build(data, 0, data.len())
+        };
+        tree.roots.push(root);
+        tree
+    }
+
+    /// Return the number of versions.
+    pub fn n_versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Create a new version equal to `version` with the element
+    /// at `index` set to `value`, and return its id. Runs in O(log n).
+    ///
+    /// # Panics
+    ///
+    /// If `version` does not exist or `index` is out of range.
+    ///
+    pub fn update(&mut self, version: usize, index: usize, value: i64) -> usize {
+        assert!(version < self.roots.len(), "no version {}", version);
+        assert!(index < self.n, "index {} is out of range", index);
+        let root = self.set(self.roots[version], 0, self.n, index, value);
+        self.roots.push(root);
+        self.roots.len() - 1
+    }
+
+    /// Return the sum of the elements with indices in `[lo, hi]`
+    /// in given version. Runs in O(log n).
+    ///
+    /// # Panics
+    ///
+    /// If `version` does not exist, `lo > hi` or `hi` is out of range.
+    ///
+    pub fn query(&self, version: usize, lo: usize, hi: usize) -> i64 {
+        assert!(version < self.roots.len(), "no version {}", version);
+        assert!(lo <= hi && hi < self.n, "invalid range [{}, {}]", lo, hi);
+        self.sum(self.roots[version], 0, self.n, lo, hi + 1)
+    }
+
+    /// Append a node to the arena and return its index.
+    fn push(&mut self, left: usize, right: usize, sum: i64) -> usize {
+        self.nodes.push(Node { left, right, sum });
+        self.nodes.len() - 1
+    }
+
+    /// Build the subtree over `data[lo..hi]`.
+    fn build(&mut self, data: &[i64], lo: usize, hi: usize) -> usize {
+        if hi - lo == 1 {
+            return self.push(0, 0, data[lo]);
+        }
+        let mid = (lo + hi) / 2;
+        let left = self.build(data, lo, mid);
+        let right = self.build(data, mid, hi);
+        let sum = self.nodes[left].sum + self.nodes[right].sum;
+        self.push(left, right, sum)
+    }
+
+    /// Return a copy of subtree `node` over `[lo, hi)` with
+    /// the element at `index` set to `value`.
+    fn set(&mut self, node: usize, lo: usize, hi: usize, index: usize, value: i64) -> usize {
+        if hi - lo == 1 {
+            return self.push(0, 0, value);
+        }
+        let mid = (lo + hi) / 2;
+        let Node {
+            mut left,
+            mut right,
+            ..
+        } = self.nodes[node];
+        if index < mid {
+            left = self.set(left, lo, mid, index, value);
+        } else {
+            right = self.set(right, mid, hi, index, value);
+        }
+        let sum = self.nodes[left].sum + self.nodes[right].sum;
+        self.push(left, right, sum)
+    }
+
+    /// Return the sum over `[from, to)` within subtree `node` over `[lo, hi)`.
+    fn sum(&self, node: usize, lo: usize, hi: usize, from: usize, to: usize) -> i64 {
+        if to <= lo || hi <= from {
+            return 0;
+        }
+        if from <= lo && hi <= to {
+            return self.nodes[node].sum;
+        }
+        let mid = (lo + hi) / 2;
+        self.sum(self.nodes[node].left, lo, mid, from, to)
+            + self.sum(self.nodes[node].right, mid, hi, from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PersistentSegTree;
+    use graph::random::XorShift;
+
+    #[test]
+    fn version_zero_holds_original_values() {
+        let data = [3, -1, 4, 1, -5, 9, 2];
+        let mut tree = PersistentSegTree::new(&data);
+        tree.update(0, 2, 100);
+        for (i, &x) in data.iter().enumerate() {
+            assert_eq!(tree.query(0, i, i), x);
+        }
+        assert_eq!(tree.query(0, 0, 6), 13);
+        assert_eq!(PersistentSegTree::new(&[]).n_versions(), 1);
+    }
+
+    #[test]
+    fn branching_versions_are_independent() {
+        let mut tree = PersistentSegTree::new(&[0; 5]);
+        let a = tree.update(0, 2, 7);
+        let b = tree.update(0, 2, -3);
+        let c = tree.update(a, 4, 1);
+        assert_eq!(tree.n_versions(), 4);
+        assert_eq!(tree.query(0, 0, 4), 0);
+        assert_eq!(tree.query(a, 0, 4), 7);
+        assert_eq!(tree.query(b, 0, 4), -3);
+        assert_eq!(tree.query(c, 0, 4), 8);
+        assert_eq!(tree.query(c, 3, 4), 1);
+    }
+
+    #[test]
+    fn random_versions_match_naive() {
+        let mut rng = XorShift::new(41);
+        let n = 20;
+        let mut arrays = vec![vec![0i64; n]];
+        let mut tree = PersistentSegTree::new(&arrays[0]);
+        for _ in 0..200 {
+            let version = rng.next_below(arrays.len());
+            let index = rng.next_below(n);
+            let value = rng.next_below(100) as i64 - 50;
+            let mut array = arrays[version].clone();
+            array[index] = value;
+            assert_eq!(tree.update(version, index, value), arrays.len());
+            arrays.push(array);
+        }
+        for _ in 0..500 {
+            let version = rng.next_below(arrays.len());
+            let a = rng.next_below(n);
+            let b = rng.next_below(n);
+            let (lo, hi) = (a.min(b), a.max(b));
+            assert_eq!(
+                tree.query(version, lo, hi),
+                arrays[version][lo..=hi].iter().sum::<i64>()
+            );
+        }
+    }
+}
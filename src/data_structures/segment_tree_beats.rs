@@ -0,0 +1,264 @@
+//! This module defines a segment tree beats ("Ji driver segment
+//! tree") supporting range chmax updates and range sums.
+
+/// A node of the tree: the minimum, the strict second minimum,
+/// the number of elements equal to the minimum and the sum.
+#[derive(Clone, Copy)]
+struct Node {
+    min: i64,
+    second_min: i64,
+    min_count: usize,
+    sum: i64,
+}
+
+impl Node {
+    /// Return the node of a single element.
+    fn leaf(value: i64) -> Node {
+        Node {
+            min: value,
+            second_min: i64::MAX,
+            min_count: 1,
+            sum: value,
+        }
+    }
+
+    /// Return the node with the minimal elements raised to `val`,
+    /// which must be below the second minimum.
+    fn raised(self, val: i64) -> Node {
+        if val <= self.min {
+            return self;
+        }
+        Node {
+            min: val,
+            sum: self.sum + (val - self.min) * self.min_count as i64,
+            ..self
+        }
+    }
+
+    /// Return the node of the concatenation of two ranges.
+    fn merge(left: Node, right: Node) -> Node {
+        let (min, min_count, second_min) = if left.min == right.min {
+            (
+                left.min,
+                left.min_count + right.min_count,
+                left.second_min.min(right.second_min),
+            )
+        } else if left.min < right.min {
+            (left.min, left.min_count, left.second_min.min(right.min))
+        } else {
+            (right.min, right.min_count, right.second_min.min(left.min))
+        };
+        Node {
+            min,
+            second_min,
+            min_count,
+            sum: left.sum + right.sum,
+        }
+    }
+}
+
+/// The segment tree over an array of integers supporting
+/// `a[i] = max(a[i], val)` on a range. An update stops at a node
+/// once `val` lies strictly between its minimum and second minimum,
+/// since then only the minimal elements change, by the same amount.
+/// Updates take O(log² n) amortized time, queries O(log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::SegTreeBeats;
+/// let mut tree = SegTreeBeats::new(&[5, 1, 4, 2, 3]);
+/// tree.range_chmax(0, 3, 3);
+/// assert_eq!(tree.range_sum(0, 4), 5 + 3 + 4 + 3 + 3);
+/// assert_eq!(tree.range_min(0, 4), 3);
+/// ```
+pub struct SegTreeBeats {
+    n: usize,
+    nodes: Vec<Node>,
+}
+
+impl SegTreeBeats {
+    /// Return a new tree holding `data`. Runs in O(n).
+    pub fn new(data: &[i64]) -> SegTreeBeats {
+        let n = data.len();
+        let mut tree = SegTreeBeats {
+            n,
+            nodes: vec![Node::leaf(0); 4 * n.max(1)],
+        };
+        if n > 0 {
+            tree.build(data, 1, 0, n);
+        }
+        tree
+    }
+
+    /// Replace every element with index in `[lo, hi]` by the
+    /// maximum of it and `val`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_chmax(&mut self, lo: usize, hi: usize, val: i64) {
+        assert!(lo <= hi && hi < self.n, "invalid range [{}, {}]", lo, hi);
+        self.chmax_in(1, 0, self.n, lo, hi + 1, val);
+    }
+
+    /// Return the sum of the elements with indices in `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_sum(&self, lo: usize, hi: usize) -> i64 {
+        assert!(lo <= hi && hi < self.n, "invalid range [{}, {}]", lo, hi);
+        self.query_in(1, 0, self.n, lo, hi + 1, i64::MIN).sum
+    }
+
+    /// Return the minimum of the elements with indices in `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi` or `hi` is out of range.
+    ///
+    pub fn range_min(&self, lo: usize, hi: usize) -> i64 {
+        assert!(lo <= hi && hi < self.n, "invalid range [{}, {}]", lo, hi);
+        self.query_in(1, 0, self.n, lo, hi + 1, i64::MIN).min
+    }
+
+    /// Build the subtree `node` over `data[lo..hi]`.
+    fn build(&mut self, data: &[i64], node: usize, lo: usize, hi: usize) {
+        if hi - lo == 1 {
+            self.nodes[node] = Node::leaf(data[lo]);
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.build(data, 2 * node, lo, mid);
+        self.build(data, 2 * node + 1, mid, hi);
+        self.pull(node);
+    }
+
+    /// Recompute `node` from its children.
+    fn pull(&mut self, node: usize) {
+        self.nodes[node] = Node::merge(self.nodes[2 * node], self.nodes[2 * node + 1]);
+    }
+
+    /// Raise the minimal elements of `node` to `val`.
+    fn raise_min(&mut self, node: usize, val: i64) {
+        self.nodes[node] = self.nodes[node].raised(val);
+    }
+
+    /// Pass the raised minimum of `node` down to its children.
+    fn push_down(&mut self, node: usize) {
+        let min = self.nodes[node].min;
+        self.raise_min(2 * node, min);
+        self.raise_min(2 * node + 1, min);
+    }
+
+    /// Apply chmax on `[from, to)` within subtree `node` over `[lo, hi)`.
+    fn chmax_in(&mut self, node: usize, lo: usize, hi: usize, from: usize, to: usize, val: i64) {
+        if to <= lo || hi <= from || val <= self.nodes[node].min {
+            return;
+        }
+        if from <= lo && hi <= to && val < self.nodes[node].second_min {
+            self.raise_min(node, val);
+            return;
+        }
+        self.push_down(node);
+        let mid = (lo + hi) / 2;
+        self.chmax_in(2 * node, lo, mid, from, to, val);
+        self.chmax_in(2 * node + 1, mid, hi, from, to, val);
+        self.pull(node);
+    }
+
+    /// Return the node of `[from, to)` within subtree `node` over `[lo, hi)`,
+    /// which must intersect it, with the minimum of the subtree raised
+    /// to `floor` by pending updates of its ancestors.
+    fn query_in(
+        &self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        from: usize,
+        to: usize,
+        floor: i64,
+    ) -> Node {
+        let current = self.nodes[node].raised(floor);
+        if from <= lo && hi <= to {
+            return current;
+        }
+        let mid = (lo + hi) / 2;
+        if to <= mid {
+            self.query_in(2 * node, lo, mid, from, to, current.min)
+        } else if mid <= from {
+            self.query_in(2 * node + 1, mid, hi, from, to, current.min)
+        } else {
+            Node::merge(
+                self.query_in(2 * node, lo, mid, from, to, current.min),
+                self.query_in(2 * node + 1, mid, hi, from, to, current.min),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SegTreeBeats;
+    use graph::random::XorShift;
+
+    #[test]
+    fn small_chmax_changes_nothing() {
+        let data = [4, 7, 5, 9];
+        let mut tree = SegTreeBeats::new(&data);
+        tree.range_chmax(0, 3, 4);
+        tree.range_chmax(1, 2, -10);
+        for (i, &x) in data.iter().enumerate() {
+            assert_eq!(tree.range_sum(i, i), x);
+        }
+        assert_eq!(tree.range_sum(0, 3), 25);
+    }
+
+    #[test]
+    fn chmax_updates_single_element() {
+        let mut tree = SegTreeBeats::new(&[6, 2, 8, 7]);
+        tree.range_chmax(0, 3, 5);
+        assert_eq!(tree.range_sum(0, 3), 26);
+        assert_eq!(tree.range_sum(1, 1), 5);
+        assert_eq!(tree.range_min(0, 3), 5);
+    }
+
+    #[test]
+    fn chmax_then_range_sum_works() {
+        let mut tree = SegTreeBeats::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        tree.range_chmax(2, 6, 5);
+        assert_eq!(tree.range_sum(0, 7), 1 + 2 + 5 + 5 + 5 + 6 + 7 + 8);
+        tree.range_chmax(0, 3, 6);
+        assert_eq!(tree.range_sum(0, 7), 6 * 4 + 5 + 6 + 7 + 8);
+        assert_eq!(tree.range_sum(4, 4), 5);
+    }
+
+    #[test]
+    fn random_operations_match_naive() {
+        let mut rng = XorShift::new(103);
+        for &n in [1, 2, 5, 33, 64].iter() {
+            let mut naive: Vec<i64> = (0..n).map(|_| rng.next_below(100) as i64 - 50).collect();
+            let mut tree = SegTreeBeats::new(&naive);
+            for _ in 0..500 {
+                let (a, b) = (rng.next_below(n), rng.next_below(n));
+                let (lo, hi) = (a.min(b), a.max(b));
+                match rng.next_below(3) {
+                    0 => {
+                        let val = rng.next_below(100) as i64 - 40;
+                        tree.range_chmax(lo, hi, val);
+                        for x in naive[lo..=hi].iter_mut() {
+                            *x = (*x).max(val);
+                        }
+                    }
+                    1 => assert_eq!(
+                        tree.range_min(lo, hi),
+                        *naive[lo..=hi].iter().min().unwrap()
+                    ),
+                    _ => assert_eq!(tree.range_sum(lo, hi), naive[lo..=hi].iter().sum::<i64>()),
+                }
+            }
+        }
+    }
+}
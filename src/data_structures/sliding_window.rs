@@ -0,0 +1,167 @@
+//! This module contains minimum and maximum over a sliding window
+//! maintained with a monotonic deque in O(1) amortized per element.
+
+use std::collections::VecDeque;
+
+/// Return the extremes of all windows of length `k`, where
+/// `better(a, b)` tells whether `a` beats `b`.
+fn window_extremes<F>(array: &[i64], k: usize, better: F) -> Vec<i64>
+where
+    F: Fn(i64, i64) -> bool,
+{
+    assert!(k > 0, "window size must be positive");
+    let mut deque: VecDeque<usize> = VecDeque::new();
+    let mut extremes = Vec::with_capacity((array.len() + 1).saturating_sub(k));
+    for (i, &x) in array.iter().enumerate() {
+        while deque.back().is_some_and(|&j| !better(array[j], x)) {
+            deque.pop_back();
+        }
+        deque.push_back(i);
+        if deque[0] + k <= i {
+            deque.pop_front();
+        }
+        if i + 1 >= k {
+            extremes.push(array[deque[0]]);
+        }
+    }
+    extremes
+}
+
+/// Return the minimums of all `array.len() - k + 1` windows of
+/// length `k` (empty if the array is shorter than `k`). Runs in O(n).
+///
+/// # Panics
+///
+/// If `k` is zero.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::sliding_window_min;
+/// assert_eq!(sliding_window_min(&[4, 2, 12, 3, 8, 1], 3), vec![2, 2, 3, 1]);
+/// ```
+pub fn sliding_window_min(array: &[i64], k: usize) -> Vec<i64> {
+    window_extremes(array, k, |a, b| a < b)
+}
+
+/// Return the maximums of all `array.len() - k + 1` windows of
+/// length `k` (empty if the array is shorter than `k`). Runs in O(n).
+///
+/// # Panics
+///
+/// If `k` is zero.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::sliding_window_max;
+/// assert_eq!(sliding_window_max(&[4, 2, 12, 3, 8, 1], 3), vec![12, 12, 12, 8]);
+/// ```
+pub fn sliding_window_max(array: &[i64], k: usize) -> Vec<i64> {
+    window_extremes(array, k, |a, b| a > b)
+}
+
+/// The minimum of the last `k` values of a stream.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::SlidingWindowMin;
+/// let mut window = SlidingWindowMin::new(2);
+/// assert_eq!(window.current_min(), None);
+/// window.push(5);
+/// window.push(3);
+/// assert_eq!(window.current_min(), Some(3));
+/// window.push(7);
+/// assert_eq!(window.current_min(), Some(3));
+/// window.push(9);
+/// assert_eq!(window.current_min(), Some(7));
+/// ```
+pub struct SlidingWindowMin {
+    k: usize,
+    pushed: usize,
+    deque: VecDeque<(usize, i64)>,
+}
+
+impl SlidingWindowMin {
+    /// Return a new empty instance of SlidingWindowMin.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - the window size.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is zero.
+    ///
+    pub fn new(k: usize) -> SlidingWindowMin {
+        assert!(k > 0, "window size must be positive");
+        SlidingWindowMin {
+            k,
+            pushed: 0,
+            deque: VecDeque::new(),
+        }
+    }
+
+    /// Append `val` to the stream, evicting the value that falls
+    /// out of the window. Amortized O(1).
+    pub fn push(&mut self, val: i64) {
+        while self.deque.back().is_some_and(|&(_, x)| x >= val) {
+            self.deque.pop_back();
+        }
+        self.deque.push_back((self.pushed, val));
+        self.pushed += 1;
+        if self.deque[0].0 + self.k < self.pushed {
+            self.deque.pop_front();
+        }
+    }
+
+    /// Return the minimum of the last `k` pushed values (of all
+    /// of them if fewer were pushed), or `None` if nothing was pushed.
+    pub fn current_min(&self) -> Option<i64> {
+        self.deque.front().map(|&(_, x)| x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sliding_window_max, sliding_window_min, SlidingWindowMin};
+    use graph::random::XorShift;
+
+    #[test]
+    fn window_edge_sizes_work() {
+        let array = [3, -1, 4, 1, -5, 9];
+        assert_eq!(sliding_window_min(&array, 1), array.to_vec());
+        assert_eq!(sliding_window_max(&array, 1), array.to_vec());
+        assert_eq!(sliding_window_min(&array, 6), vec![-5]);
+        assert_eq!(sliding_window_max(&array, 6), vec![9]);
+        assert_eq!(sliding_window_min(&array, 7), vec![]);
+        assert_eq!(sliding_window_max(&[], 2), vec![]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_window_panics() {
+        sliding_window_min(&[1, 2], 0);
+    }
+
+    #[test]
+    fn random_arrays_match_brute_force() {
+        let mut rng = XorShift::new(29);
+        for _ in 0..200 {
+            let array: Vec<i64> = (0..rng.next_below(40))
+                .map(|_| rng.next_below(10) as i64 - 5)
+                .collect();
+            let k = 1 + rng.next_below(8);
+            let windows: Vec<&[i64]> = array.windows(k).collect();
+            let mins: Vec<i64> = windows.iter().map(|w| *w.iter().min().unwrap()).collect();
+            let maxs: Vec<i64> = windows.iter().map(|w| *w.iter().max().unwrap()).collect();
+            assert_eq!(sliding_window_min(&array, k), mins);
+            assert_eq!(sliding_window_max(&array, k), maxs);
+
+            let mut stream = SlidingWindowMin::new(k);
+            for (i, &x) in array.iter().enumerate() {
+                stream.push(x);
+                let start = (i + 1).saturating_sub(k);
+                assert_eq!(stream.current_min(), array[start..=i].iter().min().cloned());
+            }
+        }
+    }
+}
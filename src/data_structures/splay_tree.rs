@@ -0,0 +1,415 @@
+//! This module defines a splay tree: a self-adjusting binary
+//! search tree that moves every accessed key to the root, which
+//! makes all operations O(log n) amortized.
+
+type Link = Option<Box<Node>>;
+
+/// A node of the splay tree with the size of its subtree.
+struct Node {
+    key: i64,
+    value: i64,
+    size: usize,
+    left: Link,
+    right: Link,
+}
+
+/// Return the number of keys under `link`.
+fn size(link: &Link) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+/// Recompute the subtree size of `node` from its children.
+fn update(node: &mut Node) {
+    node.size = 1 + size(&node.left) + size(&node.right);
+}
+
+/// Lift the left child of `node` above it.
+fn rotate_right(mut node: Box<Node>) -> Box<Node> {
+    let mut left = node.left.take().unwrap();
+    node.left = left.right.take();
+    update(&mut node);
+    left.right = Some(node);
+    update(&mut left);
+    left
+}
+
+/// Lift the right child of `node` above it.
+fn rotate_left(mut node: Box<Node>) -> Box<Node> {
+    let mut right = node.right.take().unwrap();
+    node.right = right.left.take();
+    update(&mut node);
+    right.left = Some(node);
+    update(&mut right);
+    right
+}
+
+/// Move the node with `key` to the root of `link`. If there is no
+/// such key, the last node on the search path becomes the root:
+/// either the predecessor or the successor of `key`.
+///
+/// A grandchild on the same side as its parent (zig-zig) is lifted
+/// by rotating the grandparent first and then the parent, and one
+/// on the opposite side (zig-zag) by rotating the parent first; a
+/// child of the root (zig) takes a single rotation.
+fn splay(link: Link, key: i64) -> Link {
+    let mut root = link?;
+    if key < root.key {
+        let mut left = match root.left.take() {
+            None => return Some(root),
+            Some(left) => left,
+        };
+        if key < left.key {
+            left.left = splay(left.left.take(), key);
+            root.left = Some(left);
+            root = rotate_right(root);
+        } else if key > left.key {
+            left.right = splay(left.right.take(), key);
+            if left.right.is_some() {
+                left = rotate_left(left);
+            }
+            root.left = Some(left);
+        } else {
+            root.left = Some(left);
+        }
+        if root.left.is_some() {
+            root = rotate_right(root);
+        }
+    } else if key > root.key {
+        let mut right = match root.right.take() {
+            None => return Some(root),
+            Some(right) => right,
+        };
+        if key > right.key {
+            right.right = splay(right.right.take(), key);
+            root.right = Some(right);
+            root = rotate_left(root);
+        } else if key < right.key {
+            right.left = splay(right.left.take(), key);
+            if right.left.is_some() {
+                right = rotate_right(right);
+            }
+            root.right = Some(right);
+        } else {
+            root.right = Some(right);
+        }
+        if root.right.is_some() {
+            root = rotate_left(root);
+        }
+    }
+    Some(root)
+}
+
+/// An ordered map from integer keys to integer values. Every
+/// operation splays the key it works with to the root, so keys
+/// accessed often stay near the top of the tree.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::SplayTree;
+/// let mut tree = SplayTree::new();
+/// for &key in [5, 1, 4, 2, 3].iter() {
+///     tree.insert(key, 10 * key);
+/// }
+/// assert_eq!(tree.get(4), Some(40));
+/// assert_eq!(tree.kth_key(1), Some(2));
+/// let mut large = tree.split_at(3);
+/// assert_eq!(tree.len(), 2);
+/// assert_eq!(large.kth_key(0), Some(3));
+/// tree.merge(large);
+/// assert_eq!(tree.len(), 5);
+/// ```
+pub struct SplayTree {
+    root: Link,
+}
+
+impl SplayTree {
+    /// Return a new empty SplayTree.
+    pub fn new() -> SplayTree {
+        SplayTree { root: None }
+    }
+
+    /// Return the number of keys in the tree.
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Return `true` if the tree has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Associate `value` with `key`, replacing the previous value
+    /// if `key` is already present.
+    pub fn insert(&mut self, key: i64, value: i64) {
+        let mut node = match splay(self.root.take(), key) {
+            Some(mut root) if root.key == key => {
+                root.value = value;
+                self.root = Some(root);
+                return;
+            }
+            Some(mut root) => {
+                let mut node = Box::new(Node {
+                    key,
+                    value,
+                    size: 1,
+                    left: None,
+                    right: None,
+                });
+                if root.key < key {
+                    node.right = root.right.take();
+                    update(&mut root);
+                    node.left = Some(root);
+                } else {
+                    node.left = root.left.take();
+                    update(&mut root);
+                    node.right = Some(root);
+                }
+                node
+            }
+            None => Box::new(Node {
+                key,
+                value,
+                size: 1,
+                left: None,
+                right: None,
+            }),
+        };
+        update(&mut node);
+        self.root = Some(node);
+    }
+
+    /// Return the value associated with `key`, or `None` if it is
+    /// not present. The key is splayed to the root.
+    pub fn get(&mut self, key: i64) -> Option<i64> {
+        self.root = splay(self.root.take(), key);
+        self.root
+            .as_ref()
+            .filter(|root| root.key == key)
+            .map(|root| root.value)
+    }
+
+    /// Remove `key` from the tree and return its value,
+    /// or `None` if it was not present.
+    pub fn delete(&mut self, key: i64) -> Option<i64> {
+        self.root = splay(self.root.take(), key);
+        match self.root.take() {
+            Some(root) if root.key == key => {
+                let root = *root;
+                // Every key on the left is less than `key`, so
+                // splaying it brings the maximum up with no right child.
+                self.root = match splay(root.left, key) {
+                    None => root.right,
+                    Some(mut left) => {
+                        left.right = root.right;
+                        update(&mut left);
+                        Some(left)
+                    }
+                };
+                Some(root.value)
+            }
+            other => {
+                self.root = other;
+                None
+            }
+        }
+    }
+
+    /// Move all keys greater or equal to `key` into a new tree
+    /// and return it.
+    pub fn split_at(&mut self, key: i64) -> SplayTree {
+        let mut root = match splay(self.root.take(), key) {
+            None => return SplayTree::new(),
+            Some(root) => root,
+        };
+        if root.key >= key {
+            self.root = root.left.take();
+            update(&mut root);
+            SplayTree { root: Some(root) }
+        } else {
+            let right = root.right.take();
+            update(&mut root);
+            self.root = Some(root);
+            SplayTree { root: right }
+        }
+    }
+
+    /// Move all keys of `other` into this tree.
+    ///
+    /// # Panics
+    ///
+    /// If some key of this tree is not less than every key of `other`.
+    ///
+    pub fn merge(&mut self, other: SplayTree) {
+        let right = match splay(other.root, i64::MIN) {
+            None => return,
+            Some(right) => right,
+        };
+        self.root = match splay(self.root.take(), i64::MAX) {
+            None => Some(right),
+            Some(mut max) => {
+                assert!(
+                    max.key < right.key,
+                    "keys of the left tree must precede the right ones"
+                );
+                max.right = Some(right);
+                update(&mut max);
+                Some(max)
+            }
+        };
+    }
+
+    /// Return the `k`-th smallest key, counting from 0, or `None`
+    /// if there are at most `k` keys. The key is splayed to the root.
+    pub fn kth_key(&mut self, mut k: usize) -> Option<i64> {
+        let mut link = &self.root;
+        let key = loop {
+            let node = link.as_ref()?;
+            let left_size = size(&node.left);
+            if k == left_size {
+                break node.key;
+            }
+            if k < left_size {
+                link = &node.left;
+            } else {
+                k -= left_size + 1;
+                link = &node.right;
+            }
+        };
+        self.root = splay(self.root.take(), key);
+        Some(key)
+    }
+}
+
+impl Default for SplayTree {
+    fn default() -> SplayTree {
+        SplayTree::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Link, SplayTree};
+    use graph::random::XorShift;
+
+    fn shuffled(keys: &mut [i64], seed: u64) {
+        let mut rng = XorShift::new(seed);
+        for i in (1..keys.len()).rev() {
+            keys.swap(i, rng.next_below(i + 1));
+        }
+    }
+
+    /// Return the number of edges between the root and `key`.
+    fn depth(tree: &SplayTree, key: i64) -> usize {
+        let mut link: &Link = &tree.root;
+        let mut depth = 0;
+        while let Some(node) = link {
+            if node.key == key {
+                return depth;
+            }
+            link = if key < node.key {
+                &node.left
+            } else {
+                &node.right
+            };
+            depth += 1;
+        }
+        panic!("key {} is missing", key);
+    }
+
+    #[test]
+    fn insert_get_and_delete_work() {
+        let mut keys: Vec<i64> = (1..=100).collect();
+        shuffled(&mut keys, 11);
+        let mut tree = SplayTree::new();
+        for &key in keys.iter() {
+            tree.insert(key, -key);
+        }
+        assert_eq!(tree.len(), 100);
+        for key in 1..=100 {
+            assert_eq!(tree.get(key), Some(-key));
+        }
+        assert_eq!(tree.get(0), None);
+        assert_eq!(tree.get(101), None);
+
+        tree.insert(50, 7);
+        assert_eq!(tree.len(), 100);
+        assert_eq!(tree.get(50), Some(7));
+        assert_eq!(tree.delete(50), Some(7));
+        assert_eq!(tree.delete(50), None);
+        assert_eq!(tree.get(50), None);
+        assert_eq!(tree.get(49), Some(-49));
+        assert_eq!(tree.len(), 99);
+
+        let mut empty = SplayTree::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.get(0), None);
+        assert_eq!(empty.delete(0), None);
+        assert_eq!(empty.kth_key(0), None);
+    }
+
+    #[test]
+    fn split_merge_and_kth_work() {
+        let mut keys: Vec<i64> = (1..=100).collect();
+        shuffled(&mut keys, 13);
+        let mut tree = SplayTree::new();
+        for &key in keys.iter() {
+            tree.insert(key, 0);
+        }
+        let mut large = tree.split_at(50);
+        assert_eq!(tree.len(), 49);
+        assert_eq!(large.len(), 51);
+        for k in 0..49 {
+            assert_eq!(tree.kth_key(k), Some(k as i64 + 1));
+        }
+        for k in 0..51 {
+            assert_eq!(large.kth_key(k), Some(k as i64 + 50));
+        }
+        assert_eq!(tree.kth_key(49), None);
+        assert_eq!(tree.get(50), None);
+        assert_eq!(large.get(49), None);
+
+        tree.merge(large);
+        assert_eq!(tree.len(), 100);
+        for k in 0..100 {
+            assert_eq!(tree.kth_key(k), Some(k as i64 + 1));
+        }
+        assert_eq!(tree.split_at(1000).len(), 0);
+        assert_eq!(tree.split_at(i64::MIN).len(), 100);
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn overlapping_merge_panics() {
+        let (mut left, mut right) = (SplayTree::new(), SplayTree::new());
+        left.insert(5, 0);
+        right.insert(3, 0);
+        left.merge(right);
+    }
+
+    #[test]
+    fn access_cost_is_logarithmic_amortized() {
+        // Inserting increasing keys degenerates the tree into a path,
+        // yet the total depth of many accesses stays O((m + n) log n).
+        let n = 1 << 10;
+        let mut tree = SplayTree::new();
+        for key in 0..n {
+            tree.insert(key, key);
+        }
+        assert_eq!(depth(&tree, 0), n as usize - 1);
+
+        let mut rng = XorShift::new(17);
+        let m = 20_000;
+        let mut total = 0;
+        for i in 0..m {
+            let key = if i < n as usize {
+                i as i64
+            } else {
+                rng.next_below(n as usize) as i64
+            };
+            total += depth(&tree, key);
+            assert_eq!(tree.get(key), Some(key));
+        }
+        assert!(total <= 3 * (m + n as usize) * 10);
+    }
+}
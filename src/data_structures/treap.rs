@@ -0,0 +1,347 @@
+//! This module defines a treap: a binary search tree of keys
+//! that is simultaneously a heap of random priorities, which
+//! keeps it balanced in expectation. All operations are built
+//! on top of split and merge.
+
+use graph::random::XorShift;
+
+type Link = Option<Box<Node>>;
+
+/// A node of the treap with the size of its subtree.
+struct Node {
+    key: i64,
+    priority: u64,
+    size: usize,
+    left: Link,
+    right: Link,
+}
+
+/// Return the number of keys under `link`.
+fn size(link: &Link) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+/// Recompute the subtree size of `node` from its children.
+fn update(node: &mut Node) {
+    node.size = 1 + size(&node.left) + size(&node.right);
+}
+
+/// Split `link` into the keys satisfying `goes_left`, which must be
+/// monotone (true for a prefix of keys), and all other keys.
+fn split_by<F: Fn(i64) -> bool>(link: Link, goes_left: &F) -> (Link, Link) {
+    match link {
+        None => (None, None),
+        Some(mut node) => {
+            if goes_left(node.key) {
+                let (left, right) = split_by(node.right.take(), goes_left);
+                node.right = left;
+                update(&mut node);
+                (Some(node), right)
+            } else {
+                let (left, right) = split_by(node.left.take(), goes_left);
+                node.left = right;
+                update(&mut node);
+                (left, Some(node))
+            }
+        }
+    }
+}
+
+/// Merge two treaps, all keys of `left` being less than those of `right`.
+fn merge_links(left: Link, right: Link) -> Link {
+    match (left, right) {
+        (None, right) => right,
+        (left, None) => left,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge_links(left.right.take(), Some(right));
+                update(&mut left);
+                Some(left)
+            } else {
+                right.left = merge_links(Some(left), right.left.take());
+                update(&mut right);
+                Some(right)
+            }
+        }
+    }
+}
+
+/// An ordered set of integer keys with O(log n) expected time
+/// operations. Priorities come from a seeded generator, so the
+/// shape of the tree is deterministic.
+///
+/// # Examples
+/// ```
+/// use ralgo::data_structures::Treap;
+/// let mut treap = Treap::new();
+/// for key in [5, 1, 4, 2, 3].iter() {
+///     treap.insert(*key);
+/// }
+/// assert!(treap.contains(4));
+/// assert_eq!(treap.kth_smallest(1), Some(2));
+/// let (small, large) = treap.split(3);
+/// assert_eq!(small.iter().collect::<Vec<_>>(), vec![1, 2]);
+/// assert_eq!(large.iter().collect::<Vec<_>>(), vec![3, 4, 5]);
+/// ```
+pub struct Treap {
+    root: Link,
+    rng: XorShift,
+}
+
+impl Treap {
+    /// Return a new empty Treap with the default seed.
+    pub fn new() -> Treap {
+        Treap::with_seed(0)
+    }
+
+    /// Return a new empty Treap whose priorities are generated
+    /// from given seed.
+    pub fn with_seed(seed: u64) -> Treap {
+        Treap {
+            root: None,
+            rng: XorShift::new(seed),
+        }
+    }
+
+    /// Return the number of keys in the treap.
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Return `true` if the treap has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Add `key` to the treap. Adding a present key has no effect.
+    pub fn insert(&mut self, key: i64) {
+        if self.contains(key) {
+            return;
+        }
+        let node = Box::new(Node {
+            key,
+            priority: self.rng.next_u64(),
+            size: 1,
+            left: None,
+            right: None,
+        });
+        let (left, right) = split_by(self.root.take(), &|k| k < key);
+        self.root = merge_links(merge_links(left, Some(node)), right);
+    }
+
+    /// Remove `key` from the treap. Return `true` if it was present.
+    pub fn delete(&mut self, key: i64) -> bool {
+        let (left, rest) = split_by(self.root.take(), &|k| k < key);
+        let (found, right) = split_by(rest, &|k| k <= key);
+        self.root = merge_links(left, right);
+        found.is_some()
+    }
+
+    /// Return `true` if `key` is in the treap.
+    pub fn contains(&self, key: i64) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            if key == node.key {
+                return true;
+            }
+            link = if key < node.key {
+                &node.left
+            } else {
+                &node.right
+            };
+        }
+        false
+    }
+
+    /// Split the treap into the keys less than `key` and the keys
+    /// greater or equal to `key`. The second treap continues the
+    /// sequence of priorities with a fresh seed.
+    pub fn split(mut self, key: i64) -> (Treap, Treap) {
+        let (left, right) = split_by(self.root.take(), &|k| k < key);
+        let seed = self.rng.next_u64();
+        let right = Treap {
+            root: right,
+            rng: XorShift::new(seed),
+        };
+        self.root = left;
+        (self, right)
+    }
+
+    /// Return the union of two treaps.
+    ///
+    /// # Panics
+    ///
+    /// If some key of `left` is not less than every key of `right`.
+    ///
+    pub fn merge(mut left: Treap, right: Treap) -> Treap {
+        if let (Some(max), Some(min)) = (left.iter().last(), right.iter().next()) {
+            assert!(
+                max < min,
+                "keys of the left treap must precede the right ones"
+            );
+        }
+        left.root = merge_links(left.root.take(), right.root);
+        left
+    }
+
+    /// Return the `k`-th smallest key, counting from 0, or `None`
+    /// if there are at most `k` keys. Runs in O(log n) expected
+    /// using subtree sizes.
+    pub fn kth_smallest(&self, mut k: usize) -> Option<i64> {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let left_size = size(&node.left);
+            if k == left_size {
+                return Some(node.key);
+            }
+            if k < left_size {
+                link = &node.left;
+            } else {
+                k -= left_size + 1;
+                link = &node.right;
+            }
+        }
+        None
+    }
+
+    /// Return an iterator over the keys in increasing order.
+    pub fn iter(&self) -> Iter<'_> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl Default for Treap {
+    fn default() -> Treap {
+        Treap::new()
+    }
+}
+
+/// The iterator returned by `Treap::iter`.
+pub struct Iter<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iter<'a> {
+    /// Push `link` and the chain of its left descendants.
+    fn push_left(&mut self, mut link: &'a Link) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(node.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Treap;
+    use graph::random::XorShift;
+
+    fn shuffled(keys: &mut [i64], seed: u64) {
+        let mut rng = XorShift::new(seed);
+        for i in (1..keys.len()).rev() {
+            keys.swap(i, rng.next_below(i + 1));
+        }
+    }
+
+    fn random_treap() -> Treap {
+        let mut keys: Vec<i64> = (1..=1000).collect();
+        shuffled(&mut keys, 7);
+        let mut treap = Treap::with_seed(3);
+        for &key in keys.iter() {
+            treap.insert(key);
+        }
+        treap
+    }
+
+    #[test]
+    fn insert_delete_and_iterate_work() {
+        let mut treap = random_treap();
+        assert_eq!(treap.len(), 1000);
+        assert_eq!(
+            treap.iter().collect::<Vec<_>>(),
+            (1..=1000).collect::<Vec<_>>()
+        );
+        treap.insert(500);
+        assert_eq!(treap.len(), 1000);
+
+        assert!(treap.delete(500));
+        assert!(!treap.delete(500));
+        assert!(!treap.delete(1001));
+        assert!(!treap.contains(500));
+        assert!(treap.contains(499));
+        assert_eq!(treap.len(), 999);
+
+        let empty = Treap::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter().next(), None);
+        assert_eq!(empty.kth_smallest(0), None);
+    }
+
+    #[test]
+    fn split_and_merge_work() {
+        let (small, large) = random_treap().split(500);
+        assert_eq!(
+            small.iter().collect::<Vec<_>>(),
+            (1..500).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            large.iter().collect::<Vec<_>>(),
+            (500..=1000).collect::<Vec<_>>()
+        );
+        assert_eq!(small.kth_smallest(10), Some(11));
+        assert_eq!(large.kth_smallest(10), Some(510));
+
+        let mut merged = Treap::merge(small, large);
+        assert_eq!(merged.len(), 1000);
+        assert_eq!(
+            merged.iter().collect::<Vec<_>>(),
+            (1..=1000).collect::<Vec<_>>()
+        );
+        merged.insert(0);
+        assert_eq!(merged.kth_smallest(0), Some(0));
+
+        let (empty, all) = merged.split(i64::MIN);
+        assert!(empty.is_empty());
+        assert_eq!(all.len(), 1001);
+    }
+
+    #[test]
+    #[should_panic]
+    fn overlapping_merge_panics() {
+        let (mut left, mut right) = (Treap::new(), Treap::new());
+        left.insert(5);
+        right.insert(3);
+        Treap::merge(left, right);
+    }
+
+    #[test]
+    fn kth_smallest_matches_sorted_vec() {
+        let mut rng = XorShift::new(31);
+        let mut treap = Treap::new();
+        let mut keys = Vec::new();
+        for _ in 0..500 {
+            let key = rng.next_below(2000) as i64 - 1000;
+            treap.insert(key);
+            keys.push(key);
+        }
+        keys.sort();
+        keys.dedup();
+        assert_eq!(treap.len(), keys.len());
+        for (k, &key) in keys.iter().enumerate() {
+            assert_eq!(treap.kth_smallest(k), Some(key));
+        }
+        assert_eq!(treap.kth_smallest(keys.len()), None);
+    }
+}
@@ -0,0 +1,199 @@
+//! This module contains the divide and conquer search for the
+//! closest pair of points in the plane.
+
+/// Return the indices `(i, j)`, `i < j`, of two points with the
+/// smallest Euclidean distance, or `None` if there are fewer than
+/// two points. If several pairs are equally close, any of them may
+/// be returned. Squared distances must fit in `i64`.
+///
+/// Implements the O(n log n) divide and conquer algorithm
+/// in exact integer arithmetic.
+///
+/// # Examples
+/// ```
+/// use ralgo::geometry::closest_pair;
+/// let points = [(0, 0), (10, 10), (3, 4), (11, 9)];
+/// assert_eq!(closest_pair(&points), Some((1, 3)));
+/// assert_eq!(closest_pair(&[(1, 1)]), None);
+/// ```
+pub fn closest_pair(points: &[(i64, i64)]) -> Option<(usize, usize)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by_key(|&i| points[i]);
+    let mut best = Best {
+        points,
+        distance: i64::MAX,
+        pair: (0, 1),
+    };
+    best.search(&mut order);
+    let (i, j) = best.pair;
+    Some((i.min(j), i.max(j)))
+}
+
+/// Return the squared Euclidean distance between the closest pair
+/// of points, or `None` if there are fewer than two points.
+///
+/// # Examples
+/// ```
+/// use ralgo::geometry::closest_pair_distance_squared;
+/// assert_eq!(closest_pair_distance_squared(&[(0, 0), (3, 4), (9, 9)]), Some(25));
+/// ```
+pub fn closest_pair_distance_squared(points: &[(i64, i64)]) -> Option<i64> {
+    closest_pair(points).map(|(i, j)| distance_squared(points[i], points[j]))
+}
+
+/// Return the squared Euclidean distance between `a` and `b`.
+fn distance_squared(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0) * (a.0 - b.0) + (a.1 - b.1) * (a.1 - b.1)
+}
+
+/// The closest pair found so far.
+struct Best<'a> {
+    points: &'a [(i64, i64)],
+    distance: i64,
+    pair: (usize, usize),
+}
+
+impl<'a> Best<'a> {
+    /// Remember the pair `(i, j)` if it is closer than the best one.
+    fn update(&mut self, i: usize, j: usize) {
+        let distance = distance_squared(self.points[i], self.points[j]);
+        if distance < self.distance {
+            self.distance = distance;
+            self.pair = (i, j);
+        }
+    }
+
+    /// Find the closest pair among `order`, which is sorted by x,
+    /// and leave `order` sorted by y.
+    fn search(&mut self, order: &mut [usize]) {
+        let points = self.points;
+        let n = order.len();
+        if n <= 3 {
+            for a in 0..n {
+                for b in a + 1..n {
+                    self.update(order[a], order[b]);
+                }
+            }
+            order.sort_by_key(|&i| points[i].1);
+            return;
+        }
+
+        let mid = n / 2;
+        let mid_x = points[order[mid]].0;
+        self.search(&mut order[..mid]);
+        self.search(&mut order[mid..]);
+
+        let mut merged = Vec::with_capacity(n);
+        let (mut a, mut b) = (0, mid);
+        while a < mid || b < n {
+            if b == n || (a < mid && points[order[a]].1 <= points[order[b]].1) {
+                merged.push(order[a]);
+                a += 1;
+            } else {
+                merged.push(order[b]);
+                b += 1;
+            }
+        }
+        order.copy_from_slice(&merged);
+
+        // Only points close to the dividing line may form a closer
+        // pair, and each has O(1) candidates above it in the strip.
+        let mut strip: Vec<usize> = Vec::new();
+        for &i in order.iter() {
+            let dx = points[i].0 - mid_x;
+            if dx * dx >= self.distance {
+                continue;
+            }
+            for &j in strip.iter().rev() {
+                let dy = points[i].1 - points[j].1;
+                if dy * dy >= self.distance {
+                    break;
+                }
+                self.update(j, i);
+            }
+            strip.push(i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest_pair, closest_pair_distance_squared, distance_squared};
+    use graph::random::XorShift;
+
+    fn brute_force(points: &[(i64, i64)]) -> Option<i64> {
+        let mut best = None;
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                let d = distance_squared(points[i], points[j]);
+                best = Some(best.map_or(d, |b: i64| b.min(d)));
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn trivial_inputs_work() {
+        assert_eq!(closest_pair(&[]), None);
+        assert_eq!(closest_pair_distance_squared(&[(3, 3)]), None);
+        assert_eq!(closest_pair(&[(5, 5), (-1, 2)]), Some((0, 1)));
+        assert_eq!(closest_pair_distance_squared(&[(5, 5), (-1, 2)]), Some(45));
+        assert_eq!(
+            closest_pair_distance_squared(&[(1, 1), (7, 7), (1, 1)]),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn pair_across_dividing_line_is_found() {
+        let points = [
+            (0, 0),
+            (1, 10),
+            (2, 20),
+            (49, 5),
+            (51, 6),
+            (100, 0),
+            (99, 20),
+            (98, 40),
+        ];
+        assert_eq!(closest_pair(&points), Some((3, 4)));
+
+        let mut grid = Vec::new();
+        for x in 0..10 {
+            for y in 0..10 {
+                grid.push((x * 10, y * 10));
+            }
+        }
+        grid.push((43, 57));
+        assert_eq!(closest_pair(&grid), Some((46, 100)));
+    }
+
+    #[test]
+    fn equidistant_pairs_work() {
+        let points: Vec<(i64, i64)> = (0..20).map(|i| (3 * i, 4 * i)).collect();
+        let (i, j) = closest_pair(&points).unwrap();
+        assert!(i < j);
+        assert_eq!(distance_squared(points[i], points[j]), 25);
+    }
+
+    #[test]
+    fn random_points_match_brute_force() {
+        let mut rng = XorShift::new(13);
+        for _ in 0..200 {
+            let n = rng.next_below(60);
+            let range = 1 + rng.next_below(1000);
+            let points: Vec<(i64, i64)> = (0..n)
+                .map(|_| {
+                    (
+                        rng.next_below(range) as i64 - 500,
+                        rng.next_below(range) as i64 - 500,
+                    )
+                })
+                .collect();
+            assert_eq!(closest_pair_distance_squared(&points), brute_force(&points));
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! This module contains the convex hull of a planar point set
+//! (Graham scan) and helpers for convex polygons. All computations
+//! are done in exact integer arithmetic: cross products are taken
+//! in i128, which is exact for coordinates of absolute value below
+//! 2^62.
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+/// Return the cross product of vectors `b - a` and `c - a`:
+/// positive if `a -> b -> c` turns counter-clockwise, negative
+/// if clockwise and zero if the points are collinear.
+fn cross(a: (i64, i64), b: (i64, i64), c: (i64, i64)) -> i128 {
+    cross_vectors(vector(a, b), vector(a, c))
+}
+
+/// Return the vector from `a` to `b`.
+fn vector(a: (i64, i64), b: (i64, i64)) -> (i128, i128) {
+    (b.0 as i128 - a.0 as i128, b.1 as i128 - a.1 as i128)
+}
+
+/// Return the cross product of vectors `u` and `v`.
+fn cross_vectors(u: (i128, i128), v: (i128, i128)) -> i128 {
+    u.0 * v.1 - u.1 * v.0
+}
+
+/// Return the indices of the vertices of the convex hull of `points`
+/// in counter-clockwise order, starting from the lowest (then
+/// leftmost) point. Points lying on hull edges and duplicates are
+/// not reported, so collinear input yields its two endpoints and
+/// a single distinct point yields one index.
+///
+/// Implements Graham scan in O(n log n). The result is exact as long
+/// as all coordinates have absolute value below 2^62.
+///
+/// # Examples
+/// ```
+/// use ralgo::geometry::convex_hull;
+/// let points = [(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 0)];
+/// assert_eq!(convex_hull(&points), vec![0, 1, 3, 4]);
+/// ```
+pub fn convex_hull(points: &[(i64, i64)]) -> Vec<usize> {
+    let pivot = match (0..points.len()).min_by_key(|&i| (points[i].1, points[i].0)) {
+        Some(pivot) => pivot,
+        None => return Vec::new(),
+    };
+    let origin = points[pivot];
+    let distance = |p: (i64, i64)| {
+        let (dx, dy) = vector(origin, p);
+        dx.abs() + dy.abs()
+    };
+
+    // All other points lie in the upper half-plane around the pivot,
+    // so the cross product orders them by polar angle.
+    let mut order: Vec<usize> = (0..points.len()).filter(|&i| points[i] != origin).collect();
+    order.sort_by(|&a, &b| match cross(origin, points[a], points[b]) {
+        0 => distance(points[a]).cmp(&distance(points[b])),
+        turn if turn > 0 => Ordering::Less,
+        _ => Ordering::Greater,
+    });
+
+    let mut hull = vec![pivot];
+    for i in order {
+        while hull.len() >= 2
+            && cross(
+                points[hull[hull.len() - 2]],
+                points[hull[hull.len() - 1]],
+                points[i],
+            ) <= 0
+        {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    hull
+}
+
+/// Return `true` if `points`, taken in order, are the vertices of
+/// a convex polygon in either orientation. Collinear consecutive
+/// vertices are allowed, but the polygon must have a positive area,
+/// no repeated consecutive vertices and must not wind around more
+/// than once. Exact for coordinates of absolute value below 2^62.
+///
+/// # Examples
+/// ```
+/// use ralgo::geometry::is_convex_polygon;
+/// assert!(is_convex_polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]));
+/// assert!(!is_convex_polygon(&[(0, 0), (2, 0), (1, 1), (2, 2), (0, 2)]));
+/// ```
+pub fn is_convex_polygon(points: &[(i64, i64)]) -> bool {
+    let n = points.len();
+    if n < 3 {
+        return false;
+    }
+    let edges: Vec<(i128, i128)> = (0..n)
+        .map(|i| vector(points[i], points[(i + 1) % n]))
+        .collect();
+    if edges.contains(&(0, 0)) {
+        return false;
+    }
+
+    let turns: Vec<i128> = (0..n)
+        .map(|i| cross_vectors(edges[i], edges[(i + 1) % n]))
+        .collect();
+    let orientation = match turns.iter().find(|&&t| t != 0) {
+        Some(&t) => t.signum(),
+        None => return false,
+    };
+    if turns.iter().any(|&t| t.signum() == -orientation) {
+        return false;
+    }
+
+    // The edge directions of a convex polygon go around exactly once.
+    let half = |v: (i128, i128)| (v.1 < 0 || (v.1 == 0 && v.0 < 0)) as u8;
+    let angle_less = |u: (i128, i128), v: (i128, i128)| {
+        half(u) < half(v) || (half(u) == half(v) && cross_vectors(u, v) > 0)
+    };
+    let wraps = (0..n)
+        .filter(|&i| {
+            let (u, v) = (edges[i], edges[(i + 1) % n]);
+            if orientation > 0 {
+                angle_less(v, u)
+            } else {
+                angle_less(u, v)
+            }
+        })
+        .count();
+    wraps == 1
+}
+
+/// Return twice the area of the polygon with given vertices
+/// in either orientation, computed with the shoelace formula.
+/// The sum is accumulated in i128.
+///
+/// # Panics
+///
+/// If twice the area does not fit in i64.
+///
+/// # Examples
+/// ```
+/// use ralgo::geometry::polygon_area_times_two;
+/// assert_eq!(polygon_area_times_two(&[(0, 0), (3, 0), (0, 1)]), 3);
+/// ```
+pub fn polygon_area_times_two(hull: &[(i64, i64)]) -> i64 {
+    let n = hull.len();
+    let area = (0..n)
+        .map(|i| {
+            let (a, b) = (hull[i], hull[(i + 1) % n]);
+            a.0 as i128 * b.1 as i128 - a.1 as i128 * b.0 as i128
+        })
+        .sum::<i128>()
+        .abs();
+    i64::try_from(area).expect("twice the area does not fit in i64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convex_hull, is_convex_polygon, polygon_area_times_two};
+
+    fn hull_points(points: &[(i64, i64)]) -> Vec<(i64, i64)> {
+        convex_hull(points).iter().map(|&i| points[i]).collect()
+    }
+
+    #[test]
+    fn degenerate_inputs_work() {
+        assert_eq!(convex_hull(&[]), vec![]);
+        assert_eq!(convex_hull(&[(5, -3)]), vec![0]);
+        assert_eq!(convex_hull(&[(1, 1), (1, 1), (1, 1)]), vec![0]);
+        assert_eq!(hull_points(&[(1, 1), (3, 3), (2, 2)]), vec![(1, 1), (3, 3)]);
+        assert_eq!(hull_points(&[(0, 5), (0, 1), (0, 3)]), vec![(0, 1), (0, 5)]);
+    }
+
+    #[test]
+    fn square_with_inner_and_duplicate_points_works() {
+        let points = [
+            (2, 2),
+            (0, 0),
+            (4, 0),
+            (4, 4),
+            (0, 4),
+            (1, 3),
+            (2, 0),
+            (4, 0),
+            (0, 2),
+        ];
+        assert_eq!(hull_points(&points), vec![(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(polygon_area_times_two(&hull_points(&points)), 32);
+    }
+
+    #[test]
+    fn circle_points_are_all_on_hull() {
+        let points: Vec<(i64, i64)> = (0..36)
+            .map(|i| {
+                let angle = (i * 10) as f64 * std::f64::consts::PI / 180.0;
+                (
+                    (1000.0 * angle.cos()).round() as i64,
+                    (1000.0 * angle.sin()).round() as i64,
+                )
+            })
+            .collect();
+        let hull = hull_points(&points);
+        assert_eq!(hull.len(), 36);
+        assert!(is_convex_polygon(&hull));
+        let reversed: Vec<(i64, i64)> = hull.iter().rev().cloned().collect();
+        assert!(is_convex_polygon(&reversed));
+    }
+
+    #[test]
+    fn is_convex_polygon_works() {
+        assert!(is_convex_polygon(&[(0, 0), (1, 0), (0, 1)]));
+        assert!(is_convex_polygon(&[(0, 0), (1, 0), (2, 0), (2, 2)]));
+        assert!(!is_convex_polygon(&[(0, 0), (1, 0), (2, 0)]));
+        assert!(!is_convex_polygon(&[(0, 0), (1, 0)]));
+        assert!(!is_convex_polygon(&[(0, 0), (0, 0), (1, 0), (0, 1)]));
+        // A pentagram turns the same way at every vertex but winds twice.
+        let star = [(0, 10), (6, -8), (-9, 3), (9, 3), (-6, -8)];
+        assert!(!is_convex_polygon(&star));
+        assert_eq!(polygon_area_times_two(&[(0, 0), (4, 0), (4, 3)]), 12);
+        assert_eq!(polygon_area_times_two(&[]), 0);
+    }
+
+    #[test]
+    fn large_coordinates_work() {
+        let big = 1 << 61;
+        let points = [
+            (-big, -big),
+            (big, -big),
+            (0, 0),
+            (big, big),
+            (-big, big),
+            (1, 1),
+        ];
+        assert_eq!(
+            hull_points(&points),
+            vec![(-big, -big), (big, -big), (big, big), (-big, big)]
+        );
+        assert!(is_convex_polygon(&hull_points(&points)));
+        // Nearly collinear points whose i64 cross product would overflow.
+        let points = [(0, 0), (big, big - 1), (big - 1, big - 2)];
+        assert_eq!(convex_hull(&points).len(), 3);
+        assert_eq!(
+            polygon_area_times_two(&[(0, 0), (1 << 40, 0), (0, 1 << 20)]),
+            1 << 60
+        );
+    }
+}
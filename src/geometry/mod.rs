@@ -0,0 +1,5 @@
+pub mod closest_pair;
+pub mod convex_hull;
+
+pub use self::closest_pair::{closest_pair, closest_pair_distance_squared};
+pub use self::convex_hull::{convex_hull, is_convex_polygon, polygon_area_times_two};
@@ -0,0 +1,46 @@
+//! This module defines the trait of undirected graphs stored as
+//! adjacency lists, which lets traversals run on any of them.
+
+use graph::{GraphIndexed, StaticGraph};
+
+/// An undirected graph whose vertices are indexed with integers
+/// 0, 1, ..., N-1 and whose neighbors are stored as slices.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::{AdjacencyList, GraphIndexed};
+/// fn n_leaves<G: AdjacencyList>(graph: &G) -> usize {
+///     (0..graph.n_vert()).filter(|&v| graph.neighbors(v).len() == 1).count()
+/// }
+/// let edges = [(0, 1), (0, 2), (0, 3)];
+/// assert_eq!(n_leaves(&StaticGraph::new(4, &edges)), 3);
+/// assert_eq!(n_leaves(&GraphIndexed::new(4, &edges)), 3);
+/// ```
+pub trait AdjacencyList {
+    /// Return the number of vertices.
+    fn n_vert(&self) -> usize;
+
+    /// Return the slice of neighboring vertices to the given vertex.
+    fn neighbors(&self, vert: usize) -> &[usize];
+}
+
+impl AdjacencyList for StaticGraph {
+    fn n_vert(&self) -> usize {
+        StaticGraph::n_vert(self)
+    }
+
+    fn neighbors(&self, vert: usize) -> &[usize] {
+        StaticGraph::neighbors(self, vert)
+    }
+}
+
+impl AdjacencyList for GraphIndexed {
+    fn n_vert(&self) -> usize {
+        GraphIndexed::n_vert(self)
+    }
+
+    fn neighbors(&self, vert: usize) -> &[usize] {
+        GraphIndexed::neighbors(self, vert)
+    }
+}
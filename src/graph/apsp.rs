@@ -0,0 +1,143 @@
+//! This module contains all-pairs shortest path distances of
+//! unweighted graphs, measured in hops, and a fast diameter
+//! estimate for graphs too large for the all-pairs computation.
+
+use graph::eccentricity::bfs_distances;
+use graph::StaticGraph;
+
+/// Return the matrix of distances between all pairs of vertices:
+/// `dist[u][v]` is the number of edges on a shortest path from `u`
+/// to `v`, or `None` if `v` is not reachable from `u`.
+///
+/// Runs a breadth-first search from every vertex in O(V * (V + E)),
+/// which beats Floyd–Warshall unless the graph is dense.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::all_pairs_bfs;
+/// let graph = StaticGraph::new(3, &[(0, 1)]);
+/// let dist = all_pairs_bfs(&graph);
+/// assert_eq!(dist[1], vec![Some(1), Some(0), None]);
+/// assert_eq!(dist[2][2], Some(0));
+/// ```
+pub fn all_pairs_bfs(graph: &StaticGraph) -> Vec<Vec<Option<usize>>> {
+    (0..graph.n_vert())
+        .map(|source| bfs_distances(graph, source))
+        .collect()
+}
+
+/// Return an estimate of the diameter of the graph found with
+/// a double sweep: a breadth-first search from vertex 0 finds
+/// the farthest vertex, and the eccentricity of that vertex is
+/// returned. `None` if the graph has no vertices or is disconnected.
+///
+/// The estimate never exceeds the diameter and is exact for trees.
+/// Runs in O(V + E); use `diameter` for the exact value.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::graph_diameter_fast;
+/// let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (1, 3), (3, 4)]);
+/// assert_eq!(graph_diameter_fast(&graph), Some(3));
+/// ```
+pub fn graph_diameter_fast(graph: &StaticGraph) -> Option<usize> {
+    if graph.n_vert() == 0 {
+        return None;
+    }
+    let farthest = |source| -> Option<(usize, usize)> {
+        let dist = bfs_distances(graph, source);
+        (0..dist.len()).try_fold((0, source), |(best, vert), v| {
+            dist[v].map(|d| if d > best { (d, v) } else { (best, vert) })
+        })
+    };
+    let (_, end) = farthest(0)?;
+    farthest(end).map(|(d, _)| d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_pairs_bfs, graph_diameter_fast};
+    use graph::{diameter, random_graph_gnp, StaticGraph, WeightedGraph};
+
+    /// Floyd–Warshall over the edges of an undirected weighted graph.
+    fn floyd_warshall(graph: &WeightedGraph) -> Vec<Vec<Option<i64>>> {
+        let n_vert = graph.n_vert();
+        let mut dist = vec![vec![None; n_vert]; n_vert];
+        for (v, row) in dist.iter_mut().enumerate() {
+            row[v] = Some(0);
+        }
+        for &(u, v, w) in graph.edges() {
+            for &(a, b) in &[(u, v), (v, u)] {
+                if dist[a][b].is_none_or(|d| w < d) {
+                    dist[a][b] = Some(w);
+                }
+            }
+        }
+        for k in 0..n_vert {
+            for i in 0..n_vert {
+                for j in 0..n_vert {
+                    if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
+                        if dist[i][j].is_none_or(|d| a + b < d) {
+                            dist[i][j] = Some(a + b);
+                        }
+                    }
+                }
+            }
+        }
+        dist
+    }
+
+    #[test]
+    fn matches_floyd_warshall() {
+        for seed in 0..10 {
+            let graph = random_graph_gnp(15, 0.15, seed);
+            let expected = floyd_warshall(&WeightedGraph::from_static_uniform(&graph, 1));
+            let actual: Vec<Vec<Option<i64>>> = all_pairs_bfs(&graph)
+                .into_iter()
+                .map(|row| row.into_iter().map(|d| d.map(|d| d as i64)).collect())
+                .collect();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn small_cases_work() {
+        assert!(all_pairs_bfs(&StaticGraph::new(0, &[])).is_empty());
+        assert_eq!(
+            all_pairs_bfs(&StaticGraph::new(1, &[])),
+            vec![vec![Some(0)]]
+        );
+
+        let dist = all_pairs_bfs(&StaticGraph::new(4, &[(0, 1), (2, 3)]));
+        assert_eq!(dist[0], vec![Some(0), Some(1), None, None]);
+        assert_eq!(dist[3], vec![None, None, Some(1), Some(0)]);
+    }
+
+    #[test]
+    fn fast_diameter_works() {
+        let complete = random_graph_gnp(8, 1.0, 1);
+        assert_eq!(graph_diameter_fast(&complete), Some(1));
+        for n in 1..8 {
+            let edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+            let path = StaticGraph::new(n, &edges);
+            assert_eq!(graph_diameter_fast(&path), Some(n - 1));
+        }
+        assert_eq!(graph_diameter_fast(&StaticGraph::new(0, &[])), None);
+        assert_eq!(graph_diameter_fast(&StaticGraph::new(3, &[(0, 1)])), None);
+    }
+
+    #[test]
+    fn fast_diameter_is_a_lower_bound() {
+        for seed in 0..20 {
+            let graph = random_graph_gnp(20, 0.2, seed);
+            let exact = diameter(&graph);
+            let fast = graph_diameter_fast(&graph);
+            assert_eq!(fast.is_some(), exact.is_some());
+            if let (Some(fast), Some(exact)) = (fast, exact) {
+                assert!(fast <= exact && 2 * fast >= exact);
+            }
+        }
+    }
+}
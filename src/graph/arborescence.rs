@@ -0,0 +1,254 @@
+//! This module contains the Chu–Liu/Edmonds algorithm for
+//! minimum spanning arborescences of directed graphs.
+
+use graph::WeightedDiGraph;
+
+/// Return the edges `(from, to, weight)` of a minimum weight
+/// spanning arborescence rooted at `root`: a set of edges in which
+/// every vertex other than the root has exactly one incoming edge
+/// and is reachable from the root. The edges are ordered by their
+/// target vertex. Returns `None` if some vertex is unreachable.
+///
+/// Every vertex picks its cheapest incoming edge. If the picked
+/// edges form no cycle, they are the answer; otherwise every cycle
+/// is contracted into a single vertex, the weight of every edge
+/// entering a cycle is reduced by the weight of the cycle edge it
+/// would replace, and the algorithm recurses on the smaller graph.
+/// Takes O(V E).
+///
+/// # Panics
+///
+/// If `root >= graph.n_vert()`.
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedDiGraph;
+/// use ralgo::graph::min_arborescence;
+/// let graph = WeightedDiGraph::new(
+///     4,
+///     &[(0, 1, 10), (0, 2, 2), (2, 1, 1), (1, 3, 3), (3, 2, 1)],
+/// );
+/// assert_eq!(
+///     min_arborescence(&graph, 0),
+///     Some(vec![(2, 1, 1), (0, 2, 2), (1, 3, 3)])
+/// );
+/// assert_eq!(min_arborescence(&graph, 3), None);
+/// ```
+pub fn min_arborescence(graph: &WeightedDiGraph, root: usize) -> Option<Vec<(usize, usize, i64)>> {
+    let n_vert = graph.n_vert();
+    assert!(root < n_vert, "root {} is out of range", root);
+    let edges: Vec<(usize, usize, i64)> = (0..n_vert)
+        .flat_map(|u| {
+            graph
+                .successors_with_weights(u)
+                .iter()
+                .map(move |&(v, w)| (u, v, w))
+        })
+        .collect();
+    let mut tree: Vec<(usize, usize, i64)> = contract(n_vert, root, &edges)?
+        .into_iter()
+        .map(|i| edges[i])
+        .collect();
+    tree.sort_by_key(|&(_, v, _)| v);
+    Some(tree)
+}
+
+/// Return the indices into `edges` of a minimum arborescence
+/// of the graph with `n_vert` vertices rooted at `root`.
+fn contract(n_vert: usize, root: usize, edges: &[(usize, usize, i64)]) -> Option<Vec<usize>> {
+    let mut cheapest: Vec<Option<usize>> = vec![None; n_vert];
+    for (i, &(u, v, w)) in edges.iter().enumerate() {
+        if u != v && v != root && cheapest[v].is_none_or(|j| w < edges[j].2) {
+            cheapest[v] = Some(i);
+        }
+    }
+    let mut parent = vec![root; n_vert];
+    for v in (0..n_vert).filter(|&v| v != root) {
+        parent[v] = edges[cheapest[v]?].0;
+    }
+
+    // Label the vertices of every cycle formed by the picked edges
+    // with the same new vertex, all other vertices with their own.
+    let mut comp = vec![usize::MAX; n_vert];
+    let mut visited_from = vec![usize::MAX; n_vert];
+    let mut n_comp = 0;
+    let mut has_cycle = false;
+    for start in 0..n_vert {
+        let mut v = start;
+        while v != root && visited_from[v] == usize::MAX {
+            visited_from[v] = start;
+            v = parent[v];
+        }
+        if v != root && visited_from[v] == start && comp[v] == usize::MAX {
+            has_cycle = true;
+            while comp[v] == usize::MAX {
+                comp[v] = n_comp;
+                v = parent[v];
+            }
+            n_comp += 1;
+        }
+    }
+    if !has_cycle {
+        return Some(cheapest.into_iter().flatten().collect());
+    }
+    for c in comp.iter_mut().filter(|c| **c == usize::MAX) {
+        *c = n_comp;
+        n_comp += 1;
+    }
+
+    let mut origin = Vec::new();
+    let mut contracted = Vec::new();
+    for (i, &(u, v, w)) in edges.iter().enumerate() {
+        if comp[u] != comp[v] {
+            let reduced = match cheapest[v] {
+                Some(j) if comp[v] == comp[parent[v]] => w - edges[j].2,
+                _ => w,
+            };
+            origin.push(i);
+            contracted.push((comp[u], comp[v], reduced));
+        }
+    }
+    let chosen = contract(n_comp, comp[root], &contracted)?;
+
+    // Keep the cycle edges except those into the vertices where
+    // the chosen edges enter the cycles.
+    let mut entered = vec![false; n_vert];
+    let mut result = Vec::with_capacity(n_vert - 1);
+    for i in chosen {
+        let i = origin[i];
+        entered[edges[i].1] = true;
+        result.push(i);
+    }
+    for v in 0..n_vert {
+        if v != root && !entered[v] {
+            result.push(cheapest[v].unwrap());
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::min_arborescence;
+    use graph::random::XorShift;
+    use graph::WeightedDiGraph;
+
+    /// Return the weight of a minimum arborescence by trying every
+    /// choice of an incoming edge for every vertex.
+    fn brute_force(n_vert: usize, root: usize, edges: &[(usize, usize, i64)]) -> Option<i64> {
+        let incoming: Vec<Vec<(usize, i64)>> = (0..n_vert)
+            .map(|v| {
+                edges
+                    .iter()
+                    .filter(|&&(u, x, _)| x == v && u != v)
+                    .map(|&(u, _, w)| (u, w))
+                    .collect()
+            })
+            .collect();
+        let mut best = None;
+        let mut choice = vec![0; n_vert];
+        loop {
+            let parent: Option<Vec<(usize, i64)>> = (0..n_vert)
+                .map(|v| {
+                    if v == root {
+                        Some((root, 0))
+                    } else {
+                        incoming[v].get(choice[v]).cloned()
+                    }
+                })
+                .collect();
+            let parent = parent?;
+            let reaches_root = (0..n_vert).all(|mut v| {
+                for _ in 0..n_vert {
+                    v = parent[v].0;
+                }
+                v == root
+            });
+            if reaches_root {
+                let weight: i64 = parent.iter().map(|&(_, w)| w).sum();
+                if best.is_none_or(|b| weight < b) {
+                    best = Some(weight);
+                }
+            }
+
+            let mut v = 0;
+            while v < n_vert && (v == root || choice[v] + 1 == incoming[v].len()) {
+                choice[v] = 0;
+                v += 1;
+            }
+            if v == n_vert {
+                return best;
+            }
+            choice[v] += 1;
+        }
+    }
+
+    #[test]
+    fn known_arborescence_works() {
+        let graph = WeightedDiGraph::new(
+            5,
+            &[
+                (0, 1, 5),
+                (0, 2, 8),
+                (1, 2, 1),
+                (2, 3, 2),
+                (3, 1, 1),
+                (3, 4, 4),
+                (1, 4, 7),
+                (4, 3, 1),
+            ],
+        );
+        assert_eq!(
+            min_arborescence(&graph, 0),
+            Some(vec![(0, 1, 5), (1, 2, 1), (2, 3, 2), (3, 4, 4)])
+        );
+    }
+
+    #[test]
+    fn unreachable_vertex_gives_none() {
+        let graph = WeightedDiGraph::new(4, &[(0, 1, 1), (1, 2, 1), (3, 1, 1)]);
+        assert_eq!(min_arborescence(&graph, 0), None);
+        assert_eq!(min_arborescence(&graph, 3), None);
+        let graph = WeightedDiGraph::new(3, &[(1, 2, 1), (2, 1, 1)]);
+        assert_eq!(min_arborescence(&graph, 0), None);
+    }
+
+    #[test]
+    fn single_vertex_works() {
+        let graph = WeightedDiGraph::new(1, &[(0, 0, -3)]);
+        assert_eq!(min_arborescence(&graph, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn random_graphs_match_brute_force() {
+        let mut rng = XorShift::new(149);
+        for _ in 0..200 {
+            let n_vert = 1 + rng.next_below(5);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.next_below(12))
+                .map(|_| {
+                    let w = rng.next_below(20) as i64 - 5;
+                    (rng.next_below(n_vert), rng.next_below(n_vert), w)
+                })
+                .collect();
+            let graph = WeightedDiGraph::new(n_vert, &edges);
+            let root = rng.next_below(n_vert);
+            let tree = min_arborescence(&graph, root);
+            let weight = tree.as_ref().map(|t| t.iter().map(|&(_, _, w)| w).sum());
+            assert_eq!(weight, brute_force(n_vert, root, &edges));
+            if let Some(tree) = tree {
+                assert_eq!(tree.len(), n_vert - 1);
+                for &(u, v, w) in &tree {
+                    assert!(graph.successors_with_weights(u).contains(&(v, w)));
+                }
+                let mut reached = vec![false; n_vert];
+                reached[root] = true;
+                for _ in 0..n_vert {
+                    for &(u, v, _) in &tree {
+                        reached[v] |= reached[u];
+                    }
+                }
+                assert!(reached.iter().all(|&r| r));
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! This module contains the A* shortest path search guided
+//! by a user-supplied heuristic.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use graph::WeightedGraph;
+
+/// Return the length and the vertices of a shortest path from
+/// `source` to `target`, or `None` if `target` is unreachable.
+/// Vertices are expanded in the order of `g(v) + h(v)`, where
+/// `g(v)` is the best known distance from the source.
+///
+/// The heuristic must be admissible: `heuristic(v)` must never
+/// exceed the true distance from `v` to `target`. A vertex is
+/// expanded again if a shorter path to it is found later, so
+/// admissibility alone guarantees an optimal result; with a
+/// consistent heuristic every vertex is expanded at most once.
+/// With an inadmissible heuristic the returned path is still a
+/// valid path, but it may be longer than the shortest one.
+///
+/// # Arguments
+///
+/// * `graph` - the graph with non-negative edge weights;
+/// * `source` - the first vertex of the path;
+/// * `target` - the last vertex of the path;
+/// * `heuristic` - the estimate of the distance to `target`.
+///
+/// # Panics
+///
+/// If `source` or `target` is out of range or the graph has
+/// a negative edge.
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedGraph;
+/// use ralgo::graph::astar;
+/// let graph = WeightedGraph::new(4, &[(0, 1, 1), (1, 3, 5), (0, 2, 2), (2, 3, 2)]);
+/// assert_eq!(astar(&graph, 0, 3, |_| 0), Some((4, vec![0, 2, 3])));
+/// ```
+pub fn astar(
+    graph: &WeightedGraph,
+    source: usize,
+    target: usize,
+    heuristic: impl Fn(usize) -> i64,
+) -> Option<(i64, Vec<usize>)> {
+    let n_vert = graph.n_vert();
+    assert!(
+        source < n_vert && target < n_vert,
+        "source or target is out of range"
+    );
+    assert!(
+        graph.edges().iter().all(|&(_, _, w)| w >= 0),
+        "A* search requires non-negative weights"
+    );
+
+    let mut dist: Vec<Option<i64>> = vec![None; n_vert];
+    let mut pred = vec![usize::MAX; n_vert];
+    let mut heap = BinaryHeap::new();
+    dist[source] = Some(0);
+    heap.push(Reverse((heuristic(source), 0, source)));
+    while let Some(Reverse((_, d, u))) = heap.pop() {
+        if dist[u] != Some(d) {
+            continue;
+        }
+        if u == target {
+            let mut path = vec![target];
+            while *path.last().unwrap() != source {
+                path.push(pred[*path.last().unwrap()]);
+            }
+            path.reverse();
+            return Some((d, path));
+        }
+        for &(v, w) in graph.neighbors_with_weights(u) {
+            if dist[v].is_none_or(|best| d + w < best) {
+                dist[v] = Some(d + w);
+                pred[v] = u;
+                heap.push(Reverse((d + w + heuristic(v), d + w, v)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::astar;
+    use graph::random::XorShift;
+    use graph::{Dijkstra, WeightedGraph};
+
+    fn path_length(graph: &WeightedGraph, path: &[usize]) -> i64 {
+        path.windows(2)
+            .map(|w| {
+                graph
+                    .neighbors_with_weights(w[0])
+                    .iter()
+                    .filter(|&&(x, _)| x == w[1])
+                    .map(|&(_, wt)| wt)
+                    .min()
+                    .unwrap()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn matches_dijkstra_on_random_graphs() {
+        let mut rng = XorShift::new(83);
+        for _ in 0..50 {
+            let n_vert = 1 + rng.next_below(30);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.next_below(80))
+                .map(|_| {
+                    let w = rng.next_below(20) as i64;
+                    (rng.next_below(n_vert), rng.next_below(n_vert), w)
+                })
+                .collect();
+            let graph = WeightedGraph::new(n_vert, &edges);
+            let (source, target) = (rng.next_below(n_vert), rng.next_below(n_vert));
+            let expected = Dijkstra::new(&graph, source).distance(target);
+            match astar(&graph, source, target, |_| 0) {
+                None => assert_eq!(expected, None),
+                Some((d, path)) => {
+                    assert_eq!(Some(d), expected);
+                    assert_eq!((path[0], *path.last().unwrap()), (source, target));
+                    assert_eq!(path_length(&graph, &path), d);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn grid_with_manhattan_heuristic_is_optimal() {
+        // A 10x10 grid with a wall in column 5 open only at row 9.
+        let side = 10;
+        let id = |r: usize, c: usize| r * side + c;
+        let mut edges = Vec::new();
+        for r in 0..side {
+            for c in 0..side {
+                if r + 1 < side {
+                    edges.push((id(r, c), id(r + 1, c), 1));
+                }
+                if c + 1 < side && (c + 1 != 5 && c != 5 || r == side - 1) {
+                    edges.push((id(r, c), id(r, c + 1), 1));
+                }
+            }
+        }
+        let graph = WeightedGraph::new(side * side, &edges);
+        let target = id(0, side - 1);
+        let manhattan = |v: usize| ((v / side) + (side - 1 - v % side)) as i64;
+        let (d, path) = astar(&graph, 0, target, manhattan).unwrap();
+        assert_eq!(Some(d), Dijkstra::new(&graph, 0).distance(target));
+        assert_eq!(d, 9 + 9 + 9);
+        assert_eq!(path.len() as i64, d + 1);
+    }
+
+    #[test]
+    fn inadmissible_heuristic_may_be_suboptimal() {
+        let graph = WeightedGraph::new(4, &[(0, 1, 1), (1, 3, 1), (0, 2, 1), (2, 3, 5)]);
+        // Overestimating the distance from vertex 1 hides the shortest path.
+        let misleading = |v: usize| if v == 1 { 100 } else { 0 };
+        let (d, path) = astar(&graph, 0, 3, misleading).unwrap();
+        assert_eq!((d, path), (6, vec![0, 2, 3]));
+        assert_eq!(astar(&graph, 0, 3, |_| 0), Some((2, vec![0, 1, 3])));
+    }
+
+    #[test]
+    fn trivial_and_unreachable_targets_work() {
+        let graph = WeightedGraph::new(3, &[(0, 1, 4)]);
+        assert_eq!(astar(&graph, 1, 1, |_| 0), Some((0, vec![1])));
+        assert_eq!(astar(&graph, 0, 2, |_| 0), None);
+    }
+}
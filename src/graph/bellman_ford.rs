@@ -0,0 +1,225 @@
+//! This module contains the Bellman–Ford single-source shortest
+//! paths algorithm, which allows negative edge weights.
+
+use std::error::Error;
+use std::fmt;
+
+use graph::WeightedDiGraph;
+
+/// The error returned by shortest path algorithms when a cycle of
+/// negative total weight makes distances unbounded. It holds the
+/// vertices of one such cycle in the order of its edges: there is
+/// an edge from every vertex to the next one and from the last
+/// vertex back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle(pub Vec<usize>);
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the graph contains a negative cycle {:?}", self.0)
+    }
+}
+
+impl Error for NegativeCycle {}
+
+/// Return the length of a shortest path from `source` to every
+/// vertex (`None` for unreachable ones), or a negative cycle
+/// reachable from `source`. Cycles that can not be reached from
+/// `source` do not affect the result.
+///
+/// Runs in O(V E).
+///
+/// # Panics
+///
+/// If `source` is out of range.
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedDiGraph;
+/// use ralgo::graph::{bellman_ford, NegativeCycle};
+/// let graph = WeightedDiGraph::new(3, &[(0, 1, 4), (0, 2, 5), (2, 1, -3)]);
+/// assert_eq!(bellman_ford(&graph, 0), Ok(vec![Some(0), Some(2), Some(5)]));
+/// let graph = WeightedDiGraph::new(3, &[(0, 1, 1), (1, 2, -2), (2, 1, 1)]);
+/// assert_eq!(bellman_ford(&graph, 0), Err(NegativeCycle(vec![1, 2])));
+/// ```
+pub fn bellman_ford(
+    graph: &WeightedDiGraph,
+    source: usize,
+) -> Result<Vec<Option<i64>>, NegativeCycle> {
+    let n_vert = graph.n_vert();
+    assert!(source < n_vert, "source {} is out of range", source);
+    let mut dist: Vec<Option<i64>> = vec![None; n_vert];
+    let mut pred = vec![usize::MAX; n_vert];
+    dist[source] = Some(0);
+
+    // After `n_vert - 1` rounds all shortest paths are found, so
+    // a relaxation in one more round proves a negative cycle.
+    for _ in 1..n_vert {
+        if relax_all(graph, &mut dist, &mut pred).is_none() {
+            return Ok(dist);
+        }
+    }
+    match relax_all(graph, &mut dist, &mut pred) {
+        None => Ok(dist),
+        Some(v) => Err(NegativeCycle(cycle_through_predecessors(&pred, v))),
+    }
+}
+
+/// Relax every edge going out of a reached vertex once and return
+/// the last vertex whose distance decreased, if any.
+fn relax_all(
+    graph: &WeightedDiGraph,
+    dist: &mut [Option<i64>],
+    pred: &mut [usize],
+) -> Option<usize> {
+    let mut last_relaxed = None;
+    for u in 0..graph.n_vert() {
+        let du = match dist[u] {
+            Some(du) => du,
+            None => continue,
+        };
+        for &(v, w) in graph.successors_with_weights(u) {
+            if dist[v].is_none_or(|dv| du + w < dv) {
+                dist[v] = Some(du + w);
+                pred[v] = u;
+                last_relaxed = Some(v);
+            }
+        }
+    }
+    last_relaxed
+}
+
+/// Return the cycle of the predecessor graph reached by walking
+/// back from `v`, in edge order and starting from its smallest
+/// vertex. Walking back `pred.len()` steps is enough to land on it.
+fn cycle_through_predecessors(pred: &[usize], mut v: usize) -> Vec<usize> {
+    for _ in 0..pred.len() {
+        v = pred[v];
+    }
+    let mut cycle = vec![v];
+    let mut u = pred[v];
+    while u != v {
+        cycle.push(u);
+        u = pred[u];
+    }
+    cycle.reverse();
+    let start = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+    cycle.rotate_left(start);
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bellman_ford, NegativeCycle};
+    use graph::random::XorShift;
+    use graph::{Dijkstra, WeightedDiGraph, WeightedGraph};
+
+    fn assert_negative_cycle(graph: &WeightedDiGraph, cycle: &[usize]) {
+        let mut total = 0;
+        for (i, &u) in cycle.iter().enumerate() {
+            let v = cycle[(i + 1) % cycle.len()];
+            total += graph
+                .successors_with_weights(u)
+                .iter()
+                .filter(|&&(x, _)| x == v)
+                .map(|&(_, w)| w)
+                .min()
+                .expect("consecutive cycle vertices must be adjacent");
+        }
+        assert!(total < 0);
+    }
+
+    #[test]
+    fn negative_edges_work() {
+        let graph = WeightedDiGraph::new(
+            5,
+            &[
+                (0, 1, 6),
+                (0, 2, 7),
+                (1, 2, 8),
+                (1, 3, 5),
+                (1, 4, -4),
+                (2, 3, -3),
+                (2, 4, 9),
+                (3, 1, -2),
+                (4, 3, 7),
+            ],
+        );
+        assert_eq!(
+            bellman_ford(&graph, 0),
+            Ok(vec![Some(0), Some(2), Some(7), Some(4), Some(-2)])
+        );
+        assert_eq!(
+            bellman_ford(&WeightedDiGraph::new(2, &[]), 1),
+            Ok(vec![None, Some(0)])
+        );
+    }
+
+    #[test]
+    fn negative_cycles_are_returned() {
+        let graph = WeightedDiGraph::new(4, &[(0, 1, 1), (1, 2, 1), (2, 3, -5), (3, 1, 2)]);
+        assert_eq!(bellman_ford(&graph, 0), Err(NegativeCycle(vec![1, 2, 3])));
+
+        let n_vert = 6;
+        let edges: Vec<(usize, usize, i64)> =
+            (0..n_vert).map(|v| (v, (v + 1) % n_vert, -1)).collect();
+        let graph = WeightedDiGraph::new(n_vert, &edges);
+        assert_eq!(
+            bellman_ford(&graph, 3),
+            Err(NegativeCycle((0..n_vert).collect()))
+        );
+    }
+
+    #[test]
+    fn unreachable_negative_cycle_is_ignored() {
+        let graph = WeightedDiGraph::new(4, &[(0, 1, 3), (2, 3, -1), (3, 2, -1), (3, 0, 1)]);
+        assert_eq!(
+            bellman_ford(&graph, 0),
+            Ok(vec![Some(0), Some(3), None, None])
+        );
+        match bellman_ford(&graph, 3) {
+            Err(NegativeCycle(cycle)) => assert_eq!(cycle, vec![2, 3]),
+            Ok(_) => panic!("the cycle is reachable from 3"),
+        }
+    }
+
+    #[test]
+    fn random_graphs_work() {
+        let mut rng = XorShift::new(89);
+        for _ in 0..200 {
+            let n_vert = 1 + rng.next_below(10);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.next_below(25))
+                .map(|_| {
+                    let w = rng.next_below(20) as i64 - 4;
+                    (rng.next_below(n_vert), rng.next_below(n_vert), w)
+                })
+                .collect();
+            let graph = WeightedDiGraph::new(n_vert, &edges);
+            let source = rng.next_below(n_vert);
+            match bellman_ford(&graph, source) {
+                Err(NegativeCycle(cycle)) => assert_negative_cycle(&graph, &cycle),
+                Ok(dist) => {
+                    // Shortest distances satisfy every edge constraint.
+                    for &(u, v, w) in edges.iter() {
+                        if let Some(du) = dist[u] {
+                            assert!(dist[v].is_some_and(|dv| dv <= du + w));
+                        }
+                    }
+                }
+            }
+
+            // Non-negative weights agree with Dijkstra on symmetric graphs.
+            let positive: Vec<(usize, usize, i64)> =
+                edges.iter().map(|&(u, v, w)| (u, v, w.abs())).collect();
+            let symmetric: Vec<(usize, usize, i64)> = positive
+                .iter()
+                .flat_map(|&(u, v, w)| vec![(u, v, w), (v, u, w)])
+                .collect();
+            let dist = bellman_ford(&WeightedDiGraph::new(n_vert, &symmetric), source).unwrap();
+            let paths = Dijkstra::new(&WeightedGraph::new(n_vert, &positive), source);
+            for (v, &d) in dist.iter().enumerate() {
+                assert_eq!(d, paths.distance(v));
+            }
+        }
+    }
+}
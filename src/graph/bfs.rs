@@ -0,0 +1,158 @@
+//! This module contains breadth-first search over an undirected
+//! graph from a single source vertex.
+
+use std::collections::VecDeque;
+
+use graph::{AdjacencyList, NotATree, TreeIndexed};
+
+/// The result of a breadth-first search from a source vertex: the
+/// distance in hops to every vertex and the shortest path tree in
+/// which the parent of every vertex is the vertex that discovered it.
+/// Neighbors are visited in the order of the adjacency lists, and
+/// the search runs on any `AdjacencyList`.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::Bfs;
+/// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let bfs = Bfs::new(&graph, 0);
+/// assert_eq!(bfs.distance(2), Some(2));
+/// assert_eq!(bfs.parent(2), Some(1));
+/// let tree = bfs.spanning_tree().unwrap();
+/// assert_eq!(tree.children(0), &[1, 3]);
+/// ```
+pub struct Bfs {
+    source: usize,
+    parent: Vec<Option<usize>>,
+    dist: Vec<Option<usize>>,
+    order: Vec<usize>,
+}
+
+impl Bfs {
+    /// Run the search on `graph` from `source` in O(V + E). A graph
+    /// without vertices gives an empty search that reaches nothing,
+    /// whatever `source`.
+    ///
+    /// # Panics
+    ///
+    /// If the graph has vertices and `source >= graph.n_vert()`.
+    ///
+    pub fn new<G: AdjacencyList>(graph: &G, source: usize) -> Bfs {
+        let n_vert = graph.n_vert();
+        assert!(
+            n_vert == 0 || source < n_vert,
+            "source {} is out of range",
+            source
+        );
+        let mut parent = vec![None; n_vert];
+        let mut dist = vec![None; n_vert];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::new();
+        if n_vert > 0 {
+            dist[source] = Some(0);
+            queue.push_back(source);
+        }
+        while let Some(u) = queue.pop_front() {
+            order.push(u);
+            let next = dist[u].map(|d| d + 1);
+            for &v in graph.neighbors(u) {
+                if dist[v].is_none() {
+                    dist[v] = next;
+                    parent[v] = Some(u);
+                    queue.push_back(v);
+                }
+            }
+        }
+        Bfs {
+            source,
+            parent,
+            dist,
+            order,
+        }
+    }
+
+    /// Return the vertex that discovered `vert`, or `None` for the
+    /// source and for unreachable vertices.
+    pub fn parent(&self, vert: usize) -> Option<usize> {
+        self.parent[vert]
+    }
+
+    /// Return the number of edges on a shortest path from the source
+    /// to `vert`, or `None` if `vert` is unreachable.
+    pub fn distance(&self, vert: usize) -> Option<usize> {
+        self.dist[vert]
+    }
+
+    /// Return the reachable vertices in the order they were reached,
+    /// which is nondecreasing in distance.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Return the search tree rooted at the source, or `NotATree`
+    /// if some vertex of the graph is unreachable or the graph has
+    /// no vertices.
+    pub fn spanning_tree(&self) -> Result<TreeIndexed, NotATree> {
+        if self.order.is_empty() || self.order.len() != self.parent.len() {
+            return Err(NotATree);
+        }
+        Ok(TreeIndexed::from_parents(self.source, self.parent.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bfs;
+    use graph::{GraphIndexed, NotATree, StaticGraph, TreeIndexed};
+
+    #[test]
+    fn cycle_spanning_tree_works() {
+        let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+        let tree = Bfs::new(&graph, 0).spanning_tree().unwrap();
+        assert_eq!(tree.n_vert(), graph.n_vert());
+        assert_eq!(tree.children(0), &[1, 4]);
+        assert_eq!(tree.depth(1), 1);
+        assert_eq!(tree.depth(4), 1);
+        assert_eq!(tree.depth(2), 2);
+        assert_eq!(tree.depth(3), 2);
+    }
+
+    #[test]
+    fn spanning_tree_of_a_tree_is_the_tree() {
+        let edges = [(3, 0), (3, 1), (1, 2), (1, 4), (4, 5), (0, 6)];
+        let graph = StaticGraph::new(7, &edges);
+        let expected = TreeIndexed::new(7, 3, &edges);
+        let tree = Bfs::new(&graph, 3).spanning_tree().unwrap();
+        assert_eq!(tree.n_vert(), graph.n_vert());
+        for v in 0..7 {
+            assert_eq!(tree.parent(v), expected.parent(v));
+            assert_eq!(tree.children(v), expected.children(v));
+        }
+    }
+
+    #[test]
+    fn disconnected_graph_has_no_spanning_tree() {
+        let graph = StaticGraph::new(4, &[(0, 1), (2, 3)]);
+        let bfs = Bfs::new(&graph, 0);
+        assert_eq!(bfs.spanning_tree().err(), Some(NotATree));
+        let indexed = Bfs::new(&GraphIndexed::from(graph), 0);
+        assert_eq!(indexed.order(), bfs.order());
+        assert_eq!(bfs.distance(1), Some(1));
+        assert_eq!(bfs.distance(3), None);
+        assert_eq!(bfs.order(), &[0, 1]);
+    }
+
+    #[test]
+    fn zero_vertex_graphs_give_empty_searches() {
+        for bfs in [
+            Bfs::new(&StaticGraph::new(0, &[]), 0),
+            Bfs::new(&GraphIndexed::new(0, &[]), 3),
+        ]
+        .iter()
+        {
+            assert_eq!(bfs.order(), &[]);
+            assert_eq!(bfs.spanning_tree().err(), Some(NotATree));
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! This module contains algorithms for bipartite graphs:
+//! the bipartiteness check, Hopcroft–Karp maximum matching and
+//! the maximum independent set by König's theorem, as well as
+//! a graph type that is known to be bipartite and a constructor
+//! of complete bipartite graphs.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::ops::Deref;
+
+use graph::StaticGraph;
+
+/// The error returned by algorithms that require a bipartite
+/// graph when given a graph containing an odd cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotBipartite;
+
+impl fmt::Display for NotBipartite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the graph contains an odd cycle")
+    }
+}
+
+impl Error for NotBipartite {}
+
+/// An undirected graph that is known to be bipartite. It
+/// dereferences to the wrapped `StaticGraph` and keeps the sides
+/// found by `bipartition`: every edge joins a left vertex with
+/// a right one. Vertices keep their indices in the wrapped graph.
+///
+/// # Examples
+/// ```
+/// use std::convert::TryFrom;
+/// use ralgo::{BipartiteGraph, StaticGraph};
+/// let graph = BipartiteGraph::try_from(StaticGraph::new(3, &[(0, 1), (1, 2)])).unwrap();
+/// assert_eq!((graph.left_size(), graph.right_size()), (2, 1));
+/// assert_eq!(graph.right_neighbors(1), &[0, 2]);
+/// assert!(BipartiteGraph::try_from(StaticGraph::new(3, &[(0, 1), (1, 2), (2, 0)])).is_err());
+/// ```
+pub struct BipartiteGraph {
+    graph: StaticGraph,
+    side: Vec<bool>,
+    left_size: usize,
+}
+
+impl BipartiteGraph {
+    /// Return the number of left vertices.
+    pub fn left_size(&self) -> usize {
+        self.left_size
+    }
+
+    /// Return the number of right vertices.
+    pub fn right_size(&self) -> usize {
+        self.side.len() - self.left_size
+    }
+
+    /// Return `true` if `vert` is a left vertex.
+    pub fn is_left(&self, vert: usize) -> bool {
+        !self.side[vert]
+    }
+
+    /// Return the neighbors of the left vertex `vert`,
+    /// all of which are right vertices.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` is out of range or is a right vertex.
+    ///
+    pub fn left_neighbors(&self, vert: usize) -> &[usize] {
+        assert!(self.is_left(vert), "vertex {} is not a left vertex", vert);
+        self.graph.neighbors(vert)
+    }
+
+    /// Return the neighbors of the right vertex `vert`,
+    /// all of which are left vertices.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` is out of range or is a left vertex.
+    ///
+    pub fn right_neighbors(&self, vert: usize) -> &[usize] {
+        assert!(!self.is_left(vert), "vertex {} is not a right vertex", vert);
+        self.graph.neighbors(vert)
+    }
+}
+
+impl TryFrom<StaticGraph> for BipartiteGraph {
+    type Error = NotBipartite;
+
+    /// Wrap the graph, failing with `NotBipartite` if it contains
+    /// an odd cycle.
+    fn try_from(graph: StaticGraph) -> Result<BipartiteGraph, NotBipartite> {
+        let side = bipartition(&graph)?;
+        let left_size = side.iter().filter(|&&s| !s).count();
+        Ok(BipartiteGraph {
+            graph,
+            side,
+            left_size,
+        })
+    }
+}
+
+impl Deref for BipartiteGraph {
+    type Target = StaticGraph;
+
+    fn deref(&self) -> &StaticGraph {
+        &self.graph
+    }
+}
+
+/// Return the complete bipartite graph K_{left, right}: every
+/// vertex in `0..left` is adjacent to every vertex in
+/// `left..left + right`.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::complete_bipartite;
+/// let graph = complete_bipartite(2, 3);
+/// assert_eq!(graph.n_edges(), 6);
+/// assert_eq!(graph.neighbors(0), &[2, 3, 4]);
+/// assert_eq!(graph.neighbors(4), &[0, 1]);
+/// ```
+pub fn complete_bipartite(left: usize, right: usize) -> StaticGraph {
+    let edges: Vec<(usize, usize)> = (0..left)
+        .flat_map(|u| (left..left + right).map(move |v| (u, v)))
+        .collect();
+    StaticGraph::new(left + right, &edges)
+}
+
+/// Split the vertices into two sides so that every edge joins
+/// different sides: `side[v]` is `false` for the left side and
+/// `true` for the right one. The smallest vertex of every connected
+/// component is on the left. Fails with `NotBipartite` if the graph
+/// has an odd cycle, including a self-loop. Takes O(V + E).
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::bipartition;
+/// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(bipartition(&graph), Ok(vec![false, true, false, true]));
+/// assert!(bipartition(&StaticGraph::new(3, &[(0, 1), (1, 2), (2, 0)])).is_err());
+/// ```
+pub fn bipartition(graph: &StaticGraph) -> Result<Vec<bool>, NotBipartite> {
+    let n_vert = graph.n_vert();
+    let mut side: Vec<Option<bool>> = vec![None; n_vert];
+    let mut queue = VecDeque::new();
+    for start in 0..n_vert {
+        if side[start].is_some() {
+            continue;
+        }
+        side[start] = Some(false);
+        queue.push_back(start);
+        while let Some(u) = queue.pop_front() {
+            let other = !side[u].unwrap();
+            for &v in graph.neighbors(u) {
+                match side[v] {
+                    None => {
+                        side[v] = Some(other);
+                        queue.push_back(v);
+                    }
+                    Some(s) if s != other => return Err(NotBipartite),
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    Ok(side.into_iter().map(Option::unwrap).collect())
+}
+
+/// Return a maximum matching of a bipartite graph: `mate[v]` is
+/// the vertex matched with `v`, or `None` if `v` is unmatched.
+/// Fails with `NotBipartite` if the graph has an odd cycle.
+///
+/// Uses the Hopcroft–Karp algorithm: every phase finds a maximal
+/// set of vertex-disjoint shortest augmenting paths with one BFS
+/// and one DFS, and there are O(sqrt(V)) phases, so the total time
+/// is O(E sqrt(V)).
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::max_bipartite_matching;
+/// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+/// let mate = max_bipartite_matching(&graph).unwrap();
+/// assert_eq!(mate, vec![Some(1), Some(0), Some(3), Some(2)]);
+/// ```
+pub fn max_bipartite_matching(graph: &StaticGraph) -> Result<Vec<Option<usize>>, NotBipartite> {
+    let side = bipartition(graph)?;
+    Ok(hopcroft_karp(graph, &side))
+}
+
+/// Return a maximum independent set of a bipartite graph: a largest
+/// set of vertices no two of which are adjacent, in increasing order.
+/// Fails with `NotBipartite` if the graph has an odd cycle.
+///
+/// By König's theorem a minimum vertex cover of a bipartite graph is
+/// as large as a maximum matching, and its complement is a maximum
+/// independent set of size `n - |matching|`. With `Z` the vertices
+/// reachable from unmatched left vertices by alternating paths, the
+/// cover is the left vertices outside `Z` and the right ones in `Z`.
+/// Takes O(E sqrt(V)).
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::bipartite_max_independent_set;
+/// let star = StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(bipartite_max_independent_set(&star), Ok(vec![1, 2, 3]));
+/// ```
+pub fn bipartite_max_independent_set(graph: &StaticGraph) -> Result<Vec<usize>, NotBipartite> {
+    let side = bipartition(graph)?;
+    let mate = hopcroft_karp(graph, &side);
+
+    let mut reached = vec![false; graph.n_vert()];
+    let mut queue: VecDeque<usize> = (0..graph.n_vert())
+        .filter(|&v| !side[v] && mate[v].is_none())
+        .collect();
+    for &v in queue.iter() {
+        reached[v] = true;
+    }
+    while let Some(u) = queue.pop_front() {
+        // Alternating paths leave left vertices by unmatched edges
+        // (the mate of a reached left vertex is reached already)
+        // and right vertices by their matching edge.
+        let next: Vec<usize> = if side[u] {
+            mate[u].into_iter().collect()
+        } else {
+            graph.neighbors(u).to_vec()
+        };
+        for v in next {
+            if !reached[v] {
+                reached[v] = true;
+                queue.push_back(v);
+            }
+        }
+    }
+    Ok((0..graph.n_vert())
+        .filter(|&v| side[v] != reached[v])
+        .collect())
+}
+
+/// Run Hopcroft–Karp on a graph with a known bipartition.
+fn hopcroft_karp(graph: &StaticGraph, side: &[bool]) -> Vec<Option<usize>> {
+    let n_vert = graph.n_vert();
+    let mut mate: Vec<Option<usize>> = vec![None; n_vert];
+    let mut dist = vec![usize::MAX; n_vert];
+    loop {
+        // Layer the left vertices by the length of the shortest
+        // alternating path from an unmatched left vertex.
+        let mut queue = VecDeque::new();
+        for v in 0..n_vert {
+            dist[v] = usize::MAX;
+            if !side[v] && mate[v].is_none() {
+                dist[v] = 0;
+                queue.push_back(v);
+            }
+        }
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for &v in graph.neighbors(u) {
+                match mate[v] {
+                    None => found = true,
+                    Some(w) if dist[w] == usize::MAX => {
+                        dist[w] = dist[u] + 1;
+                        queue.push_back(w);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if !found {
+            return mate;
+        }
+        for v in 0..n_vert {
+            if !side[v] && mate[v].is_none() {
+                augment(graph, v, &mut mate, &mut dist);
+            }
+        }
+    }
+}
+
+/// Look for an augmenting path from the left vertex `u` along the
+/// BFS layers and flip it. Return `true` if one was found.
+fn augment(graph: &StaticGraph, u: usize, mate: &mut [Option<usize>], dist: &mut [usize]) -> bool {
+    for &v in graph.neighbors(u) {
+        let extends = match mate[v] {
+            None => true,
+            Some(w) => dist[w] == dist[u] + 1 && augment(graph, w, mate, dist),
+        };
+        if extends {
+            mate[u] = Some(v);
+            mate[v] = Some(u);
+            return true;
+        }
+    }
+    // No path goes through `u` in this phase.
+    dist[u] = usize::MAX;
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bipartite_max_independent_set, bipartition, complete_bipartite, max_bipartite_matching,
+        BipartiteGraph, NotBipartite,
+    };
+    use graph::random::XorShift;
+    use graph::StaticGraph;
+    use std::convert::TryFrom;
+
+    fn is_independent(graph: &StaticGraph, set: &[usize]) -> bool {
+        set.iter()
+            .all(|&u| graph.neighbors(u).iter().all(|v| !set.contains(v)))
+    }
+
+    #[test]
+    fn complete_bipartite_works() {
+        for n in 1..6 {
+            let graph = complete_bipartite(n, n);
+            let set = bipartite_max_independent_set(&graph).unwrap();
+            assert_eq!(set.len(), n);
+            assert!(set.iter().all(|&v| v < n) || set.iter().all(|&v| v >= n));
+            let mate = max_bipartite_matching(&graph).unwrap();
+            assert!(mate.iter().all(|m| m.is_some()));
+        }
+    }
+
+    #[test]
+    fn edgeless_graph_is_independent() {
+        let graph = StaticGraph::new(5, &[]);
+        assert_eq!(
+            bipartite_max_independent_set(&graph),
+            Ok(vec![0, 1, 2, 3, 4])
+        );
+        assert_eq!(max_bipartite_matching(&graph), Ok(vec![None; 5]));
+        assert_eq!(
+            bipartite_max_independent_set(&StaticGraph::new(0, &[])),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn paths_and_odd_cycles_work() {
+        for n in 1..12 {
+            let edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+            let graph = StaticGraph::new(n, &edges);
+            let set = bipartite_max_independent_set(&graph).unwrap();
+            assert_eq!(set.len(), n.div_ceil(2));
+            assert!(is_independent(&graph, &set));
+        }
+        let triangle = StaticGraph::new(3, &[(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(bipartite_max_independent_set(&triangle), Err(NotBipartite));
+        let self_loop = StaticGraph::new_with_loops(2, &[(0, 1), (1, 1)]);
+        assert_eq!(bipartition(&self_loop), Err(NotBipartite));
+    }
+
+    #[test]
+    fn random_graphs_match_brute_force() {
+        let mut rng = XorShift::new(151);
+        for _ in 0..200 {
+            let n = 1 + rng.next_below(10);
+            let left = rng.next_below(n + 1);
+            let edges: Vec<(usize, usize)> = (0..rng.next_below(20))
+                .filter_map(|_| {
+                    let (u, v) = (rng.next_below(n), rng.next_below(n));
+                    if (u < left) != (v < left) {
+                        Some((u, v))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            let graph = StaticGraph::new(n, &edges);
+            let best = (0u32..1 << n)
+                .filter(|mask| edges.iter().all(|&(u, v)| mask >> u & mask >> v & 1 == 0))
+                .map(|mask| mask.count_ones() as usize)
+                .max()
+                .unwrap();
+            let set = bipartite_max_independent_set(&graph).unwrap();
+            assert_eq!(set.len(), best);
+            assert!(is_independent(&graph, &set));
+
+            let mate = max_bipartite_matching(&graph).unwrap();
+            let matched = mate.iter().filter(|m| m.is_some()).count();
+            assert_eq!(matched / 2, n - best);
+            for (u, m) in mate.iter().enumerate() {
+                if let Some(v) = *m {
+                    assert_eq!(mate[v], Some(u));
+                    assert!(graph.neighbors(u).contains(&v));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bipartite_graph_works() {
+        let edges: Vec<(usize, usize)> = (0..3).flat_map(|u| (3..6).map(move |v| (u, v))).collect();
+        let graph = BipartiteGraph::try_from(StaticGraph::new(6, &edges)).unwrap();
+        assert_eq!(graph.left_size(), 3);
+        assert_eq!(graph.right_size(), 3);
+        assert_eq!(graph.n_edges(), 9);
+        for v in 0..6 {
+            if graph.is_left(v) {
+                assert!(graph.left_neighbors(v).iter().all(|&u| !graph.is_left(u)));
+            } else {
+                assert!(graph.right_neighbors(v).iter().all(|&u| graph.is_left(u)));
+            }
+        }
+        assert!((0..3).all(|v| graph.is_left(v)));
+
+        let cycle = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+        assert_eq!(BipartiteGraph::try_from(cycle).err(), Some(NotBipartite));
+    }
+
+    #[test]
+    #[should_panic]
+    fn left_neighbors_of_right_vertex_panics() {
+        let graph = BipartiteGraph::try_from(StaticGraph::new(2, &[(0, 1)])).unwrap();
+        graph.left_neighbors(1);
+    }
+
+    #[test]
+    fn complete_bipartite_degrees_work() {
+        let graph = complete_bipartite(3, 3);
+        assert_eq!(graph.n_vert(), 6);
+        assert_eq!(graph.n_edges(), 9);
+
+        let graph = BipartiteGraph::try_from(complete_bipartite(4, 7)).unwrap();
+        assert_eq!((graph.left_size(), graph.right_size()), (4, 7));
+        assert!((0..4).all(|v| graph.left_neighbors(v).len() == 7));
+        assert!((4..11).all(|v| graph.right_neighbors(v).len() == 4));
+        assert_eq!(complete_bipartite(0, 5).n_edges(), 0);
+    }
+}
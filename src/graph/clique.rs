@@ -0,0 +1,236 @@
+//! This module contains the enumeration of maximal cliques
+//! with the Bron–Kerbosch algorithm and the search for a
+//! maximum clique built on top of it.
+
+use std::collections::HashSet;
+
+use graph::StaticGraph;
+
+/// Return all maximal cliques of the graph (cliques that can not
+/// be extended by another vertex). Every clique is sorted and the
+/// cliques are listed in lexicographic order. Self-loops and
+/// parallel edges are ignored.
+///
+/// Implements Bron–Kerbosch with pivoting, started from every vertex
+/// in degeneracy order. Finding cliques is NP-hard, so in the worst
+/// case this takes exponential time: a graph may have up to 3^(V/3)
+/// maximal cliques.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::all_maximal_cliques;
+/// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// assert_eq!(all_maximal_cliques(&graph), vec![vec![0, 1, 2], vec![2, 3]]);
+/// ```
+pub fn all_maximal_cliques(graph: &StaticGraph) -> Vec<Vec<usize>> {
+    let n_vert = graph.n_vert();
+    let adjacent: Vec<HashSet<usize>> = (0..n_vert)
+        .map(|u| {
+            graph
+                .neighbors(u)
+                .iter()
+                .cloned()
+                .filter(|&v| v != u)
+                .collect()
+        })
+        .collect();
+
+    let order = degeneracy_order(&adjacent);
+    let mut position = vec![0; n_vert];
+    for (i, &v) in order.iter().enumerate() {
+        position[v] = i;
+    }
+
+    let mut cliques = Vec::new();
+    for &v in order.iter() {
+        let (later, earlier): (Vec<usize>, Vec<usize>) = adjacent[v]
+            .iter()
+            .partition(|&&u| position[u] > position[v]);
+        bron_kerbosch(&adjacent, &mut vec![v], later, earlier, &mut cliques);
+    }
+
+    for clique in cliques.iter_mut() {
+        clique.sort_unstable();
+    }
+    cliques.sort();
+    cliques
+}
+
+/// Return a largest clique of the graph as a sorted vector of
+/// vertices (empty if the graph has no vertices). Takes exponential
+/// time in the worst case, see `all_maximal_cliques`.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::maximum_clique;
+/// let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+/// assert_eq!(maximum_clique(&graph), vec![1, 2, 3]);
+/// ```
+pub fn maximum_clique(graph: &StaticGraph) -> Vec<usize> {
+    all_maximal_cliques(graph)
+        .into_iter()
+        .fold(Vec::new(), |best, clique| {
+            if clique.len() > best.len() {
+                clique
+            } else {
+                best
+            }
+        })
+}
+
+/// Report every maximal clique that contains all of `current`,
+/// some of `candidates` and none of `excluded`.
+fn bron_kerbosch(
+    adjacent: &[HashSet<usize>],
+    current: &mut Vec<usize>,
+    mut candidates: Vec<usize>,
+    mut excluded: Vec<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            cliques.push(current.clone());
+        }
+        return;
+    }
+
+    // Only the non-neighbors of the pivot need to be branched on.
+    let pivot = candidates
+        .iter()
+        .chain(excluded.iter())
+        .cloned()
+        .max_by_key(|&u| {
+            candidates
+                .iter()
+                .filter(|v| adjacent[u].contains(v))
+                .count()
+        })
+        .unwrap();
+    let branches: Vec<usize> = candidates
+        .iter()
+        .cloned()
+        .filter(|v| !adjacent[pivot].contains(v))
+        .collect();
+
+    for v in branches {
+        current.push(v);
+        bron_kerbosch(
+            adjacent,
+            current,
+            candidates
+                .iter()
+                .cloned()
+                .filter(|u| adjacent[v].contains(u))
+                .collect(),
+            excluded
+                .iter()
+                .cloned()
+                .filter(|u| adjacent[v].contains(u))
+                .collect(),
+            cliques,
+        );
+        current.pop();
+        candidates.retain(|&u| u != v);
+        excluded.push(v);
+    }
+}
+
+/// Return the vertices ordered by repeatedly removing
+/// a vertex of minimum remaining degree.
+fn degeneracy_order(adjacent: &[HashSet<usize>]) -> Vec<usize> {
+    let n_vert = adjacent.len();
+    let mut degree: Vec<usize> = adjacent.iter().map(|a| a.len()).collect();
+    let mut removed = vec![false; n_vert];
+    let mut order = Vec::with_capacity(n_vert);
+    for _ in 0..n_vert {
+        let v = (0..n_vert)
+            .filter(|&v| !removed[v])
+            .min_by_key(|&v| degree[v])
+            .unwrap();
+        removed[v] = true;
+        order.push(v);
+        for &u in adjacent[v].iter() {
+            degree[u] -= 1;
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_maximal_cliques, maximum_clique};
+    use graph::{random_graph_gnp, StaticGraph};
+
+    fn is_clique(graph: &StaticGraph, clique: &[usize]) -> bool {
+        clique.iter().all(|&u| {
+            clique
+                .iter()
+                .all(|&v| u == v || graph.neighbors(u).contains(&v))
+        })
+    }
+
+    #[test]
+    fn complete_graph_is_one_clique() {
+        let graph = random_graph_gnp(6, 1.0, 0);
+        assert_eq!(all_maximal_cliques(&graph), vec![vec![0, 1, 2, 3, 4, 5]]);
+        assert_eq!(maximum_clique(&graph), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn path_cliques_are_edges() {
+        let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 4)]);
+        assert_eq!(
+            all_maximal_cliques(&graph),
+            vec![vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 4]]
+        );
+        assert_eq!(maximum_clique(&graph).len(), 2);
+
+        assert_eq!(all_maximal_cliques(&StaticGraph::new(0, &[])).len(), 0);
+        assert_eq!(maximum_clique(&StaticGraph::new(0, &[])), vec![]);
+        assert_eq!(
+            all_maximal_cliques(&StaticGraph::new(2, &[])),
+            vec![vec![0], vec![1]]
+        );
+    }
+
+    #[test]
+    fn unique_triangle_is_found() {
+        let graph = StaticGraph::new(
+            7,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 2),
+                (4, 5),
+                (5, 6),
+                (6, 0),
+            ],
+        );
+        assert_eq!(maximum_clique(&graph), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn random_graph_cliques_are_maximal() {
+        for seed in 0..5 {
+            let graph = random_graph_gnp(25, 0.4, seed);
+            let cliques = all_maximal_cliques(&graph);
+            for clique in cliques.iter() {
+                assert!(is_clique(&graph, clique));
+                for v in 0..25 {
+                    if !clique.contains(&v) {
+                        let mut extended = clique.clone();
+                        extended.push(v);
+                        assert!(!is_clique(&graph, &extended));
+                    }
+                }
+            }
+            let mut deduped = cliques.clone();
+            deduped.dedup();
+            assert_eq!(deduped.len(), cliques.len());
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! This module contains the transitive closure of a directed
+//! graph, single reachability queries and a reachability oracle.
+//! A vertex is always considered reachable from itself.
+
+use std::collections::VecDeque;
+
+use graph::DiGraph;
+
+/// Return the reachability matrix of the graph: `closure[u][v]`
+/// is `true` iff there is a path from `u` to `v`.
+///
+/// The rows are propagated as 64-bit bitsets in a Floyd–Warshall
+/// fashion, which takes O(V³ / 64) time and O(V² / 64) memory
+/// before the result is expanded to booleans.
+///
+/// # Examples
+/// ```
+/// use ralgo::DiGraph;
+/// use ralgo::graph::transitive_closure;
+/// let graph = DiGraph::new(3, &[(0, 1), (1, 2)]);
+/// let closure = transitive_closure(&graph);
+/// assert!(closure[0][2]);
+/// assert!(!closure[2][0]);
+/// ```
+pub fn transitive_closure(graph: &DiGraph) -> Vec<Vec<bool>> {
+    let n_vert = graph.n_vert();
+    let n_words = n_vert.div_ceil(64);
+
+    let mut reach = vec![vec![0u64; n_words]; n_vert];
+    for (u, row) in reach.iter_mut().enumerate() {
+        row[u / 64] |= 1 << (u % 64);
+        for &v in graph.successors(u) {
+            row[v / 64] |= 1 << (v % 64);
+        }
+    }
+
+    for k in 0..n_vert {
+        let via = reach[k].clone();
+        for row in reach.iter_mut() {
+            if row[k / 64] & (1 << (k % 64)) != 0 {
+                for (word, &bits) in row.iter_mut().zip(via.iter()) {
+                    *word |= bits;
+                }
+            }
+        }
+    }
+
+    reach
+        .iter()
+        .map(|row| {
+            (0..n_vert)
+                .map(|v| row[v / 64] & (1 << (v % 64)) != 0)
+                .collect()
+        })
+        .collect()
+}
+
+/// Return `true` if there is a path from `from` to `to`,
+/// `false` otherwise. Uses a single breadth-first search.
+///
+/// # Arguments
+///
+/// * `graph` - the graph in question;
+/// * `from` - the start vertex;
+/// * `to` - the target vertex.
+///
+/// # Panics
+///
+/// If `from` or `to` >= `graph.n_vert()`.
+///
+pub fn is_reachable(graph: &DiGraph, from: usize, to: usize) -> bool {
+    assert!(to < graph.n_vert());
+    let mut visited = vec![false; graph.n_vert()];
+    let mut queue = VecDeque::new();
+    visited[from] = true;
+    queue.push_back(from);
+    while let Some(u) = queue.pop_front() {
+        if u == to {
+            return true;
+        }
+        for &v in graph.successors(u) {
+            if !visited[v] {
+                visited[v] = true;
+                queue.push_back(v);
+            }
+        }
+    }
+    false
+}
+
+/// Reachability queries in O(1) after preprocessing. Vertices of
+/// a strongly connected component reach exactly the same vertices,
+/// so only the reachability between the `k` components is stored,
+/// as bitsets taking O(n + k² / 64) memory.
+///
+/// The components are found with Tarjan's algorithm, which lists
+/// them in reverse topological order of the condensation. The bitset
+/// of every component is then the union of the bitsets of its
+/// successors, all of which are complete by that time. Preprocessing
+/// takes O(n + m) for the components and O(m k / 64) for the unions.
+///
+/// # Examples
+/// ```
+/// use ralgo::DiGraph;
+/// use ralgo::graph::ReachabilityOracle;
+/// let graph = DiGraph::new(4, &[(0, 1), (1, 0), (1, 2)]);
+/// let oracle = ReachabilityOracle::new(&graph);
+/// assert!(oracle.is_reachable(0, 2));
+/// assert!(oracle.is_reachable(1, 0));
+/// assert!(!oracle.is_reachable(2, 0));
+/// assert!(!oracle.is_reachable(0, 3));
+/// ```
+pub struct ReachabilityOracle {
+    comp: Vec<usize>,
+    reach: Vec<Vec<u64>>,
+}
+
+impl ReachabilityOracle {
+    /// Preprocess the graph for reachability queries.
+    pub fn new(graph: &DiGraph) -> ReachabilityOracle {
+        let (comp, n_comp) = strongly_connected_components(graph);
+        let n_words = n_comp.div_ceil(64);
+        let mut members = vec![Vec::new(); n_comp];
+        for (v, &c) in comp.iter().enumerate() {
+            members[c].push(v);
+        }
+
+        let mut reach = vec![vec![0u64; n_words]; n_comp];
+        for c in 0..n_comp {
+            let (done, rest) = reach.split_at_mut(c);
+            let row = &mut rest[0];
+            row[c / 64] |= 1 << (c % 64);
+            for &u in &members[c] {
+                for &v in graph.successors(u) {
+                    if comp[v] != c {
+                        for (word, &bits) in row.iter_mut().zip(done[comp[v]].iter()) {
+                            *word |= bits;
+                        }
+                    }
+                }
+            }
+        }
+        ReachabilityOracle { comp, reach }
+    }
+
+    /// Return the number of strongly connected components.
+    pub fn n_components(&self) -> usize {
+        self.reach.len()
+    }
+
+    /// Return `true` if there is a path from `from` to `to`,
+    /// `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If `from` or `to` is out of range.
+    ///
+    pub fn is_reachable(&self, from: usize, to: usize) -> bool {
+        let (a, b) = (self.comp[from], self.comp[to]);
+        self.reach[a][b / 64] & (1 << (b % 64)) != 0
+    }
+}
+
+/// Return the strongly connected component of every vertex and the
+/// number of components. Components are numbered by Tarjan's
+/// algorithm, so every edge goes from a component to itself or to
+/// a component with a smaller number.
+fn strongly_connected_components(graph: &DiGraph) -> (Vec<usize>, usize) {
+    let n_vert = graph.n_vert();
+    let mut index = vec![usize::MAX; n_vert];
+    let mut low = vec![0; n_vert];
+    let mut comp = vec![usize::MAX; n_vert];
+    let mut stack = Vec::new();
+    let mut n_index = 0;
+    let mut n_comp = 0;
+
+    for start in 0..n_vert {
+        if index[start] != usize::MAX {
+            continue;
+        }
+        // Frames `(vertex, next successor to visit)` of the DFS.
+        let mut calls = vec![(start, 0)];
+        index[start] = n_index;
+        low[start] = n_index;
+        n_index += 1;
+        stack.push(start);
+        while let Some(&mut (u, ref mut next)) = calls.last_mut() {
+            if let Some(&v) = graph.successors(u).get(*next) {
+                *next += 1;
+                if index[v] == usize::MAX {
+                    index[v] = n_index;
+                    low[v] = n_index;
+                    n_index += 1;
+                    stack.push(v);
+                    calls.push((v, 0));
+                } else if comp[v] == usize::MAX {
+                    low[u] = low[u].min(index[v]);
+                }
+                continue;
+            }
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                low[parent] = low[parent].min(low[u]);
+            }
+            if low[u] == index[u] {
+                loop {
+                    let v = stack.pop().unwrap();
+                    comp[v] = n_comp;
+                    if v == u {
+                        break;
+                    }
+                }
+                n_comp += 1;
+            }
+        }
+    }
+    (comp, n_comp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_reachable, transitive_closure, ReachabilityOracle};
+    use graph::random::XorShift;
+    use graph::{random_graph_gnp, DiGraph};
+
+    #[test]
+    fn chain_closure_works() {
+        let graph = DiGraph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+        let closure = transitive_closure(&graph);
+        for (u, row) in closure.iter().enumerate() {
+            for (v, &reachable) in row.iter().enumerate() {
+                assert_eq!(reachable, u <= v);
+                assert_eq!(is_reachable(&graph, u, v), u <= v);
+            }
+        }
+    }
+
+    #[test]
+    fn complete_dag_closure_works() {
+        let n_vert = 70;
+        let mut edges = Vec::new();
+        for u in 0..n_vert {
+            for v in u + 1..n_vert {
+                edges.push((u, v));
+            }
+        }
+        let closure = transitive_closure(&DiGraph::new(n_vert, &edges));
+        for (u, row) in closure.iter().enumerate() {
+            for (v, &reachable) in row.iter().enumerate() {
+                assert_eq!(reachable, u <= v);
+            }
+        }
+    }
+
+    #[test]
+    fn undirected_graph_closure_is_symmetric() {
+        let undirected = random_graph_gnp(80, 0.02, 3);
+        let mut edges = Vec::new();
+        for u in 0..undirected.n_vert() {
+            for &v in undirected.neighbors(u) {
+                edges.push((u, v));
+            }
+        }
+        let graph = DiGraph::new(80, &edges);
+        let closure = transitive_closure(&graph);
+        for (u, row) in closure.iter().enumerate() {
+            for (v, &reachable) in row.iter().enumerate() {
+                assert_eq!(reachable, closure[v][u]);
+                assert_eq!(reachable, is_reachable(&graph, u, v));
+            }
+        }
+    }
+
+    #[test]
+    fn cycle_closure_is_full() {
+        let graph = DiGraph::new(4, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+        let closure = transitive_closure(&graph);
+        for row in closure.iter().take(3) {
+            assert!(row.iter().all(|&r| r));
+        }
+        assert_eq!(closure[3], vec![false, false, false, true]);
+    }
+
+    #[test]
+    fn oracle_matches_bfs_on_random_graphs() {
+        let mut rng = XorShift::new(157);
+        for _ in 0..100 {
+            let n_vert = 1 + rng.next_below(30);
+            let edges: Vec<(usize, usize)> = (0..rng.next_below(3 * n_vert))
+                .map(|_| (rng.next_below(n_vert), rng.next_below(n_vert)))
+                .collect();
+            let graph = DiGraph::new(n_vert, &edges);
+            let oracle = ReachabilityOracle::new(&graph);
+            for u in 0..n_vert {
+                for v in 0..n_vert {
+                    assert_eq!(oracle.is_reachable(u, v), is_reachable(&graph, u, v));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn oracle_on_special_graphs_works() {
+        let n_vert = 100;
+        let cycle: Vec<(usize, usize)> = (0..n_vert).map(|v| (v, (v + 1) % n_vert)).collect();
+        let oracle = ReachabilityOracle::new(&DiGraph::new(n_vert, &cycle));
+        assert_eq!(oracle.n_components(), 1);
+        assert!((0..n_vert).all(|u| (0..n_vert).all(|v| oracle.is_reachable(u, v))));
+
+        // Divisibility on 1..=n is a partial order.
+        let edges: Vec<(usize, usize)> = (1..=n_vert)
+            .flat_map(|a| (2..=n_vert / a).map(move |k| (a - 1, a * k - 1)))
+            .collect();
+        let oracle = ReachabilityOracle::new(&DiGraph::new(n_vert, &edges));
+        assert_eq!(oracle.n_components(), n_vert);
+        for a in 1..=n_vert {
+            for b in 1..=n_vert {
+                assert_eq!(oracle.is_reachable(a - 1, b - 1), b % a == 0);
+            }
+        }
+
+        let graph = DiGraph::new(4, &[(0, 1), (1, 2), (2, 0)]);
+        let oracle = ReachabilityOracle::new(&graph);
+        assert!((0..3).all(|v| !oracle.is_reachable(v, 3) && !oracle.is_reachable(3, v)));
+        assert!(oracle.is_reachable(3, 3));
+    }
+}
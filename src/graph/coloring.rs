@@ -0,0 +1,119 @@
+//! This module contains the greedy vertex coloring of a graph
+//! with the DSATUR heuristic.
+
+use std::collections::HashSet;
+
+use graph::StaticGraph;
+
+/// Return a proper vertex coloring of the graph: `color[v]` is
+/// the color of vertex `v`, colors are numbered 0, 1, 2, ... and
+/// no two adjacent vertices share a color. Self-loops are ignored.
+///
+/// Uses the DSATUR heuristic: at every step the uncolored vertex
+/// with the largest number of distinct colors among its neighbors
+/// (ties broken by degree) gets the smallest color not used by its
+/// neighbors. Takes O(V² + E) time. The result is optimal for
+/// bipartite graphs, but in general it is only an upper bound.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::greedy_coloring;
+/// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let color = greedy_coloring(&graph);
+/// assert!(color[0] == color[2] && color[1] == color[3]);
+/// assert_ne!(color[0], color[1]);
+/// ```
+pub fn greedy_coloring(graph: &StaticGraph) -> Vec<usize> {
+    let n_vert = graph.n_vert();
+    let mut color = vec![usize::MAX; n_vert];
+    let mut neighbor_colors = vec![HashSet::new(); n_vert];
+
+    for _ in 0..n_vert {
+        let vert = (0..n_vert)
+            .filter(|&v| color[v] == usize::MAX)
+            .max_by_key(|&v| {
+                (
+                    neighbor_colors[v].len(),
+                    graph.neighbors(v).len(),
+                    usize::MAX - v,
+                )
+            })
+            .unwrap();
+
+        let used = &neighbor_colors[vert];
+        let chosen = (0..).find(|c| !used.contains(c)).unwrap();
+        color[vert] = chosen;
+        for &v in graph.neighbors(vert) {
+            if v != vert {
+                neighbor_colors[v].insert(chosen);
+            }
+        }
+    }
+    color
+}
+
+/// Return the number of colors used by `greedy_coloring`,
+/// which is an upper bound of the chromatic number.
+pub fn chromatic_number_upper_bound(graph: &StaticGraph) -> usize {
+    greedy_coloring(graph)
+        .into_iter()
+        .max()
+        .map_or(0, |c| c + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chromatic_number_upper_bound, greedy_coloring};
+    use graph::{random_graph_gnp, StaticGraph};
+
+    fn is_proper(graph: &StaticGraph, color: &[usize]) -> bool {
+        (0..graph.n_vert()).all(|u| {
+            graph
+                .neighbors(u)
+                .iter()
+                .all(|&v| u == v || color[u] != color[v])
+        })
+    }
+
+    #[test]
+    fn bipartite_graph_uses_two_colors() {
+        let mut edges = Vec::new();
+        for u in 0..6 {
+            for v in 6..10 {
+                if (u + v) % 3 != 0 {
+                    edges.push((u, v));
+                }
+            }
+        }
+        let graph = StaticGraph::new(10, &edges);
+        assert!(is_proper(&graph, &greedy_coloring(&graph)));
+        assert_eq!(chromatic_number_upper_bound(&graph), 2);
+
+        let cycle = StaticGraph::new(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+        assert_eq!(chromatic_number_upper_bound(&cycle), 2);
+    }
+
+    #[test]
+    fn complete_graphs_need_n_colors() {
+        let triangle = StaticGraph::new(3, &[(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(chromatic_number_upper_bound(&triangle), 3);
+
+        for n in 0..8 {
+            let graph = random_graph_gnp(n, 1.0, 0);
+            assert!(is_proper(&graph, &greedy_coloring(&graph)));
+            assert_eq!(chromatic_number_upper_bound(&graph), n);
+        }
+    }
+
+    #[test]
+    fn random_graph_coloring_is_proper() {
+        for seed in 0..10 {
+            let graph = random_graph_gnp(40, 0.2, seed);
+            let color = greedy_coloring(&graph);
+            assert!(is_proper(&graph, &color));
+            let max_degree = (0..40).map(|v| graph.neighbors(v).len()).max().unwrap();
+            assert!(chromatic_number_upper_bound(&graph) <= max_degree + 1);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! This module contains the vertex and edge connectivity
+//! of an undirected graph computed with maximum flows.
+
+use graph::{MaxFlow, StaticGraph};
+
+/// Return the vertex connectivity of the graph: the minimum number
+/// of vertices whose removal leaves a disconnected graph or a single
+/// vertex. A complete graph on `n` vertices has connectivity `n - 1`
+/// and a disconnected one has 0. Self-loops are ignored.
+///
+/// Every vertex is split into an entry and an exit joined by an arc
+/// of capacity 1, so by Menger's theorem the maximum flow between
+/// non-adjacent `s` and `t` is the size of a minimum `s`-`t` vertex
+/// cut. The vertex with the smallest index outside a minimum cut is
+/// among the first `k + 1`, so only those serve as sources, which
+/// takes O(k V) flow computations for connectivity `k`.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::vertex_connectivity;
+/// // Two triangles sharing vertex 2.
+/// let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+/// assert_eq!(vertex_connectivity(&graph), 1);
+/// ```
+pub fn vertex_connectivity(graph: &StaticGraph) -> usize {
+    let n_vert = graph.n_vert();
+    let mut network = MaxFlow::new(2 * n_vert);
+    for v in 0..n_vert {
+        network.add_edge(v, v + n_vert, 1);
+        for &u in graph.neighbors(v) {
+            if u != v {
+                network.add_edge(v + n_vert, u, n_vert as i64);
+            }
+        }
+    }
+
+    let mut best = n_vert.saturating_sub(1);
+    let mut s = 0;
+    while s <= best && s < n_vert {
+        for t in s + 1..n_vert {
+            if !graph.neighbors(s).contains(&t) {
+                best = best.min(network.max_flow(s + n_vert, t) as usize);
+            }
+        }
+        s += 1;
+    }
+    best
+}
+
+/// Return the edge connectivity of the graph: the minimum number of
+/// edges whose removal leaves a disconnected graph. Parallel edges
+/// count separately and self-loops are ignored. A graph with fewer
+/// than two vertices has edge connectivity 0.
+///
+/// Every minimum cut separates vertex 0 from some other vertex, so
+/// the answer is the smallest maximum flow from vertex 0 with unit
+/// capacities, which takes V - 1 flow computations.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::edge_connectivity;
+/// let cycle = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert_eq!(edge_connectivity(&cycle), 2);
+/// ```
+pub fn edge_connectivity(graph: &StaticGraph) -> usize {
+    let n_vert = graph.n_vert();
+    let mut network = MaxFlow::new(n_vert);
+    for (u, v) in graph.edges() {
+        if u != v {
+            network.add_undirected_edge(u, v, 1);
+        }
+    }
+    (1..n_vert)
+        .map(|t| network.max_flow(0, t) as usize)
+        .min()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edge_connectivity, vertex_connectivity};
+    use graph::random::XorShift;
+    use graph::StaticGraph;
+
+    fn complete_graph(n: usize) -> StaticGraph {
+        let edges: Vec<(usize, usize)> = (0..n)
+            .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+            .collect();
+        StaticGraph::new(n, &edges)
+    }
+
+    /// Return `true` if the vertices that are not removed are connected.
+    fn connected_without(graph: &StaticGraph, removed: &[bool]) -> bool {
+        let n_vert = graph.n_vert();
+        let start = match (0..n_vert).find(|&v| !removed[v]) {
+            None => return true,
+            Some(start) => start,
+        };
+        let mut seen = removed.to_vec();
+        seen[start] = true;
+        let mut stack = vec![start];
+        while let Some(u) = stack.pop() {
+            for &v in graph.neighbors(u) {
+                if !seen[v] {
+                    seen[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        seen.into_iter().all(|s| s)
+    }
+
+    #[test]
+    fn complete_graphs_work() {
+        for n in 1..8 {
+            let graph = complete_graph(n);
+            assert_eq!(vertex_connectivity(&graph), n - 1);
+            assert_eq!(edge_connectivity(&graph), n - 1);
+        }
+        assert_eq!(vertex_connectivity(&StaticGraph::new(0, &[])), 0);
+        assert_eq!(edge_connectivity(&StaticGraph::new(0, &[])), 0);
+    }
+
+    #[test]
+    fn trees_and_cycles_work() {
+        let tree = StaticGraph::new(6, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]);
+        assert_eq!(edge_connectivity(&tree), 1);
+        assert_eq!(vertex_connectivity(&tree), 1);
+        for n in 3..10 {
+            let edges: Vec<(usize, usize)> = (0..n).map(|v| (v, (v + 1) % n)).collect();
+            let cycle = StaticGraph::new(n, &edges);
+            assert_eq!(edge_connectivity(&cycle), 2);
+            assert_eq!(vertex_connectivity(&cycle), 2);
+        }
+        let disconnected = StaticGraph::new(4, &[(0, 1), (2, 3)]);
+        assert_eq!(edge_connectivity(&disconnected), 0);
+        assert_eq!(vertex_connectivity(&disconnected), 0);
+    }
+
+    #[test]
+    fn articulation_point_works() {
+        // Two copies of K4 glued at vertex 3.
+        let mut edges = Vec::new();
+        for u in 0..4 {
+            for v in u + 1..4 {
+                edges.push((u, v));
+                edges.push((u + 3, v + 3));
+            }
+        }
+        let graph = StaticGraph::new(7, &edges);
+        assert_eq!(vertex_connectivity(&graph), 1);
+        assert_eq!(edge_connectivity(&graph), 3);
+
+        let doubled = StaticGraph::new(3, &[(0, 1), (0, 1), (1, 2), (1, 2), (2, 2)]);
+        assert_eq!(edge_connectivity(&doubled), 2);
+        assert_eq!(vertex_connectivity(&doubled), 1);
+    }
+
+    #[test]
+    fn random_graphs_match_brute_force() {
+        let mut rng = XorShift::new(179);
+        for _ in 0..100 {
+            let n = 2 + rng.next_below(6);
+            let edges: Vec<(usize, usize)> = (0..rng.next_below(16))
+                .map(|_| (rng.next_below(n), rng.next_below(n)))
+                .collect();
+            let graph = StaticGraph::new(n, &edges);
+
+            let expected_vertex = (0u32..1 << n)
+                .filter(|&mask| {
+                    let removed: Vec<bool> = (0..n).map(|v| mask >> v & 1 == 1).collect();
+                    (mask.count_ones() as usize) < n - 1 && !connected_without(&graph, &removed)
+                })
+                .map(|mask| mask.count_ones() as usize)
+                .min()
+                .unwrap_or(n - 1);
+            assert_eq!(vertex_connectivity(&graph), expected_vertex);
+
+            // The smallest cut between the two sides of some partition.
+            let expected_edge = (1u32..1 << (n - 1))
+                .map(|mask| {
+                    edges
+                        .iter()
+                        .filter(|&&(u, v)| (mask >> u & 1) != (mask >> v & 1))
+                        .count()
+                })
+                .min()
+                .unwrap();
+            assert_eq!(edge_connectivity(&graph), expected_edge);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! This module contains algorithms on the cycles of undirected
+//! graphs: enumeration of all simple cycles and a minimum cycle
+//! basis of the cycle space.
+
+use std::collections::VecDeque;
+
+use graph::{AdjacencyList, StaticGraph, UnionFind};
+
+/// Return every simple cycle of the graph as the sorted list of
+/// its vertices, in lexicographic order. Distinct cycles through
+/// the same vertices (e.g. the three Hamiltonian cycles of K_4)
+/// each appear separately. Only cycles of length at least 3 are
+/// considered: self-loops and parallel edges are ignored.
+///
+/// Every cycle is found by a depth-first search from its smallest
+/// vertex, once per direction of traversal. The search extends every
+/// simple path that starts at its smallest vertex, whether or not it
+/// ever closes a cycle, so the running time is O(V + P * D) for P
+/// such paths and maximum degree D. P can be far larger than the
+/// number of cycles (a tree has no cycles but O(V²) such paths) and
+/// is exponential in dense graphs, so this is only practical for
+/// small graphs.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::enumerate_simple_cycles;
+/// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 0)]);
+/// assert_eq!(
+///     enumerate_simple_cycles(&graph),
+///     vec![vec![0, 1, 2], vec![0, 1, 2, 3], vec![0, 2, 3]]
+/// );
+/// ```
+pub fn enumerate_simple_cycles<G: AdjacencyList>(graph: &G) -> Vec<Vec<usize>> {
+    let n = graph.n_vert();
+    let neighbors: Vec<Vec<usize>> = (0..n)
+        .map(|u| {
+            let mut neigh: Vec<usize> = graph
+                .neighbors(u)
+                .iter()
+                .cloned()
+                .filter(|&v| v != u)
+                .collect();
+            neigh.sort_unstable();
+            neigh.dedup();
+            neigh
+        })
+        .collect();
+
+    let mut cycles = Vec::new();
+    let mut on_path = vec![false; n];
+    for start in 0..n {
+        let mut path = vec![start];
+        on_path[start] = true;
+        extend_path(&neighbors, start, &mut path, &mut on_path, &mut cycles);
+        on_path[start] = false;
+    }
+    cycles.sort();
+    cycles
+}
+
+/// Continue the simple path from `start` in every possible way
+/// through vertices greater than `start`, recording the cycles
+/// it closes in one of the two directions.
+fn extend_path(
+    neighbors: &[Vec<usize>],
+    start: usize,
+    path: &mut Vec<usize>,
+    on_path: &mut [bool],
+    cycles: &mut Vec<Vec<usize>>,
+) {
+    let last = *path.last().unwrap();
+    for &v in &neighbors[last] {
+        if v == start && path.len() >= 3 && path[1] < last {
+            let mut cycle = path.clone();
+            cycle.sort_unstable();
+            cycles.push(cycle);
+        } else if v > start && !on_path[v] {
+            on_path[v] = true;
+            path.push(v);
+            extend_path(neighbors, start, path, on_path, cycles);
+            path.pop();
+            on_path[v] = false;
+        }
+    }
+}
+
+/// Return a minimum cycle basis of the graph: a basis of its cycle
+/// space (over GF(2)) with the smallest total number of edges. Every
+/// cycle is returned as the sorted list of its vertices, shortest
+/// first. The basis has `E - V + C` cycles, where C is the number of
+/// connected components; self-loops and parallel edges count as
+/// cycles of length 1 and 2.
+///
+/// Uses Horton's algorithm: for every vertex `v` and edge `(x, y)`,
+/// the candidate cycle closes the paths from `v` to `x` and `y` in
+/// a breadth-first search tree rooted at `v`. The candidates are
+/// taken shortest first while they are independent of the ones
+/// already chosen, checked with Gaussian elimination on the edge
+/// incidence vectors. Runs in O(V * E^2 * (E - V) / 64).
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::minimum_cycle_basis;
+/// let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 0), (1, 4), (4, 2)]);
+/// assert_eq!(minimum_cycle_basis(&graph), vec![vec![1, 2, 4], vec![0, 1, 2, 3]]);
+/// ```
+pub fn minimum_cycle_basis(graph: &StaticGraph) -> Vec<Vec<usize>> {
+    let n = graph.n_vert();
+    let edges = graph.edges();
+    let mut adjacent = vec![Vec::new(); n];
+    let mut uf = UnionFind::new(n);
+    for (e, &(u, v)) in edges.iter().enumerate() {
+        adjacent[u].push((v, e));
+        if u != v {
+            adjacent[v].push((u, e));
+        }
+        uf.union(u, v);
+    }
+    let dimension = edges.len() + uf.n_components() - n;
+
+    let mut candidates = Vec::new();
+    for root in 0..n {
+        candidates.extend(horton_candidates(&adjacent, &edges, root));
+    }
+    candidates.sort_by_key(|&(len, _, _)| len);
+
+    let words = edges.len().div_ceil(64);
+    let mut basis: Vec<(usize, Vec<u64>)> = Vec::new();
+    let mut cycles = Vec::new();
+    for (_, mut vector, vertices) in candidates {
+        if cycles.len() == dimension {
+            break;
+        }
+        vector.resize(words, 0);
+        for (pivot, row) in &basis {
+            if vector[pivot / 64] >> (pivot % 64) & 1 == 1 {
+                for (x, y) in vector.iter_mut().zip(row.iter()) {
+                    *x ^= y;
+                }
+            }
+        }
+        if let Some(word) = vector.iter().position(|&x| x != 0) {
+            let pivot = 64 * word + vector[word].trailing_zeros() as usize;
+            basis.push((pivot, vector));
+            cycles.push(vertices);
+        }
+    }
+    cycles
+}
+
+/// Return the candidate cycles `(length, edge set, sorted vertices)`
+/// closed by the non-tree edges of a breadth-first search tree
+/// rooted at `root`, keeping only those that are simple.
+fn horton_candidates(
+    adjacent: &[Vec<(usize, usize)>],
+    edges: &[(usize, usize)],
+    root: usize,
+) -> Vec<(usize, Vec<u64>, Vec<usize>)> {
+    let n = adjacent.len();
+    let mut parent: Vec<Option<(usize, usize)>> = vec![None; n];
+    let mut reached = vec![false; n];
+    let mut queue = VecDeque::new();
+    reached[root] = true;
+    queue.push_back(root);
+    while let Some(u) = queue.pop_front() {
+        for &(v, e) in &adjacent[u] {
+            if !reached[v] {
+                reached[v] = true;
+                parent[v] = Some((u, e));
+                queue.push_back(v);
+            }
+        }
+    }
+
+    let mut candidates = Vec::new();
+    let mut seen = vec![false; n];
+    for (e, &(x, y)) in edges.iter().enumerate() {
+        let is_tree_edge =
+            parent[x].is_some_and(|(_, f)| f == e) || parent[y].is_some_and(|(_, f)| f == e);
+        // A self-loop is only simple as a cycle through the root.
+        if !reached[x] || is_tree_edge || (x == y && x != root) {
+            continue;
+        }
+        let mut vector = vec![0u64; e / 64 + 1];
+        vector[e / 64] |= 1 << (e % 64);
+        let mut vertices = vec![root];
+        let mut simple = true;
+        for &end in &[x, y] {
+            let mut v = end;
+            while let Some((p, f)) = parent[v] {
+                if seen[v] {
+                    simple = false;
+                    break;
+                }
+                seen[v] = true;
+                vertices.push(v);
+                if vector.len() <= f / 64 {
+                    vector.resize(f / 64 + 1, 0);
+                }
+                vector[f / 64] |= 1 << (f % 64);
+                v = p;
+            }
+        }
+        for &v in &vertices {
+            seen[v] = false;
+        }
+        if simple {
+            vertices.sort_unstable();
+            let len = vector.iter().map(|x| x.count_ones() as usize).sum();
+            candidates.push((len, vector, vertices));
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{enumerate_simple_cycles, minimum_cycle_basis};
+    use graph::{random_graph_gnp, StaticGraph, UnionFind};
+
+    fn complete_graph(n: usize) -> StaticGraph {
+        random_graph_gnp(n, 1.0, 0)
+    }
+
+    #[test]
+    fn small_cycle_counts_work() {
+        let triangle = complete_graph(3);
+        assert_eq!(enumerate_simple_cycles(&triangle), vec![vec![0, 1, 2]]);
+        assert_eq!(minimum_cycle_basis(&triangle), vec![vec![0, 1, 2]]);
+
+        // 4 triangles and 3 Hamiltonian cycles.
+        let cycles = enumerate_simple_cycles(&complete_graph(4));
+        assert_eq!(cycles.len(), 7);
+        assert_eq!(cycles.iter().filter(|c| c.len() == 4).count(), 3);
+        // Sum over k >= 3 of C(5, k) * (k - 1)! / 2.
+        assert_eq!(enumerate_simple_cycles(&complete_graph(5)).len(), 37);
+    }
+
+    #[test]
+    fn forests_have_no_cycles() {
+        let forest = StaticGraph::new(7, &[(0, 1), (1, 2), (1, 3), (4, 5)]);
+        assert!(enumerate_simple_cycles(&forest).is_empty());
+        assert!(minimum_cycle_basis(&forest).is_empty());
+        assert!(enumerate_simple_cycles(&StaticGraph::new(0, &[])).is_empty());
+    }
+
+    #[test]
+    fn basis_has_cycle_space_dimension() {
+        for seed in 0..20 {
+            let graph = random_graph_gnp(12, 0.3, seed);
+            let mut uf = UnionFind::new(12);
+            for (u, v) in graph.edges() {
+                uf.union(u, v);
+            }
+            let basis = minimum_cycle_basis(&graph);
+            assert_eq!(basis.len(), graph.n_edges() + uf.n_components() - 12);
+            assert!(basis.windows(2).all(|w| w[0].len() <= w[1].len()));
+            assert!(basis.iter().all(|c| c.len() >= 3));
+        }
+
+        // The 4-cycles of K_4 are sums of triangles.
+        let basis = minimum_cycle_basis(&complete_graph(4));
+        assert_eq!(basis.len(), 3);
+        assert!(basis.iter().all(|c| c.len() == 3));
+    }
+
+    #[test]
+    fn basis_has_minimum_total_length() {
+        let total = |graph: &StaticGraph| -> usize {
+            minimum_cycle_basis(graph).iter().map(|c| c.len()).sum()
+        };
+
+        // The 9 unit squares of a 4x4 grid; a spanning tree basis
+        // would use longer fundamental cycles.
+        let mut grid = Vec::new();
+        for r in 0..4 {
+            for c in 0..4 {
+                if c < 3 {
+                    grid.push((4 * r + c, 4 * r + c + 1));
+                }
+                if r < 3 {
+                    grid.push((4 * r + c, 4 * r + c + 4));
+                }
+            }
+        }
+        let grid = StaticGraph::new(16, &grid);
+        assert_eq!(minimum_cycle_basis(&grid).len(), 9);
+        assert_eq!(total(&grid), 36);
+
+        // A wheel with 6 spokes: 6 triangles, not the outer 6-cycle.
+        let mut wheel: Vec<(usize, usize)> = (1..7).map(|v| (0, v)).collect();
+        wheel.extend((1..7).map(|v| (v, v % 6 + 1)));
+        assert_eq!(total(&StaticGraph::new(7, &wheel)), 18);
+
+        // A triangular prism: both triangles and two of the squares.
+        let prism = [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (0, 3),
+            (1, 4),
+            (2, 5),
+        ];
+        assert_eq!(total(&StaticGraph::new(6, &prism)), 14);
+    }
+
+    #[test]
+    fn multigraph_basis_works() {
+        let graph = StaticGraph::new(3, &[(0, 1), (0, 1), (1, 2), (2, 2), (2, 0)]);
+        let basis = minimum_cycle_basis(&graph);
+        assert_eq!(basis, vec![vec![2], vec![0, 1], vec![0, 1, 2]]);
+        assert_eq!(enumerate_simple_cycles(&graph), vec![vec![0, 1, 2]]);
+    }
+}
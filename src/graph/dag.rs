@@ -0,0 +1,274 @@
+//! This module contains algorithms for directed acyclic graphs
+//! based on dynamic programming over a topological order.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::ops::Deref;
+
+use graph::{DiGraph, WeightedDiGraph};
+
+/// The error returned by algorithms that require an acyclic
+/// graph when given a graph containing a cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleError;
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the graph contains a cycle")
+    }
+}
+
+impl Error for CycleError {}
+
+/// A directed graph that is known to be acyclic. It dereferences
+/// to the wrapped `DiGraph` and keeps a topological order of it.
+///
+/// # Examples
+/// ```
+/// use std::convert::TryFrom;
+/// use ralgo::{Dag, DiGraph};
+/// let dag = Dag::try_from(DiGraph::new(3, &[(2, 0), (0, 1)])).unwrap();
+/// assert_eq!(dag.n_edges(), 2);
+/// assert_eq!(dag.topological_order(), &[2, 0, 1]);
+/// assert!(Dag::try_from(DiGraph::new(2, &[(0, 1), (1, 0)])).is_err());
+/// ```
+pub struct Dag {
+    graph: DiGraph,
+    order: Vec<usize>,
+}
+
+impl Dag {
+    /// Return the vertices of the graph in topological order.
+    pub fn topological_order(&self) -> &[usize] {
+        &self.order
+    }
+}
+
+impl TryFrom<DiGraph> for Dag {
+    type Error = CycleError;
+
+    /// Wrap the graph, failing with `CycleError` if it contains a cycle.
+    fn try_from(graph: DiGraph) -> Result<Dag, CycleError> {
+        let order = topological_order(&graph)?;
+        Ok(Dag { graph, order })
+    }
+}
+
+impl Deref for Dag {
+    type Target = DiGraph;
+
+    fn deref(&self) -> &DiGraph {
+        &self.graph
+    }
+}
+
+/// Return the vertices of a longest path (by number of edges)
+/// in the graph, or an empty vector if the graph has no vertices.
+///
+/// # Examples
+/// ```
+/// use std::convert::TryFrom;
+/// use ralgo::{Dag, DiGraph};
+/// use ralgo::graph::longest_path;
+/// let graph = DiGraph::new(4, &[(0, 1), (1, 2), (0, 3), (3, 2)]);
+/// let dag = Dag::try_from(graph).unwrap();
+/// assert_eq!(longest_path(&dag), vec![0, 1, 2]);
+/// ```
+pub fn longest_path(dag: &Dag) -> Vec<usize> {
+    let (_, path) = longest_path_by(dag.n_vert(), dag.topological_order(), |v| {
+        dag.successors(v).iter().map(|&u| (u, 1))
+    });
+    path
+}
+
+/// Return the number of edges in a longest path of the graph.
+pub fn longest_path_length(dag: &Dag) -> usize {
+    let mut length = vec![0; dag.n_vert()];
+    for &v in dag.topological_order() {
+        for &u in dag.successors(v) {
+            length[u] = length[u].max(length[v] + 1);
+        }
+    }
+    length.into_iter().max().unwrap_or(0)
+}
+
+/// Return the total weight and the vertices of a path with the
+/// largest total weight in the graph. A path may consist of a single
+/// vertex, in which case its weight is 0, so the returned weight
+/// is never negative. For a graph without vertices the path is empty.
+///
+/// # Errors
+///
+/// `CycleError` if the graph contains a cycle.
+///
+pub fn longest_weighted_path(graph: &WeightedDiGraph) -> Result<(i64, Vec<usize>), CycleError> {
+    let order = topological_order_by(graph.n_vert(), |v| {
+        graph.successors_with_weights(v).iter().map(|&(u, _)| u)
+    })?;
+    Ok(longest_path_by(graph.n_vert(), &order, |v| {
+        graph.successors_with_weights(v).iter().cloned()
+    }))
+}
+
+/// Return the vertices of the graph in topological order.
+pub(crate) fn topological_order(graph: &DiGraph) -> Result<Vec<usize>, CycleError> {
+    topological_order_by(graph.n_vert(), |v| graph.successors(v).iter().cloned())
+}
+
+/// Kahn's algorithm on a graph given by its successor function.
+fn topological_order_by<F, I>(n_vert: usize, successors: F) -> Result<Vec<usize>, CycleError>
+where
+    F: Fn(usize) -> I,
+    I: Iterator<Item = usize>,
+{
+    let mut in_degree = vec![0; n_vert];
+    for v in 0..n_vert {
+        for u in successors(v) {
+            in_degree[u] += 1;
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n_vert).filter(|&v| in_degree[v] == 0).collect();
+    let mut head = 0;
+    while head < order.len() {
+        let v = order[head];
+        head += 1;
+        for u in successors(v) {
+            in_degree[u] -= 1;
+            if in_degree[u] == 0 {
+                order.push(u);
+            }
+        }
+    }
+
+    if order.len() == n_vert {
+        Ok(order)
+    } else {
+        Err(CycleError)
+    }
+}
+
+/// Find the heaviest path given a topological order of the graph.
+fn longest_path_by<F, I>(n_vert: usize, order: &[usize], successors: F) -> (i64, Vec<usize>)
+where
+    F: Fn(usize) -> I,
+    I: Iterator<Item = (usize, i64)>,
+{
+    let mut weight = vec![0; n_vert];
+    let mut parent = vec![usize::MAX; n_vert];
+    for &v in order {
+        for (u, w) in successors(v) {
+            if weight[v] + w > weight[u] {
+                weight[u] = weight[v] + w;
+                parent[u] = v;
+            }
+        }
+    }
+
+    let mut end = match (0..n_vert).max_by_key(|&v| (weight[v], usize::MAX - v)) {
+        Some(end) => end,
+        None => return (0, Vec::new()),
+    };
+    let total = weight[end];
+    let mut path = vec![end];
+    while parent[end] != usize::MAX {
+        end = parent[end];
+        path.push(end);
+    }
+    path.reverse();
+    (total, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{longest_path, longest_path_length, longest_weighted_path, CycleError, Dag};
+    use graph::{DiGraph, WeightedDiGraph};
+    use std::convert::TryFrom;
+
+    fn dag(n_vert: usize, edges: &[(usize, usize)]) -> Dag {
+        Dag::try_from(DiGraph::new(n_vert, edges)).unwrap()
+    }
+
+    #[test]
+    fn dag_construction_works() {
+        let edges = [(4, 2), (2, 0), (4, 1), (1, 0), (3, 1)];
+        let dag = dag(5, &edges);
+        assert_eq!(dag.n_vert(), 5);
+        assert_eq!(dag.successors(4), &[2, 1]);
+
+        let order = dag.topological_order();
+        assert_eq!(order.len(), 5);
+        let mut pos = [0; 5];
+        for (i, &v) in order.iter().enumerate() {
+            pos[v] = i;
+        }
+        assert!(edges.iter().all(|&(u, v)| pos[u] < pos[v]));
+
+        let cyclic = DiGraph::new(3, &[(0, 1), (1, 2), (2, 1)]);
+        assert_eq!(Dag::try_from(cyclic).err(), Some(CycleError));
+        let self_loop = DiGraph::new(1, &[(0, 0)]);
+        assert!(Dag::try_from(self_loop).is_err());
+    }
+
+    #[test]
+    fn empty_and_chain_work() {
+        let graph = dag(0, &[]);
+        assert_eq!(longest_path(&graph), vec![]);
+        assert_eq!(longest_path_length(&graph), 0);
+
+        let graph = dag(3, &[]);
+        assert_eq!(longest_path(&graph).len(), 1);
+        assert_eq!(longest_path_length(&graph), 0);
+
+        let graph = dag(5, &[(2, 0), (0, 4), (4, 1), (1, 3)]);
+        assert_eq!(longest_path(&graph), vec![2, 0, 4, 1, 3]);
+        assert_eq!(longest_path_length(&graph), 4);
+    }
+
+    #[test]
+    fn longest_of_many_paths_is_found() {
+        // Vertex 0 has the most successors, but the longest
+        // path starts at vertex 5.
+        let graph = dag(
+            8,
+            &[
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (0, 4),
+                (5, 6),
+                (6, 7),
+                (7, 2),
+                (2, 3),
+            ],
+        );
+        assert_eq!(longest_path(&graph), vec![5, 6, 7, 2, 3]);
+        assert_eq!(longest_path_length(&graph), 4);
+    }
+
+    #[test]
+    fn weighted_path_works() {
+        let graph = WeightedDiGraph::new(
+            5,
+            &[
+                (0, 1, 3),
+                (1, 2, 4),
+                (0, 2, 10),
+                (2, 3, -5),
+                (3, 4, 6),
+                (2, 4, 2),
+            ],
+        );
+        assert_eq!(longest_weighted_path(&graph), Ok((12, vec![0, 2, 4])));
+
+        let graph = WeightedDiGraph::new(2, &[(0, 1, -1)]);
+        assert_eq!(longest_weighted_path(&graph), Ok((0, vec![0])));
+    }
+
+    #[test]
+    fn cycle_is_reported() {
+        let graph = WeightedDiGraph::new(2, &[(0, 1, 1), (1, 0, 1)]);
+        assert_eq!(longest_weighted_path(&graph), Err(CycleError));
+    }
+}
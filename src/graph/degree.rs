@@ -0,0 +1,130 @@
+//! This module contains functions dealing with vertex degrees:
+//! the degree sequence of a graph, the Erdős–Gallai test
+//! of whether a sequence is graphical and regularity checks.
+
+use graph::StaticGraph;
+
+/// Return the degrees of all vertices sorted in descending order.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::degree_sequence;
+/// let graph = StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(degree_sequence(&graph), vec![3, 1, 1, 1]);
+/// ```
+pub fn degree_sequence(graph: &StaticGraph) -> Vec<usize> {
+    let mut degrees: Vec<usize> = (0..graph.n_vert())
+        .map(|v| graph.neighbors(v).len())
+        .collect();
+    degrees.sort_unstable_by(|a, b| b.cmp(a));
+    degrees
+}
+
+/// Return `true` if there exists a simple graph with the given
+/// degree sequence, `false` otherwise. The check is done using
+/// the Erdős–Gallai theorem in O(n²).
+///
+/// # Arguments
+///
+/// * `sequence` - the degrees in any order.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::is_graphical;
+/// assert!(is_graphical(&[3, 3, 3, 3]));
+/// assert!(!is_graphical(&[3, 3, 1]));
+/// ```
+pub fn is_graphical(sequence: &[usize]) -> bool {
+    let mut degrees = sequence.to_vec();
+    degrees.sort_unstable_by(|a, b| b.cmp(a));
+
+    if degrees.iter().sum::<usize>() % 2 != 0 {
+        return false;
+    }
+
+    let mut prefix_sum = 0;
+    for k in 1..=degrees.len() {
+        prefix_sum += degrees[k - 1];
+        let tail: usize = degrees[k..].iter().map(|&d| d.min(k)).sum();
+        if prefix_sum > k * (k - 1) + tail {
+            return false;
+        }
+    }
+    true
+}
+
+/// Return `Some(k)` if every vertex of the graph has degree `k`,
+/// `None` otherwise (including the graph without vertices).
+pub fn regular_degree(graph: &StaticGraph) -> Option<usize> {
+    if graph.n_vert() == 0 {
+        return None;
+    }
+    let degree = graph.neighbors(0).len();
+    if (1..graph.n_vert()).all(|v| graph.neighbors(v).len() == degree) {
+        Some(degree)
+    } else {
+        None
+    }
+}
+
+/// Return `true` if every vertex of the graph has degree `k`.
+/// The graph without vertices is `k`-regular for every `k`.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::is_k_regular;
+/// let cycle = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert!(is_k_regular(&cycle, 2));
+/// assert!(!is_k_regular(&cycle, 3));
+/// ```
+pub fn is_k_regular(graph: &StaticGraph, k: usize) -> bool {
+    (0..graph.n_vert()).all(|v| graph.neighbors(v).len() == k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{degree_sequence, is_graphical, is_k_regular, regular_degree};
+    use graph::StaticGraph;
+
+    #[test]
+    fn degree_sequence_works() {
+        let star = StaticGraph::new(5, &[(2, 0), (2, 1), (2, 3), (2, 4)]);
+        assert_eq!(degree_sequence(&star), vec![4, 1, 1, 1, 1]);
+
+        let cycle = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+        assert_eq!(degree_sequence(&cycle), vec![2; 5]);
+
+        assert_eq!(degree_sequence(&StaticGraph::new(0, &[])), vec![]);
+    }
+
+    #[test]
+    fn is_graphical_works() {
+        assert!(is_graphical(&[3, 3, 3, 3]));
+        assert!(is_graphical(&[]));
+        assert!(is_graphical(&[0, 0, 0]));
+        assert!(is_graphical(&[1, 2, 2, 1]));
+        assert!(!is_graphical(&[3, 3, 1]));
+        assert!(!is_graphical(&[3, 3, 1, 1]));
+        assert!(is_graphical(&[4, 1, 1, 1, 1, 0]));
+        assert!(!is_graphical(&[4, 2, 1, 1]));
+    }
+
+    #[test]
+    fn regular_degree_works() {
+        let complete = StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        assert_eq!(regular_degree(&complete), Some(3));
+
+        let path = StaticGraph::new(3, &[(0, 1), (1, 2)]);
+        assert_eq!(regular_degree(&path), None);
+
+        assert_eq!(regular_degree(&StaticGraph::new(3, &[])), Some(0));
+        assert_eq!(regular_degree(&StaticGraph::new(0, &[])), None);
+
+        assert!(is_k_regular(&complete, 3));
+        assert!(!is_k_regular(&path, 1));
+        assert!(is_k_regular(&StaticGraph::new(3, &[]), 0));
+        assert!(is_k_regular(&StaticGraph::new(0, &[]), 7));
+    }
+}
@@ -0,0 +1,372 @@
+//! This module contains depth-first search over an undirected
+//! graph from a single source vertex.
+
+use graph::{enumerate_simple_cycles, AdjacencyList};
+
+/// The result of a depth-first search from a source vertex: the
+/// search tree given by parent pointers, the order in which the
+/// vertices were reached and whether the search met a cycle.
+/// Neighbors are visited in the order of the adjacency lists, and
+/// the search runs on any `AdjacencyList`.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::Dfs;
+/// let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (0, 2), (3, 4)]);
+/// let dfs = Dfs::new(&graph, 0);
+/// assert_eq!(dfs.order(), &[0, 1, 2]);
+/// assert_eq!(dfs.parent(2), Some(1));
+/// assert_eq!(dfs.n_vert_reached(), 3);
+/// assert!(dfs.cycle_found());
+/// assert_eq!(dfs.cycle_vertices(), Some(vec![0, 1, 2]));
+/// ```
+pub struct Dfs {
+    parent: Vec<Option<usize>>,
+    visited: Vec<bool>,
+    order: Vec<usize>,
+    on_stack: Vec<bool>,
+    cycle: Option<Vec<usize>>,
+}
+
+impl Dfs {
+    /// Run the search on `graph` from `source` in O(V + E). The
+    /// search is recursive, so its depth is bounded by the stack;
+    /// use `new_iterative` for long paths. A graph without vertices
+    /// gives an empty search that reaches nothing, whatever `source`.
+    ///
+    /// # Panics
+    ///
+    /// If the graph has vertices and `source >= graph.n_vert()`.
+    ///
+    pub fn new<G: AdjacencyList>(graph: &G, source: usize) -> Dfs {
+        let mut dfs = Dfs::empty(graph.n_vert(), source);
+        if graph.n_vert() > 0 {
+            dfs.run(graph, source);
+        }
+        dfs
+    }
+
+    /// Run the same search as `new` with an explicit stack, so that
+    /// deep graphs do not overflow the call stack. Every vertex keeps
+    /// a frame with the index of its next neighbor, so neighbors are
+    /// visited in exactly the same order and the result is identical.
+    ///
+    /// # Panics
+    ///
+    /// If the graph has vertices and `source >= graph.n_vert()`.
+    ///
+    pub fn new_iterative<G: AdjacencyList>(graph: &G, source: usize) -> Dfs {
+        let mut dfs = Dfs::empty(graph.n_vert(), source);
+        if graph.n_vert() == 0 {
+            return dfs;
+        }
+        dfs.enter(source);
+        let mut stack = vec![(source, 0, None)];
+        while let Some(&mut (u, ref mut next, ref mut parent_edge)) = stack.last_mut() {
+            let v = match graph.neighbors(u).get(*next) {
+                Some(&v) => v,
+                None => {
+                    dfs.on_stack[u] = false;
+                    stack.pop();
+                    continue;
+                }
+            };
+            *next += 1;
+            if !dfs.visited[v] {
+                dfs.parent[v] = Some(u);
+                dfs.enter(v);
+                stack.push((v, 0, Some(u)));
+            } else if *parent_edge == Some(v) {
+                *parent_edge = None;
+            } else if v != u {
+                dfs.close_cycle(u, v);
+            }
+        }
+        dfs
+    }
+
+    /// Return the state before the search.
+    fn empty(n_vert: usize, source: usize) -> Dfs {
+        assert!(
+            n_vert == 0 || source < n_vert,
+            "source {} is out of range",
+            source
+        );
+        Dfs {
+            parent: vec![None; n_vert],
+            visited: vec![false; n_vert],
+            order: Vec::new(),
+            on_stack: vec![false; n_vert],
+            cycle: None,
+        }
+    }
+
+    /// Visit `u` and everything reachable from it.
+    fn run<G: AdjacencyList>(&mut self, graph: &G, u: usize) {
+        self.enter(u);
+        // The edge to the parent is skipped once; a parallel edge
+        // to the parent closes a cycle. Self-loops are skipped.
+        let mut parent_edge = self.parent[u];
+        for &v in graph.neighbors(u) {
+            if !self.visited[v] {
+                self.parent[v] = Some(u);
+                self.run(graph, v);
+            } else if parent_edge == Some(v) {
+                parent_edge = None;
+            } else if v != u {
+                self.close_cycle(u, v);
+            }
+        }
+        self.on_stack[u] = false;
+    }
+
+    /// Mark `u` as reached and put it on the stack.
+    fn enter(&mut self, u: usize) {
+        self.visited[u] = true;
+        self.on_stack[u] = true;
+        self.order.push(u);
+    }
+
+    /// Handle the non-tree edge from `u` to the reached vertex `v`.
+    fn close_cycle(&mut self, u: usize, v: usize) {
+        if self.cycle.is_some() || !self.on_stack[v] {
+            return;
+        }
+        // `v` is an ancestor of `u`, and the tree path from it down
+        // to `u` closes a cycle with this edge.
+        let mut cycle = vec![u];
+        let mut w = u;
+        while w != v {
+            w = self.parent[w].unwrap();
+            cycle.push(w);
+        }
+        cycle.reverse();
+        self.cycle = Some(cycle);
+    }
+
+    /// Return the vertex from which `vert` was reached, or `None`
+    /// for the source and for unreachable vertices.
+    pub fn parent(&self, vert: usize) -> Option<usize> {
+        self.parent[vert]
+    }
+
+    /// Return `true` if `vert` is reachable from the source.
+    pub fn is_reached(&self, vert: usize) -> bool {
+        self.visited[vert]
+    }
+
+    /// Return the reachable vertices in the order they were reached.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Return the number of vertices reachable from the source.
+    pub fn n_vert_reached(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Return `true` if the component of the source has a cycle,
+    /// including one closed by parallel edges. Self-loops are not
+    /// counted as cycles.
+    pub fn cycle_found(&self) -> bool {
+        self.cycle.is_some()
+    }
+
+    /// Return the vertices of the first cycle met by the search in
+    /// the order they go around it, starting from the vertex closest
+    /// to the source, or `None` if the component of the source is
+    /// acyclic. A parallel edge gives its two ends; self-loops are
+    /// ignored.
+    pub fn cycle_vertices(&self) -> Option<Vec<usize>> {
+        self.cycle.clone()
+    }
+
+    /// Return every simple cycle of length at least 3 through the
+    /// vertices reached by the search, each as a sorted list of its
+    /// vertices, in lexicographic order. `graph` must be the graph
+    /// the search ran on. This enumerates all cycles of the graph
+    /// with `enumerate_simple_cycles` and takes exponential time in
+    /// the worst case.
+    pub fn all_cycles<G: AdjacencyList>(&self, graph: &G) -> Vec<Vec<usize>> {
+        enumerate_simple_cycles(graph)
+            .into_iter()
+            .filter(|cycle| self.visited[cycle[0]])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dfs;
+    use graph::random::XorShift;
+    use graph::{GraphIndexed, StaticGraph};
+
+    #[test]
+    fn tree_has_no_cycle() {
+        let graph = StaticGraph::new(6, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]);
+        let dfs = Dfs::new(&graph, 0);
+        assert!(!dfs.cycle_found());
+        assert_eq!(dfs.order(), &[0, 1, 3, 4, 2, 5]);
+        let parents: Vec<Option<usize>> = (0..6).map(|v| dfs.parent(v)).collect();
+        assert_eq!(
+            parents,
+            vec![None, Some(0), Some(0), Some(1), Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn cycles_are_found() {
+        let square = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+        assert!(Dfs::new(&square, 2).cycle_found());
+        let parallel = StaticGraph::new(2, &[(0, 1), (1, 0)]);
+        assert!(Dfs::new(&parallel, 0).cycle_found());
+        let self_loop = StaticGraph::new(2, &[(0, 1), (1, 1)]);
+        assert!(!Dfs::new(&self_loop, 0).cycle_found());
+        assert!(!Dfs::new_iterative(&self_loop, 0).cycle_found());
+    }
+
+    #[test]
+    fn self_loop_only_is_not_a_cycle() {
+        for graph in [
+            StaticGraph::new_with_loops(1, &[(0, 0)]),
+            StaticGraph::new(1, &[(0, 0)]),
+            StaticGraph::new(1, &[(0, 0), (0, 0)]),
+        ]
+        .iter()
+        {
+            for dfs in [Dfs::new(graph, 0), Dfs::new_iterative(graph, 0)].iter() {
+                assert!(!dfs.cycle_found());
+                assert_eq!(dfs.cycle_vertices(), None);
+                assert_eq!(dfs.order(), &[0]);
+            }
+        }
+        // A self-loop on a real cycle does not hide the cycle.
+        let graph = StaticGraph::new(3, &[(0, 0), (0, 1), (1, 2), (2, 0)]);
+        assert_eq!(Dfs::new(&graph, 0).cycle_vertices(), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn cycle_vertices_work() {
+        let square = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+        assert_eq!(
+            Dfs::new(&square, 0).cycle_vertices(),
+            Some(vec![0, 1, 2, 3])
+        );
+        let tail = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 2)]);
+        assert_eq!(Dfs::new(&tail, 0).cycle_vertices(), Some(vec![2, 3, 4]));
+        let parallel = StaticGraph::new(2, &[(0, 1), (1, 0)]);
+        assert_eq!(Dfs::new(&parallel, 0).cycle_vertices(), Some(vec![0, 1]));
+        let self_loop = StaticGraph::new(2, &[(0, 1), (1, 1)]);
+        assert_eq!(Dfs::new(&self_loop, 0).cycle_vertices(), None);
+        let tree = StaticGraph::new(4, &[(0, 1), (1, 2), (1, 3)]);
+        assert_eq!(Dfs::new(&tree, 3).cycle_vertices(), None);
+        assert!(Dfs::new(&tree, 3).all_cycles(&tree).is_empty());
+    }
+
+    #[test]
+    fn two_disconnected_cycles_work() {
+        // A triangle and a square sharing no vertices.
+        let edges = [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 6), (6, 3)];
+        let graph = StaticGraph::new(7, &edges);
+        let left = Dfs::new(&graph, 1);
+        assert_eq!(left.cycle_vertices(), Some(vec![1, 0, 2]));
+        assert_eq!(left.all_cycles(&graph), vec![vec![0, 1, 2]]);
+        let right = Dfs::new(&graph, 5);
+        let mut cycle = right.cycle_vertices().unwrap();
+        cycle.sort_unstable();
+        assert_eq!(cycle, vec![3, 4, 5, 6]);
+        assert_eq!(right.all_cycles(&graph), vec![vec![3, 4, 5, 6]]);
+    }
+
+    #[test]
+    fn only_the_component_of_the_source_is_searched() {
+        let graph = StaticGraph::new(5, &[(0, 1), (2, 3), (3, 4), (4, 2)]);
+        let dfs = Dfs::new(&graph, 1);
+        assert_eq!(dfs.n_vert_reached(), 2);
+        assert!(!dfs.cycle_found());
+        assert!(!dfs.is_reached(2));
+        assert_eq!(dfs.parent(3), None);
+        assert!(Dfs::new(&graph, 4).cycle_found());
+    }
+
+    #[test]
+    fn graph_types_give_the_same_search() {
+        let mut rng = XorShift::new(103);
+        for _ in 0..30 {
+            let n_vert = 1 + rng.next_below(12);
+            let edges: Vec<(usize, usize)> = (0..rng.next_below(20))
+                .map(|_| (rng.next_below(n_vert), rng.next_below(n_vert)))
+                .collect();
+            let source = rng.next_below(n_vert);
+            let graph = StaticGraph::new(n_vert, &edges);
+            let indexed = GraphIndexed::new(n_vert, &edges);
+            let (left, right) = (Dfs::new(&graph, source), Dfs::new(&indexed, source));
+            assert_eq!(left.order(), right.order());
+            assert_eq!(left.cycle_vertices(), right.cycle_vertices());
+            assert_eq!(left.all_cycles(&graph), right.all_cycles(&indexed));
+            for v in 0..n_vert {
+                assert_eq!(left.parent(v), right.parent(v));
+            }
+        }
+    }
+
+    #[test]
+    fn iterative_search_matches_recursive() {
+        let mut rng = XorShift::new(113);
+        for _ in 0..100 {
+            let n_vert = 1 + rng.next_below(15);
+            let edges: Vec<(usize, usize)> = (0..rng.next_below(25))
+                .map(|_| (rng.next_below(n_vert), rng.next_below(n_vert)))
+                .collect();
+            let graph = StaticGraph::new(n_vert, &edges);
+            let source = rng.next_below(n_vert);
+            let (recursive, iterative) =
+                (Dfs::new(&graph, source), Dfs::new_iterative(&graph, source));
+            assert_eq!(recursive.order(), iterative.order());
+            assert_eq!(recursive.n_vert_reached(), iterative.n_vert_reached());
+            assert_eq!(recursive.cycle_found(), iterative.cycle_found());
+            assert_eq!(recursive.cycle_vertices(), iterative.cycle_vertices());
+            for v in 0..n_vert {
+                assert_eq!(recursive.parent(v), iterative.parent(v));
+                assert_eq!(recursive.is_reached(v), iterative.is_reached(v));
+            }
+        }
+    }
+
+    #[test]
+    fn zero_vertex_graphs_give_empty_searches() {
+        let graph = StaticGraph::new(0, &[]);
+        let indexed = GraphIndexed::new(0, &[]);
+        for dfs in [
+            Dfs::new(&graph, 0),
+            Dfs::new_iterative(&graph, 0),
+            Dfs::new(&indexed, 0),
+            Dfs::new_iterative(&indexed, 0),
+        ]
+        .iter()
+        {
+            assert_eq!(dfs.n_vert_reached(), 0);
+            assert_eq!(dfs.order(), &[]);
+            assert!(!dfs.cycle_found());
+            assert_eq!(dfs.cycle_vertices(), None);
+            assert!(dfs.all_cycles(&graph).is_empty());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range_source_panics() {
+        Dfs::new(&StaticGraph::new(2, &[(0, 1)]), 2);
+    }
+
+    #[test]
+    fn iterative_search_handles_long_paths() {
+        let n_vert = 1_000_000;
+        let edges: Vec<(usize, usize)> = (1..n_vert).map(|v| (v - 1, v)).collect();
+        let dfs = Dfs::new_iterative(&StaticGraph::new(n_vert, &edges), 0);
+        assert_eq!(dfs.n_vert_reached(), n_vert);
+        assert_eq!(dfs.parent(n_vert - 1), Some(n_vert - 2));
+        assert!(!dfs.cycle_found());
+    }
+}
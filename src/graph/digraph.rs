@@ -0,0 +1,152 @@
+//! This module defines a static directed graph data structure
+//! whose vertices are indexed with integers 0, 1, ..., N-1.
+//! This graph can not be modified once created.
+
+/// The integer-indexed static directed graph data structure.
+/// Both successors and predecessors of every vertex are stored.
+///
+/// # Examples
+/// ```
+/// use ralgo::DiGraph;
+/// let graph = DiGraph::new(4, &[(0, 1), (0, 2), (2, 1)]);
+/// assert_eq!(graph.n_vert(), 4);
+/// assert_eq!(graph.n_edges(), 3);
+/// assert_eq!(graph.successors(0), &[1, 2]);
+/// assert_eq!(graph.predecessors(1), &[0, 2]);
+/// assert_eq!(graph.successors(3), &[]);
+/// ```
+pub struct DiGraph {
+    succ_offset: Vec<usize>,
+    succ: Vec<usize>,
+    pred_offset: Vec<usize>,
+    pred: Vec<usize>,
+}
+
+impl DiGraph {
+    /// Return a new instance of DiGraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices;
+    /// * `edges` - pairs `(from, to)` of directed edges.
+    ///
+    /// # Panics
+    ///
+    /// If `edges` contains an element >= `n_vert`.
+    ///
+    pub fn new(n_vert: usize, edges: &[(usize, usize)]) -> DiGraph {
+        for &(u, v) in edges {
+            assert!(
+                u < n_vert && v < n_vert,
+                "edge ({}, {}) is out of range for a graph with {} vertices",
+                u,
+                v,
+                n_vert
+            );
+        }
+        let (succ_offset, succ) = compress(n_vert, edges.iter().cloned());
+        let (pred_offset, pred) = compress(n_vert, edges.iter().map(|&(u, v)| (v, u)));
+        DiGraph {
+            succ_offset,
+            succ,
+            pred_offset,
+            pred,
+        }
+    }
+
+    /// Return the number of vertices in given DiGraph instance.
+    pub fn n_vert(&self) -> usize {
+        self.succ_offset.len() - 1
+    }
+
+    /// Return the number of edges in given DiGraph instance.
+    pub fn n_edges(&self) -> usize {
+        self.succ.len()
+    }
+
+    /// Return the slice of vertices that the given vertex has
+    /// outgoing edges to, in the order the edges were given.
+    ///
+    /// # Arguments
+    ///
+    /// * `vert` - the vertex in question.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` >= `self.n_vert()`.
+    ///
+    pub fn successors(&self, vert: usize) -> &[usize] {
+        &self.succ[self.succ_offset[vert]..self.succ_offset[vert + 1]]
+    }
+
+    /// Return the slice of vertices that have outgoing edges
+    /// to the given vertex, in the order the edges were given.
+    ///
+    /// # Arguments
+    ///
+    /// * `vert` - the vertex in question.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` >= `self.n_vert()`.
+    ///
+    pub fn predecessors(&self, vert: usize) -> &[usize] {
+        &self.pred[self.pred_offset[vert]..self.pred_offset[vert + 1]]
+    }
+}
+
+/// Build the compressed adjacency representation: the targets of
+/// vertex `v` are `targets[offset[v]..offset[v + 1]]`.
+fn compress<I>(n_vert: usize, edges: I) -> (Vec<usize>, Vec<usize>)
+where
+    I: Iterator<Item = (usize, usize)> + Clone,
+{
+    let mut offset = vec![0; n_vert + 1];
+    for (u, _) in edges.clone() {
+        offset[u + 1] += 1;
+    }
+    for i in 1..=n_vert {
+        offset[i] += offset[i - 1];
+    }
+
+    let mut pos = offset.clone();
+    let mut targets = vec![0; offset[n_vert]];
+    for (u, v) in edges {
+        targets[pos[u]] = v;
+        pos[u] += 1;
+    }
+    (offset, targets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DiGraph;
+
+    #[test]
+    fn simple_digraph_works() {
+        let graph = DiGraph::new(5, &[(0, 1), (2, 1), (1, 3), (3, 0)]);
+        assert_eq!(graph.n_vert(), 5);
+        assert_eq!(graph.n_edges(), 4);
+        assert_eq!(graph.successors(0), &[1]);
+        assert_eq!(graph.successors(1), &[3]);
+        assert_eq!(graph.successors(2), &[1]);
+        assert_eq!(graph.predecessors(1), &[0, 2]);
+        assert_eq!(graph.predecessors(0), &[3]);
+        assert_eq!(graph.predecessors(2), &[]);
+        assert_eq!(graph.successors(4), &[]);
+        assert_eq!(graph.predecessors(4), &[]);
+    }
+
+    #[test]
+    fn empty_digraph_works() {
+        let graph = DiGraph::new(0, &[]);
+        assert_eq!(graph.n_vert(), 0);
+        assert_eq!(graph.n_edges(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range_edge_panics() {
+        DiGraph::new(2, &[(0, 2)]);
+    }
+}
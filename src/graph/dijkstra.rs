@@ -0,0 +1,212 @@
+//! This module contains Dijkstra's single-source shortest paths
+//! algorithm with a choice of priority queue.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use data_structures::FibHeap;
+use graph::WeightedGraph;
+
+/// Shortest paths from a single source in a graph with
+/// non-negative edge weights.
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedGraph;
+/// use ralgo::graph::Dijkstra;
+/// let graph = WeightedGraph::new(4, &[(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 5)]);
+/// let paths = Dijkstra::new(&graph, 0);
+/// assert_eq!(paths.distance(1), Some(3));
+/// assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+/// ```
+pub struct Dijkstra {
+    source: usize,
+    dist: Vec<Option<i64>>,
+    pred: Vec<Option<usize>>,
+}
+
+impl Dijkstra {
+    /// Run Dijkstra's algorithm with a binary heap and lazy
+    /// deletion in O(E log V).
+    ///
+    /// # Panics
+    ///
+    /// If `source` is out of range or the graph has a negative edge.
+    ///
+    pub fn new(graph: &WeightedGraph, source: usize) -> Dijkstra {
+        let mut paths = Dijkstra::start(graph, source);
+        let mut done = vec![false; graph.n_vert()];
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((0, source)));
+        while let Some(Reverse((d, u))) = heap.pop() {
+            if done[u] {
+                continue;
+            }
+            done[u] = true;
+            for &(v, w) in graph.neighbors_with_weights(u) {
+                if paths.relax(u, v, d + w) {
+                    heap.push(Reverse((d + w, v)));
+                }
+            }
+        }
+        paths
+    }
+
+    /// Run Dijkstra's algorithm with a Fibonacci heap in
+    /// O(E + V log V).
+    ///
+    /// # Panics
+    ///
+    /// If `source` is out of range or the graph has a negative edge.
+    ///
+    pub fn new_fibonacci(graph: &WeightedGraph, source: usize) -> Dijkstra {
+        let mut paths = Dijkstra::start(graph, source);
+        let mut handle = vec![None; graph.n_vert()];
+        let mut heap = FibHeap::new();
+        handle[source] = Some(heap.insert(0, source));
+        while let Some((d, u)) = heap.extract_min() {
+            for &(v, w) in graph.neighbors_with_weights(u) {
+                if paths.relax(u, v, d + w) {
+                    match handle[v] {
+                        None => handle[v] = Some(heap.insert(d + w, v)),
+                        Some(h) => heap.decrease_key(h, d + w),
+                    }
+                }
+            }
+        }
+        paths
+    }
+
+    /// Return the source vertex.
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Return the length of a shortest path from the source to
+    /// `vert`, or `None` if `vert` is unreachable.
+    pub fn distance(&self, vert: usize) -> Option<i64> {
+        self.dist[vert]
+    }
+
+    /// Return the vertices of a shortest path from the source
+    /// to `vert`, or `None` if `vert` is unreachable.
+    pub fn path_to(&self, vert: usize) -> Option<Vec<usize>> {
+        self.dist[vert]?;
+        let mut path = vec![vert];
+        let mut current = vert;
+        while let Some(p) = self.pred[current] {
+            path.push(p);
+            current = p;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Return the initial state with only the source reached.
+    fn start(graph: &WeightedGraph, source: usize) -> Dijkstra {
+        assert!(source < graph.n_vert(), "source {} is out of range", source);
+        assert!(
+            graph.edges().iter().all(|&(_, _, w)| w >= 0),
+            "Dijkstra's algorithm requires non-negative weights"
+        );
+        let mut dist = vec![None; graph.n_vert()];
+        dist[source] = Some(0);
+        Dijkstra {
+            source,
+            dist,
+            pred: vec![None; graph.n_vert()],
+        }
+    }
+
+    /// Record the path to `v` through `u` of length `d` if it is
+    /// shorter than the best known one.
+    fn relax(&mut self, u: usize, v: usize, d: i64) -> bool {
+        if self.dist[v].is_some_and(|best| best <= d) {
+            return false;
+        }
+        self.dist[v] = Some(d);
+        self.pred[v] = Some(u);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dijkstra;
+    use graph::random::XorShift;
+    use graph::WeightedGraph;
+
+    #[test]
+    fn simple_graph_works() {
+        let graph = WeightedGraph::new(
+            6,
+            &[
+                (0, 1, 7),
+                (0, 2, 9),
+                (0, 5, 14),
+                (1, 2, 10),
+                (1, 3, 15),
+                (2, 3, 11),
+                (2, 5, 2),
+                (3, 4, 6),
+            ],
+        );
+        let paths = Dijkstra::new(&graph, 0);
+        let distances: Vec<Option<i64>> = (0..6).map(|v| paths.distance(v)).collect();
+        assert_eq!(
+            distances,
+            vec![Some(0), Some(7), Some(9), Some(20), Some(26), Some(11)]
+        );
+        assert_eq!(paths.path_to(4), Some(vec![0, 2, 3, 4]));
+        assert_eq!(paths.path_to(0), Some(vec![0]));
+        assert_eq!(paths.source(), 0);
+    }
+
+    #[test]
+    fn unreachable_vertices_work() {
+        let graph = WeightedGraph::new(3, &[(0, 1, 0)]);
+        let paths = Dijkstra::new_fibonacci(&graph, 1);
+        assert_eq!(paths.distance(0), Some(0));
+        assert_eq!(paths.distance(2), None);
+        assert_eq!(paths.path_to(2), None);
+    }
+
+    #[test]
+    fn heaps_agree_on_random_graphs() {
+        let mut rng = XorShift::new(79);
+        for _ in 0..30 {
+            let n_vert = 1 + rng.next_below(40);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.next_below(120))
+                .map(|_| {
+                    (
+                        rng.next_below(n_vert),
+                        rng.next_below(n_vert),
+                        rng.next_below(20) as i64,
+                    )
+                })
+                .collect();
+            let graph = WeightedGraph::new(n_vert, &edges);
+            let source = rng.next_below(n_vert);
+            let binary = Dijkstra::new(&graph, source);
+            let fibonacci = Dijkstra::new_fibonacci(&graph, source);
+            for v in 0..n_vert {
+                assert_eq!(binary.distance(v), fibonacci.distance(v));
+                if let Some(path) = fibonacci.path_to(v) {
+                    let length: i64 = path
+                        .windows(2)
+                        .map(|w| {
+                            graph
+                                .neighbors_with_weights(w[0])
+                                .iter()
+                                .filter(|&&(x, _)| x == w[1])
+                                .map(|&(_, wt)| wt)
+                                .min()
+                                .unwrap()
+                        })
+                        .sum();
+                    assert_eq!(Some(length), binary.distance(v));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! This module contains serializers of graphs into the DOT
+//! language of Graphviz for visualization.
+
+use std::fmt::Write;
+
+use graph::{DiGraph, StaticGraph};
+
+/// Return the DOT representation of an undirected graph. Every
+/// vertex is declared by its index, and every edge is listed once
+/// as `u -- v` with `u <= v`, in lexicographic order.
+///
+/// # Arguments
+///
+/// * `graph` - the graph to serialize;
+/// * `graph_name` - the name of the graph, omitted if empty.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::to_dot;
+/// let graph = StaticGraph::new(3, &[(1, 0), (1, 2)]);
+/// assert_eq!(
+///     to_dot(&graph, "path"),
+///     "graph \"path\" {\n  0;\n  1;\n  2;\n  0 -- 1;\n  1 -- 2;\n}\n"
+/// );
+/// assert_eq!(to_dot(&StaticGraph::new(0, &[]), ""), "graph {}\n");
+/// ```
+pub fn to_dot(graph: &StaticGraph, graph_name: &str) -> String {
+    let vertices: Vec<String> = (0..graph.n_vert()).map(|v| format!("{};", v)).collect();
+    let edges: Vec<String> = sorted_edges(graph)
+        .into_iter()
+        .map(|(u, v)| format!("{} -- {};", u, v))
+        .collect();
+    render("graph", graph_name, &vertices, &edges)
+}
+
+/// Return the DOT representation of an undirected graph with
+/// labeled vertices and, optionally, labeled edges. The edges are
+/// listed as in `to_dot`, and `edge_labels` follow the same order.
+///
+/// # Arguments
+///
+/// * `graph` - the graph to serialize;
+/// * `vertex_labels` - the label of every vertex;
+/// * `edge_labels` - the label of every edge, if any.
+///
+/// # Panics
+///
+/// If the number of labels does not match the number of vertices
+/// or edges.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::to_dot_with_labels;
+/// let graph = StaticGraph::new(2, &[(0, 1)]);
+/// let dot = to_dot_with_labels(&graph, &["a", "b"], Some(&["a-b"]));
+/// assert!(dot.contains("0 [label=\"a\"];"));
+/// assert!(dot.contains("0 -- 1 [label=\"a-b\"];"));
+/// ```
+pub fn to_dot_with_labels(
+    graph: &StaticGraph,
+    vertex_labels: &[&str],
+    edge_labels: Option<&[&str]>,
+) -> String {
+    assert_eq!(
+        vertex_labels.len(),
+        graph.n_vert(),
+        "expected a label for every vertex"
+    );
+    let vertices: Vec<String> = vertex_labels
+        .iter()
+        .enumerate()
+        .map(|(v, label)| format!("{} [label={}];", v, quote(label)))
+        .collect();
+
+    let edges = sorted_edges(graph);
+    let edges: Vec<String> = match edge_labels {
+        None => edges
+            .into_iter()
+            .map(|(u, v)| format!("{} -- {};", u, v))
+            .collect(),
+        Some(labels) => {
+            assert_eq!(labels.len(), edges.len(), "expected a label for every edge");
+            edges
+                .into_iter()
+                .zip(labels.iter())
+                .map(|((u, v), label)| format!("{} -- {} [label={}];", u, v, quote(label)))
+                .collect()
+        }
+    };
+    render("graph", "", &vertices, &edges)
+}
+
+/// Return the DOT representation of a directed graph. Every edge
+/// is listed as `u -> v` in the order of `graph.successors(u)`.
+///
+/// # Examples
+/// ```
+/// use ralgo::DiGraph;
+/// use ralgo::graph::digraph_to_dot;
+/// let graph = DiGraph::new(2, &[(1, 0)]);
+/// assert_eq!(digraph_to_dot(&graph), "digraph {\n  0;\n  1;\n  1 -> 0;\n}\n");
+/// ```
+pub fn digraph_to_dot(graph: &DiGraph) -> String {
+    let vertices: Vec<String> = (0..graph.n_vert()).map(|v| format!("{};", v)).collect();
+    let edges: Vec<String> = (0..graph.n_vert())
+        .flat_map(|u| {
+            graph
+                .successors(u)
+                .iter()
+                .map(move |v| format!("{} -> {};", u, v))
+        })
+        .collect();
+    render("digraph", "", &vertices, &edges)
+}
+
+/// Return every edge of `graph` once as `(u, v)` with `u <= v`
+/// in lexicographic order.
+fn sorted_edges(graph: &StaticGraph) -> Vec<(usize, usize)> {
+    let mut edges = graph.edges();
+    edges.sort();
+    edges
+}
+
+/// Assemble the statements into a DOT graph of given kind.
+fn render(kind: &str, name: &str, vertices: &[String], edges: &[String]) -> String {
+    let mut dot = String::from(kind);
+    if !name.is_empty() {
+        write!(dot, " {}", quote(name)).unwrap();
+    }
+    if vertices.is_empty() {
+        dot.push_str(" {}\n");
+        return dot;
+    }
+    dot.push_str(" {\n");
+    for statement in vertices.iter().chain(edges.iter()) {
+        writeln!(dot, "  {}", statement).unwrap();
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Return `s` as a quoted DOT identifier.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{digraph_to_dot, to_dot, to_dot_with_labels};
+    use graph::{DiGraph, StaticGraph};
+
+    #[test]
+    fn declarations_work() {
+        let graph = StaticGraph::new(4, &[(2, 0), (0, 1), (3, 3), (1, 2), (0, 1)]);
+        let dot = to_dot(&graph, "g");
+        assert!(dot.starts_with("graph \"g\" {\n"));
+        assert!(dot.ends_with("}\n"));
+        for v in 0..4 {
+            assert!(dot.contains(&format!("  {};\n", v)));
+        }
+        assert!(dot.contains("  0 -- 2;\n"));
+        assert!(dot.contains("  3 -- 3;\n"));
+        assert_eq!(dot.matches("0 -- 1;").count(), 2);
+        assert_eq!(dot.matches(" -- ").count(), graph.n_edges());
+    }
+
+    #[test]
+    fn empty_graphs_work() {
+        assert_eq!(to_dot(&StaticGraph::new(0, &[]), ""), "graph {}\n");
+        assert_eq!(to_dot(&StaticGraph::new(0, &[]), "g"), "graph \"g\" {}\n");
+        assert_eq!(digraph_to_dot(&DiGraph::new(0, &[])), "digraph {}\n");
+        assert_eq!(to_dot(&StaticGraph::new(1, &[]), ""), "graph {\n  0;\n}\n");
+    }
+
+    #[test]
+    fn labels_are_quoted() {
+        let graph = StaticGraph::new(3, &[(2, 1), (0, 1)]);
+        let dot = to_dot_with_labels(&graph, &["a", "say \"hi\"", "c\\d"], Some(&["x", "y"]));
+        assert!(dot.contains("  1 [label=\"say \\\"hi\\\"\"];\n"));
+        assert!(dot.contains("  2 [label=\"c\\\\d\"];\n"));
+        assert!(dot.contains("  0 -- 1 [label=\"x\"];\n"));
+        assert!(dot.contains("  1 -- 2 [label=\"y\"];\n"));
+        let dot = to_dot_with_labels(&graph, &["a", "b", "c"], None);
+        assert!(dot.contains("  1 -- 2;\n"));
+    }
+
+    #[test]
+    fn digraph_edges_are_directed() {
+        let graph = DiGraph::new(3, &[(0, 1), (1, 0), (2, 1)]);
+        let dot = digraph_to_dot(&graph);
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains("  0 -> 1;\n"));
+        assert!(dot.contains("  1 -> 0;\n"));
+        assert!(dot.contains("  2 -> 1;\n"));
+        assert_eq!(dot.matches(" -> ").count(), graph.n_edges());
+        assert!(!dot.contains("--"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_labels_panic() {
+        to_dot_with_labels(&StaticGraph::new(2, &[(0, 1)]), &["a", "b"], Some(&[]));
+    }
+}
@@ -0,0 +1,242 @@
+//! This module contains offline dynamic connectivity: answering
+//! connectivity queries on a graph whose edges appear and
+//! disappear over time, with all changes known in advance.
+
+use graph::UnionFindRollback;
+
+/// The set of edges of a graph over discrete time, each edge
+/// being present during a time interval.
+///
+/// Queries are answered with a segment tree over the distinct query
+/// times: every edge is stored in the O(log Q) nodes covering the
+/// query times within its interval, and a
+/// depth-first traversal of the tree unites the edges of a node on
+/// entry and rolls them back on exit, so at every leaf the
+/// union-find holds exactly the edges present at that time.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::DynamicConnectivity;
+/// let mut graph = DynamicConnectivity::new(3);
+/// graph.add_edge(0, 5, 0, 1);
+/// graph.add_edge(2, 4, 1, 2);
+/// assert!(!graph.query(1, 0, 2));
+/// assert!(graph.query(3, 0, 2));
+/// assert_eq!(graph.answer_all(&[(2, 0, 2), (4, 0, 2), (4, 0, 1)]), vec![true, false, true]);
+/// ```
+pub struct DynamicConnectivity {
+    n_vert: usize,
+    edges: Vec<(usize, usize, usize, usize)>,
+}
+
+impl DynamicConnectivity {
+    /// Return a graph with `n_vert` vertices and no edges.
+    pub fn new(n_vert: usize) -> DynamicConnectivity {
+        DynamicConnectivity {
+            n_vert,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Add the edge `(u, v)` present at times `t` with
+    /// `time_start <= t < time_end`.
+    ///
+    /// # Panics
+    ///
+    /// If `time_start > time_end` or a vertex is out of range.
+    ///
+    pub fn add_edge(&mut self, time_start: usize, time_end: usize, u: usize, v: usize) {
+        assert!(time_start <= time_end, "edge ends before it starts");
+        assert!(
+            u < self.n_vert && v < self.n_vert,
+            "edge ({}, {}) is out of range for a graph with {} vertices",
+            u,
+            v,
+            self.n_vert
+        );
+        self.edges.push((time_start, time_end, u, v));
+    }
+
+    /// Return `true` if `u` and `v` are connected at time `time`.
+    /// Every call runs the whole offline algorithm; to answer many
+    /// queries, pass them all to `answer_all` at once.
+    pub fn query(&self, time: usize, u: usize, v: usize) -> bool {
+        self.answer_all(&[(time, u, v)])[0]
+    }
+
+    /// Return for every query `(time, u, v)` whether `u` and `v`
+    /// are connected at time `time`.
+    ///
+    /// Times are compressed to the distinct query times, so the
+    /// magnitude of the times does not matter. Runs in
+    /// O((E log Q + Q) log V + Q log Q).
+    ///
+    /// # Panics
+    ///
+    /// If a vertex is out of range.
+    ///
+    pub fn answer_all(&self, queries: &[(usize, usize, usize)]) -> Vec<bool> {
+        let mut times: Vec<usize> = queries.iter().map(|&(t, _, _)| t).collect();
+        times.sort_unstable();
+        times.dedup();
+        let horizon = times.len();
+        if horizon == 0 {
+            return Vec::new();
+        }
+
+        // An edge present during `[start, end)` covers the query
+        // times with indices in `[rank(start), rank(end))`.
+        let rank = |t: usize| times.partition_point(|&x| x < t);
+        let mut tree = vec![Vec::new(); 4 * horizon];
+        for &(start, end, u, v) in self.edges.iter() {
+            insert(&mut tree, 1, 0, horizon, rank(start), rank(end), (u, v));
+        }
+        let mut at_time = vec![Vec::new(); horizon];
+        for (index, &(time, _, _)) in queries.iter().enumerate() {
+            at_time[rank(time)].push(index);
+        }
+
+        let mut traversal = Traversal {
+            tree: &tree,
+            at_time: &at_time,
+            queries,
+            components: UnionFindRollback::new(self.n_vert),
+            answers: vec![false; queries.len()],
+        };
+        traversal.visit(1, 0, horizon);
+        traversal.answers
+    }
+}
+
+/// Store `edge` in the nodes of the segment tree that cover
+/// `[from, to)`; `node` covers `[lo, hi)`.
+fn insert(
+    tree: &mut [Vec<(usize, usize)>],
+    node: usize,
+    lo: usize,
+    hi: usize,
+    from: usize,
+    to: usize,
+    edge: (usize, usize),
+) {
+    if to <= lo || hi <= from {
+        return;
+    }
+    if from <= lo && hi <= to {
+        tree[node].push(edge);
+        return;
+    }
+    let mid = (lo + hi) / 2;
+    insert(tree, 2 * node, lo, mid, from, to, edge);
+    insert(tree, 2 * node + 1, mid, hi, from, to, edge);
+}
+
+/// The state of the depth-first traversal of the segment tree.
+struct Traversal<'a> {
+    tree: &'a [Vec<(usize, usize)>],
+    at_time: &'a [Vec<usize>],
+    queries: &'a [(usize, usize, usize)],
+    components: UnionFindRollback,
+    answers: Vec<bool>,
+}
+
+impl<'a> Traversal<'a> {
+    /// Answer the queries at the leaves under `node`, which covers `[lo, hi)`.
+    fn visit(&mut self, node: usize, lo: usize, hi: usize) {
+        let snapshot = self.components.snapshot();
+        for &(u, v) in self.tree[node].iter() {
+            self.components.union(u, v);
+        }
+        if hi - lo == 1 {
+            for &index in self.at_time[lo].iter() {
+                let (_, u, v) = self.queries[index];
+                self.answers[index] = self.components.connected(u, v);
+            }
+        } else {
+            let mid = (lo + hi) / 2;
+            self.visit(2 * node, lo, mid);
+            self.visit(2 * node + 1, mid, hi);
+        }
+        self.components.rollback(snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynamicConnectivity;
+    use graph::random::XorShift;
+    use graph::UnionFind;
+
+    #[test]
+    fn added_then_removed_edge_works() {
+        let mut graph = DynamicConnectivity::new(2);
+        graph.add_edge(3, 6, 0, 1);
+        let answers: Vec<bool> = (0..8).map(|t| graph.query(t, 0, 1)).collect();
+        assert_eq!(
+            answers,
+            vec![false, false, false, true, true, true, false, false]
+        );
+        assert!(graph.query(10, 1, 1));
+    }
+
+    #[test]
+    fn simultaneous_edges_work() {
+        let mut graph = DynamicConnectivity::new(4);
+        graph.add_edge(0, 10, 0, 1);
+        graph.add_edge(0, 10, 1, 2);
+        graph.add_edge(0, 5, 2, 3);
+        graph.add_edge(5, 10, 0, 3);
+        graph.add_edge(5, 5, 0, 2);
+        let queries = [(4, 0, 3), (5, 0, 3), (7, 3, 1), (10, 0, 1), (2, 3, 0)];
+        assert_eq!(
+            graph.answer_all(&queries),
+            vec![true, true, true, false, true]
+        );
+        assert_eq!(graph.answer_all(&[]), vec![]);
+    }
+
+    #[test]
+    fn huge_times_work() {
+        let mut graph = DynamicConnectivity::new(3);
+        graph.add_edge(1_000_000_000, usize::MAX, 0, 1);
+        graph.add_edge(0, 2_000_000_000, 1, 2);
+        assert!(graph.query(1_000_000_000, 0, 2));
+        assert!(!graph.query(999_999_999, 0, 1));
+        assert!(graph.query(usize::MAX - 1, 0, 1));
+        assert!(!graph.query(usize::MAX, 0, 1));
+        assert!(!graph.query(usize::MAX, 1, 2));
+    }
+
+    #[test]
+    fn random_schedule_matches_brute_force() {
+        let mut rng = XorShift::new(59);
+        let (n_vert, horizon) = (8, 30);
+        let mut graph = DynamicConnectivity::new(n_vert);
+        let mut edges = Vec::new();
+        for _ in 0..15 {
+            let a = rng.next_below(horizon + 1);
+            let b = rng.next_below(horizon + 1);
+            let (u, v) = (rng.next_below(n_vert), rng.next_below(n_vert));
+            graph.add_edge(a.min(b), a.max(b), u, v);
+            edges.push((a.min(b), a.max(b), u, v));
+        }
+
+        let mut queries = Vec::new();
+        let mut expected = Vec::new();
+        for time in 0..horizon {
+            let mut uf = UnionFind::new(n_vert);
+            for &(start, end, u, v) in edges.iter() {
+                if start <= time && time < end {
+                    uf.union(u, v);
+                }
+            }
+            for u in 0..n_vert {
+                for v in 0..n_vert {
+                    queries.push((time, u, v));
+                    expected.push(uf.connected(u, v));
+                }
+            }
+        }
+        assert_eq!(graph.answer_all(&queries), expected);
+    }
+}
@@ -0,0 +1,164 @@
+//! This module contains distance-based characteristics of
+//! a graph: eccentricity of vertices, diameter, radius and
+//! center. All distances are measured in hops and computed
+//! with breadth-first search.
+
+use std::collections::VecDeque;
+
+use graph::StaticGraph;
+
+/// Return the eccentricity of every vertex, i.e. the largest
+/// distance from that vertex to any other vertex reachable from it.
+/// Vertices in other components are ignored, so in a disconnected
+/// graph every vertex gets the eccentricity within its component.
+/// The eccentricity is `None` if no other vertex is reachable.
+///
+/// Runs a breadth-first search from every vertex in O(V * (V + E)).
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::eccentricity;
+/// let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(
+///     eccentricity(&graph),
+///     vec![Some(3), Some(2), Some(2), Some(3), None]
+/// );
+/// ```
+pub fn eccentricity(graph: &StaticGraph) -> Vec<Option<usize>> {
+    (0..graph.n_vert())
+        .map(|source| {
+            let dist = bfs_distances(graph, source);
+            (0..dist.len())
+                .filter(|&v| v != source)
+                .filter_map(|v| dist[v])
+                .max()
+        })
+        .collect()
+}
+
+/// Return the diameter of the graph, i.e. the largest distance
+/// between two vertices, or `None` if the graph has no vertices
+/// or is disconnected.
+pub fn diameter(graph: &StaticGraph) -> Option<usize> {
+    finite_eccentricity(graph)?.into_iter().max()
+}
+
+/// Return the radius of the graph, i.e. the smallest eccentricity
+/// of its vertices, or `None` if the graph has no vertices
+/// or is disconnected.
+pub fn radius(graph: &StaticGraph) -> Option<usize> {
+    finite_eccentricity(graph)?.into_iter().min()
+}
+
+/// Return the center of the graph, i.e. the vertices whose
+/// eccentricity equals the radius, in increasing order. Empty if
+/// the graph has no vertices or is disconnected.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::center_vertices;
+/// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(center_vertices(&graph), vec![1, 2]);
+/// ```
+pub fn center_vertices(graph: &StaticGraph) -> Vec<usize> {
+    let ecc = match finite_eccentricity(graph) {
+        Some(ecc) => ecc,
+        None => return Vec::new(),
+    };
+    match ecc.iter().min() {
+        Some(&rad) => (0..ecc.len()).filter(|&v| ecc[v] == rad).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Return the eccentricities if the graph is connected, `None` otherwise.
+fn finite_eccentricity(graph: &StaticGraph) -> Option<Vec<usize>> {
+    (0..graph.n_vert())
+        .map(|source| {
+            let dist = bfs_distances(graph, source);
+            dist.iter().try_fold(0, |ecc, d| d.map(|d| ecc.max(d)))
+        })
+        .collect()
+}
+
+/// Return the distance from `source` to every vertex,
+/// or `None` for vertices that are not reachable.
+pub(crate) fn bfs_distances(graph: &StaticGraph, source: usize) -> Vec<Option<usize>> {
+    let mut dist = vec![None; graph.n_vert()];
+    let mut queue = VecDeque::new();
+    dist[source] = Some(0);
+    queue.push_back(source);
+    while let Some(u) = queue.pop_front() {
+        let next = dist[u].map(|d| d + 1);
+        for &v in graph.neighbors(u) {
+            if dist[v].is_none() {
+                dist[v] = next;
+                queue.push_back(v);
+            }
+        }
+    }
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{center_vertices, diameter, eccentricity, radius};
+    use graph::StaticGraph;
+
+    #[test]
+    fn path_works() {
+        for n in 1..8 {
+            let edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+            let path = StaticGraph::new(n, &edges);
+            assert_eq!(diameter(&path), Some(n - 1));
+            assert_eq!(radius(&path), Some(n / 2));
+        }
+    }
+
+    #[test]
+    fn complete_graph_and_star_work() {
+        let mut edges = Vec::new();
+        for u in 0..5 {
+            for v in u + 1..5 {
+                edges.push((u, v));
+            }
+        }
+        let complete = StaticGraph::new(5, &edges);
+        assert_eq!(diameter(&complete), Some(1));
+        assert_eq!(center_vertices(&complete), vec![0, 1, 2, 3, 4]);
+
+        let star = StaticGraph::new(5, &[(3, 0), (3, 1), (3, 2), (3, 4)]);
+        let ecc = eccentricity(&star);
+        assert_eq!(ecc[3], Some(1));
+        assert_eq!(ecc[0], Some(2));
+        assert_eq!(diameter(&star), Some(2));
+        assert_eq!(radius(&star), Some(1));
+        assert_eq!(center_vertices(&star), vec![3]);
+    }
+
+    #[test]
+    fn disconnected_and_empty_graphs_work() {
+        let graph = StaticGraph::new(4, &[(0, 1), (2, 3)]);
+        assert_eq!(eccentricity(&graph), vec![Some(1); 4]);
+        assert_eq!(diameter(&graph), None);
+        assert_eq!(radius(&graph), None);
+        assert_eq!(center_vertices(&graph), vec![]);
+
+        let graph = StaticGraph::new(6, &[(0, 1), (1, 2), (3, 4)]);
+        let ecc = eccentricity(&graph);
+        assert_eq!(ecc, vec![Some(2), Some(1), Some(2), Some(1), Some(1), None]);
+        assert_eq!(diameter(&graph), None);
+
+        let graph = StaticGraph::new(1, &[]);
+        assert_eq!(eccentricity(&graph), vec![None]);
+        assert_eq!(diameter(&graph), Some(0));
+        assert_eq!(center_vertices(&graph), vec![0]);
+
+        let graph = StaticGraph::new(0, &[]);
+        assert_eq!(diameter(&graph), None);
+        assert_eq!(radius(&graph), None);
+        assert_eq!(center_vertices(&graph), vec![]);
+    }
+}
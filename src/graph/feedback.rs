@@ -0,0 +1,205 @@
+//! This module contains a greedy approximation of a minimum
+//! feedback vertex set of an undirected graph.
+
+use graph::{StaticGraph, UnionFind};
+
+/// Return a feedback vertex set of the graph in increasing order:
+/// a set of vertices whose removal leaves a forest. Self-loops and
+/// parallel edges count as cycles.
+///
+/// This is a greedy heuristic, so the set is valid but not always
+/// minimum. While the remaining graph has a cycle, a depth-first
+/// search finds one and its vertex with the largest remaining
+/// degree is removed. Afterwards every chosen vertex that can be
+/// put back without creating a cycle is put back. Takes
+/// O(V (V + E)).
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::{feedback_vertex_set, is_feedback_vertex_set};
+/// // Two triangles sharing vertex 0.
+/// let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (4, 0)]);
+/// let fvs = feedback_vertex_set(&graph);
+/// assert_eq!(fvs, vec![0]);
+/// assert!(is_feedback_vertex_set(&graph, &fvs));
+/// ```
+pub fn feedback_vertex_set(graph: &StaticGraph) -> Vec<usize> {
+    let n_vert = graph.n_vert();
+    let edges = graph.edges();
+    let mut adjacent = vec![Vec::new(); n_vert];
+    for (e, &(u, v)) in edges.iter().enumerate() {
+        adjacent[u].push((v, e));
+        if u != v {
+            adjacent[v].push((u, e));
+        }
+    }
+
+    let mut removed = vec![false; n_vert];
+    let mut chosen = Vec::new();
+    while let Some(cycle) = find_cycle(&adjacent, &removed) {
+        let degree = |v: usize| adjacent[v].iter().filter(|&&(w, _)| !removed[w]).count();
+        let vert = cycle.into_iter().max_by_key(|&v| degree(v)).unwrap();
+        removed[vert] = true;
+        chosen.push(vert);
+    }
+
+    for &vert in chosen.iter().rev() {
+        removed[vert] = false;
+        removed[vert] = !is_forest(n_vert, &edges, &removed);
+    }
+    (0..n_vert).filter(|&v| removed[v]).collect()
+}
+
+/// Return `true` if removing the vertices `fvs` from the graph
+/// leaves no cycles, `false` otherwise.
+///
+/// # Panics
+///
+/// If `fvs` contains a vertex >= `graph.n_vert()`.
+///
+pub fn is_feedback_vertex_set(graph: &StaticGraph, fvs: &[usize]) -> bool {
+    let mut removed = vec![false; graph.n_vert()];
+    for &v in fvs {
+        assert!(v < graph.n_vert(), "vertex {} is out of range", v);
+        removed[v] = true;
+    }
+    is_forest(graph.n_vert(), &graph.edges(), &removed)
+}
+
+/// Return `true` if the edges between vertices that are not
+/// removed form no cycle.
+fn is_forest(n_vert: usize, edges: &[(usize, usize)], removed: &[bool]) -> bool {
+    let mut components = UnionFind::new(n_vert);
+    for &(u, v) in edges {
+        if !removed[u] && !removed[v] {
+            if components.connected(u, v) {
+                return false;
+            }
+            components.union(u, v);
+        }
+    }
+    true
+}
+
+/// Return the vertices of some cycle among the vertices that are
+/// not removed, or `None` if there is none. `adjacent[v]` lists the
+/// pairs `(neighbor, edge id)`, so parallel edges are told apart.
+fn find_cycle(adjacent: &[Vec<(usize, usize)>], removed: &[bool]) -> Option<Vec<usize>> {
+    let n_vert = adjacent.len();
+    let mut visited = vec![false; n_vert];
+    // Position of a vertex on the current DFS path, if it is on it.
+    let mut depth: Vec<Option<usize>> = vec![None; n_vert];
+    let mut parent_edge = vec![usize::MAX; n_vert];
+    for start in 0..n_vert {
+        if removed[start] || visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        depth[start] = Some(0);
+        let mut path = vec![(start, 0)];
+        while let Some(&mut (u, ref mut next)) = path.last_mut() {
+            if let Some(&(w, e)) = adjacent[u].get(*next) {
+                *next += 1;
+                if removed[w] || e == parent_edge[u] {
+                    continue;
+                }
+                if let Some(d) = depth[w] {
+                    return Some(path[d..].iter().map(|&(v, _)| v).collect());
+                }
+                if !visited[w] {
+                    visited[w] = true;
+                    depth[w] = Some(path.len());
+                    parent_edge[w] = e;
+                    path.push((w, 0));
+                }
+                continue;
+            }
+            depth[u] = None;
+            path.pop();
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{feedback_vertex_set, is_feedback_vertex_set};
+    use graph::random::XorShift;
+    use graph::StaticGraph;
+
+    #[test]
+    fn single_cycle_needs_one_vertex() {
+        for n in 3..10 {
+            let edges: Vec<(usize, usize)> = (0..n).map(|v| (v, (v + 1) % n)).collect();
+            let graph = StaticGraph::new(n, &edges);
+            let fvs = feedback_vertex_set(&graph);
+            assert_eq!(fvs.len(), 1);
+            assert!(is_feedback_vertex_set(&graph, &fvs));
+        }
+        let graph = StaticGraph::new(3, &[(0, 1), (1, 1)]);
+        assert_eq!(feedback_vertex_set(&graph), vec![1]);
+        let graph = StaticGraph::new(3, &[(0, 1), (1, 2), (1, 2)]);
+        assert_eq!(feedback_vertex_set(&graph).len(), 1);
+    }
+
+    #[test]
+    fn independent_cycles_need_one_vertex_each() {
+        let graph = StaticGraph::new(
+            8,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 0),
+                (3, 4),
+                (4, 5),
+                (5, 6),
+                (6, 3),
+                (2, 7),
+            ],
+        );
+        let fvs = feedback_vertex_set(&graph);
+        assert_eq!(fvs.len(), 2);
+        assert!(fvs[0] < 3 && (3..7).contains(&fvs[1]));
+        assert!(is_feedback_vertex_set(&graph, &fvs));
+    }
+
+    #[test]
+    fn forest_needs_nothing() {
+        let graph = StaticGraph::new(7, &[(0, 1), (0, 2), (1, 3), (1, 4), (5, 6)]);
+        assert_eq!(feedback_vertex_set(&graph), vec![]);
+        assert!(is_feedback_vertex_set(&graph, &[]));
+        assert_eq!(feedback_vertex_set(&StaticGraph::new(0, &[])), vec![]);
+    }
+
+    #[test]
+    fn validation_works() {
+        // K4 needs two vertices removed.
+        let graph = StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        assert!(!is_feedback_vertex_set(&graph, &[]));
+        assert!(!is_feedback_vertex_set(&graph, &[3]));
+        assert!(is_feedback_vertex_set(&graph, &[1, 3]));
+        assert!(is_feedback_vertex_set(&graph, &[0, 1, 2, 3]));
+        assert_eq!(feedback_vertex_set(&graph).len(), 2);
+    }
+
+    #[test]
+    fn random_graphs_get_valid_sets() {
+        let mut rng = XorShift::new(173);
+        for _ in 0..100 {
+            let n = 1 + rng.next_below(15);
+            let edges: Vec<(usize, usize)> = (0..rng.next_below(25))
+                .map(|_| (rng.next_below(n), rng.next_below(n)))
+                .collect();
+            let graph = StaticGraph::new(n, &edges);
+            let fvs = feedback_vertex_set(&graph);
+            assert!(is_feedback_vertex_set(&graph, &fvs));
+            // No chosen vertex is redundant.
+            for i in 0..fvs.len() {
+                let mut smaller = fvs.clone();
+                smaller.remove(i);
+                assert!(!is_feedback_vertex_set(&graph, &smaller));
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! This module contains the computation of graph girth,
+//! i.e. the length of the shortest cycle in the graph.
+
+use std::collections::VecDeque;
+
+use graph::StaticGraph;
+
+/// Return the length of the shortest cycle in the graph,
+/// or `None` if the graph is a forest. A self-loop is a cycle
+/// of length 1 and a pair of parallel edges is a cycle of length 2.
+///
+/// Runs a breadth-first search from every vertex, which takes
+/// O(V * (V + E)) in the worst case. A search is cut short as soon
+/// as it gets deep enough that it can not improve the best cycle.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::girth;
+/// let square = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert_eq!(girth(&square), Some(4));
+/// let path = StaticGraph::new(3, &[(0, 1), (1, 2)]);
+/// assert_eq!(girth(&path), None);
+/// ```
+pub fn girth(graph: &StaticGraph) -> Option<usize> {
+    let n_vert = graph.n_vert();
+    let mut best = usize::MAX;
+    let mut dist = vec![usize::MAX; n_vert];
+    let mut parent = vec![usize::MAX; n_vert];
+    let mut visited = Vec::new();
+    let mut queue = VecDeque::new();
+
+    for source in 0..n_vert {
+        dist[source] = 0;
+        visited.push(source);
+        queue.push_back(source);
+
+        'bfs: while let Some(u) = queue.pop_front() {
+            // Every cycle found from here on is at least this long.
+            if 2 * dist[u] + 1 >= best {
+                break;
+            }
+            let mut parent_skipped = false;
+            for &w in graph.neighbors(u) {
+                if w == parent[u] && !parent_skipped {
+                    parent_skipped = true;
+                } else if dist[w] == usize::MAX {
+                    dist[w] = dist[u] + 1;
+                    parent[w] = u;
+                    visited.push(w);
+                    queue.push_back(w);
+                } else {
+                    best = best.min(dist[u] + dist[w] + 1);
+                    if 2 * dist[u] + 1 >= best {
+                        break 'bfs;
+                    }
+                }
+            }
+        }
+
+        for v in visited.drain(..) {
+            dist[v] = usize::MAX;
+            parent[v] = usize::MAX;
+        }
+        queue.clear();
+    }
+
+    if best == usize::MAX {
+        None
+    } else {
+        Some(best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::girth;
+    use graph::StaticGraph;
+
+    fn cycle(n_vert: usize) -> StaticGraph {
+        let edges: Vec<(usize, usize)> = (0..n_vert).map(|v| (v, (v + 1) % n_vert)).collect();
+        StaticGraph::new(n_vert, &edges)
+    }
+
+    #[test]
+    fn cycles_work() {
+        assert_eq!(girth(&cycle(3)), Some(3));
+        assert_eq!(girth(&cycle(4)), Some(4));
+        assert_eq!(girth(&cycle(9)), Some(9));
+
+        let mut edges = Vec::new();
+        for u in 0..5 {
+            for v in u + 1..5 {
+                edges.push((u, v));
+            }
+        }
+        assert_eq!(girth(&StaticGraph::new(5, &edges)), Some(3));
+    }
+
+    #[test]
+    fn forests_have_no_girth() {
+        assert_eq!(girth(&StaticGraph::new(0, &[])), None);
+        assert_eq!(girth(&StaticGraph::new(4, &[])), None);
+        assert_eq!(girth(&StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3)])), None);
+        let tree = StaticGraph::new(7, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (2, 6)]);
+        assert_eq!(girth(&tree), None);
+    }
+
+    #[test]
+    fn petersen_graph_has_girth_five() {
+        let mut edges = Vec::new();
+        for v in 0..5 {
+            edges.push((v, (v + 1) % 5));
+            edges.push((v, v + 5));
+            edges.push((v + 5, (v + 2) % 5 + 5));
+        }
+        let petersen = StaticGraph::new(10, &edges);
+        assert_eq!(girth(&petersen), Some(5));
+    }
+
+    #[test]
+    fn loops_and_parallel_edges_work() {
+        assert_eq!(
+            girth(&StaticGraph::new(3, &[(0, 1), (1, 2), (1, 2)])),
+            Some(2)
+        );
+        assert_eq!(girth(&StaticGraph::new(3, &[(0, 1), (2, 2)])), Some(1));
+    }
+}
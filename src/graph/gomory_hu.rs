@@ -0,0 +1,178 @@
+//! This module contains the Gomory–Hu tree, which answers
+//! maximum flow queries between all pairs of vertices of
+//! an undirected graph.
+
+use graph::{MaxFlow, WeightedGraph};
+
+/// A tree on the vertices of an undirected graph such that the
+/// maximum flow between any two vertices equals the minimum edge
+/// weight on the tree path between them. Edge weights of the graph
+/// are treated as capacities.
+///
+/// Built with Gusfield's algorithm using `n - 1` maximum flow
+/// computations.
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedGraph;
+/// use ralgo::graph::GomoryHuTree;
+/// let graph = WeightedGraph::new(4, &[(0, 1, 3), (1, 2, 1), (2, 3, 4), (3, 0, 2)]);
+/// let tree = GomoryHuTree::new(&graph);
+/// assert_eq!(tree.max_flow(0, 1), 4);
+/// assert_eq!(tree.max_flow(0, 2), 3);
+/// ```
+pub struct GomoryHuTree {
+    parent: Vec<usize>,
+    weight: Vec<i64>,
+    depth: Vec<usize>,
+}
+
+impl GomoryHuTree {
+    /// Return the Gomory–Hu tree of given graph. Vertex 0 becomes
+    /// the root.
+    ///
+    /// # Panics
+    ///
+    /// If the graph has an edge of negative weight.
+    ///
+    pub fn new(graph: &WeightedGraph) -> GomoryHuTree {
+        let n_vert = graph.n_vert();
+        let mut network = MaxFlow::new(n_vert);
+        for &(u, v, w) in graph.edges() {
+            if u != v {
+                network.add_undirected_edge(u, v, w);
+            }
+        }
+
+        let mut parent = vec![0; n_vert];
+        let mut weight = vec![0; n_vert];
+        for s in 1..n_vert {
+            let t = parent[s];
+            weight[s] = network.max_flow(s, t);
+            let cut = network.min_cut();
+            for v in s + 1..n_vert {
+                if cut[v] && parent[v] == t {
+                    parent[v] = s;
+                }
+            }
+        }
+
+        // Parents always precede their children.
+        let mut depth = vec![0; n_vert];
+        for v in 1..n_vert {
+            depth[v] = depth[parent[v]] + 1;
+        }
+        GomoryHuTree {
+            parent,
+            weight,
+            depth,
+        }
+    }
+
+    /// Return the value of the maximum flow between `u` and `v`,
+    /// or `i64::MAX` if `u == v`. Takes time proportional to the
+    /// length of the tree path.
+    ///
+    /// # Panics
+    ///
+    /// If `u` or `v` is out of range.
+    ///
+    pub fn max_flow(&self, mut u: usize, mut v: usize) -> i64 {
+        let mut result = i64::MAX;
+        while u != v {
+            if self.depth[u] < self.depth[v] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            result = result.min(self.weight[u]);
+            u = self.parent[u];
+        }
+        result
+    }
+
+    /// Return the edges of the tree as triples
+    /// `(vertex, parent, max_flow)`.
+    pub fn edges(&self) -> Vec<(usize, usize, i64)> {
+        (1..self.parent.len())
+            .map(|v| (v, self.parent[v], self.weight[v]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GomoryHuTree;
+    use graph::random::XorShift;
+    use graph::{is_spanning_tree, MaxFlow, StaticGraph, WeightedGraph};
+
+    fn complete_graph(n_vert: usize) -> WeightedGraph {
+        let mut edges = Vec::new();
+        for u in 0..n_vert {
+            for v in u + 1..n_vert {
+                edges.push((u, v, 1));
+            }
+        }
+        WeightedGraph::new(n_vert, &edges)
+    }
+
+    #[test]
+    fn complete_graph_works() {
+        let tree = GomoryHuTree::new(&complete_graph(4));
+        for u in 0..4 {
+            for v in 0..4 {
+                if u != v {
+                    assert_eq!(tree.max_flow(u, v), 3);
+                }
+            }
+        }
+        let edges: Vec<(usize, usize)> = tree.edges().iter().map(|e| (e.0, e.1)).collect();
+        assert!(is_spanning_tree(&StaticGraph::new(4, &edges), &edges));
+        assert_eq!(edges.len(), 3);
+    }
+
+    #[test]
+    fn two_halves_work() {
+        // Two triangles of capacity 5 joined by a bridge of capacity 2.
+        let graph = WeightedGraph::new(
+            6,
+            &[
+                (0, 1, 5),
+                (1, 2, 5),
+                (2, 0, 5),
+                (3, 4, 5),
+                (4, 5, 5),
+                (5, 3, 5),
+                (2, 3, 2),
+            ],
+        );
+        let tree = GomoryHuTree::new(&graph);
+        assert_eq!(tree.max_flow(0, 1), 10);
+        assert_eq!(tree.max_flow(4, 5), 10);
+        assert_eq!(tree.max_flow(0, 5), 2);
+        assert_eq!(tree.max_flow(2, 3), 2);
+        assert_eq!(tree.edges().iter().filter(|e| e.2 == 2).count(), 1);
+    }
+
+    #[test]
+    fn random_graphs_match_max_flow() {
+        let mut rng = XorShift::new(71);
+        for _ in 0..30 {
+            let n_vert = 1 + rng.next_below(8);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.next_below(20))
+                .map(|_| {
+                    let c = rng.next_below(10) as i64;
+                    (rng.next_below(n_vert), rng.next_below(n_vert), c)
+                })
+                .collect();
+            let tree = GomoryHuTree::new(&WeightedGraph::new(n_vert, &edges));
+            let mut network = MaxFlow::new(n_vert);
+            for &(u, v, c) in edges.iter() {
+                network.add_undirected_edge(u, v, c);
+            }
+            for u in 0..n_vert {
+                for v in u + 1..n_vert {
+                    assert_eq!(tree.max_flow(u, v), network.max_flow(u, v));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! This module defines the heavy-light decomposition of a rooted
+//! tree, which splits any path into O(log n) contiguous ranges of
+//! a vertex ordering.
+
+use graph::TreeIndexed;
+
+/// The heavy-light decomposition of a rooted tree. Every vertex
+/// continues the chain of its parent if it has the largest subtree
+/// among its siblings, and starts a new chain otherwise. Vertices
+/// are numbered so that every chain is a contiguous range of
+/// positions, and any path crosses O(log n) chains.
+///
+/// Values attached to vertices can be stored in an array indexed
+/// by `position`; a value attached to an edge is stored at the
+/// position of its lower endpoint.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{Hld, TreeIndexed};
+/// let tree = TreeIndexed::new(5, 0, &[(0, 1), (1, 2), (0, 3), (3, 4)]);
+/// let hld = Hld::new(&tree);
+/// assert_eq!(hld.lca(2, 4), 0);
+/// let len: usize = hld.path_ranges(2, 4, true).iter().map(|&(lo, hi)| hi - lo + 1).sum();
+/// assert_eq!(len, 5);
+/// ```
+pub struct Hld {
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    head: Vec<usize>,
+    pos: Vec<usize>,
+}
+
+impl Hld {
+    /// Return the decomposition of `tree` in O(n).
+    pub fn new(tree: &TreeIndexed) -> Hld {
+        let n = tree.n_vert();
+        let preorder = tree.preorder();
+        let mut size = vec![1; n];
+        for &v in preorder.iter().rev() {
+            if let Some(p) = tree.parent(v) {
+                size[p] += size[v];
+            }
+        }
+
+        // Walk every chain from its head, visiting the heavy child
+        // first and queueing the light children as new heads.
+        let mut head = vec![0; n];
+        let mut pos = vec![0; n];
+        let mut next_pos = 0;
+        let mut heads = vec![tree.root()];
+        while let Some(start) = heads.pop() {
+            let mut v = start;
+            loop {
+                head[v] = start;
+                pos[v] = next_pos;
+                next_pos += 1;
+                let heavy = tree.children(v).iter().cloned().max_by_key(|&c| size[c]);
+                for &c in tree.children(v).iter().rev() {
+                    if Some(c) != heavy {
+                        heads.push(c);
+                    }
+                }
+                match heavy {
+                    Some(c) => v = c,
+                    None => break,
+                }
+            }
+        }
+
+        Hld {
+            parent: (0..n).map(|v| tree.parent(v)).collect(),
+            depth: (0..n).map(|v| tree.depth(v)).collect(),
+            head,
+            pos,
+        }
+    }
+
+    /// Return the position of `vert` in the chain ordering.
+    pub fn position(&self, vert: usize) -> usize {
+        self.pos[vert]
+    }
+
+    /// Return the lowest common ancestor of `u` and `v` in O(log n).
+    pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            u = self.parent[self.head[u]].unwrap();
+        }
+        if self.depth[u] < self.depth[v] {
+            u
+        } else {
+            v
+        }
+    }
+
+    /// Return the O(log n) inclusive ranges `[lo, hi]` of positions
+    /// that together cover the path between `u` and `v`. The lowest
+    /// common ancestor is left out unless `include_lca` is set, so
+    /// that the ranges cover exactly the edges of the path.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - one end of the path;
+    /// * `v` - the other end;
+    /// * `include_lca` - whether to cover the topmost vertex.
+    ///
+    pub fn path_ranges(
+        &self,
+        mut u: usize,
+        mut v: usize,
+        include_lca: bool,
+    ) -> Vec<(usize, usize)> {
+        let mut ranges = Vec::new();
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            ranges.push((self.pos[self.head[u]], self.pos[u]));
+            u = self.parent[self.head[u]].unwrap();
+        }
+        if self.depth[u] > self.depth[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+        let lo = if include_lca {
+            self.pos[u]
+        } else {
+            self.pos[u] + 1
+        };
+        if lo <= self.pos[v] {
+            ranges.push((lo, self.pos[v]));
+        }
+        ranges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hld;
+    use graph::random::XorShift;
+    use graph::TreeIndexed;
+
+    fn random_tree(n: usize, rng: &mut XorShift) -> TreeIndexed {
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+        TreeIndexed::new(n, 0, &edges)
+    }
+
+    fn naive_path(tree: &TreeIndexed, mut u: usize, mut v: usize) -> Vec<usize> {
+        let mut path = Vec::new();
+        while u != v {
+            if tree.depth(u) < tree.depth(v) {
+                std::mem::swap(&mut u, &mut v);
+            }
+            path.push(u);
+            u = tree.parent(u).unwrap();
+        }
+        path.push(u);
+        path.sort_unstable();
+        path
+    }
+
+    #[test]
+    fn ranges_cover_paths() {
+        let mut rng = XorShift::new(23);
+        for _ in 0..20 {
+            let n = 1 + rng.next_below(40);
+            let tree = random_tree(n, &mut rng);
+            let hld = Hld::new(&tree);
+            let mut at = vec![0; n];
+            for v in 0..n {
+                at[hld.position(v)] = v;
+            }
+            for _ in 0..20 {
+                let (u, v) = (rng.next_below(n), rng.next_below(n));
+                let expected = naive_path(&tree, u, v);
+                let lca = *expected.iter().min_by_key(|&&x| tree.depth(x)).unwrap();
+                assert_eq!(hld.lca(u, v), lca);
+
+                let mut covered: Vec<usize> = hld
+                    .path_ranges(u, v, true)
+                    .iter()
+                    .flat_map(|&(lo, hi)| (lo..=hi).map(|p| at[p]))
+                    .collect();
+                covered.sort_unstable();
+                assert_eq!(covered, expected);
+
+                let edges = hld.path_ranges(u, v, false);
+                let len: usize = edges.iter().map(|&(lo, hi)| hi + 1 - lo).sum();
+                assert_eq!(len, expected.len() - 1);
+                assert!(edges
+                    .iter()
+                    .all(|&(lo, hi)| !(lo..=hi).contains(&hld.position(lca))));
+            }
+        }
+    }
+
+    #[test]
+    fn chains_are_few() {
+        // On a path every vertex is in the chain of the root.
+        let edges: Vec<(usize, usize)> = (1..100).map(|v| (v - 1, v)).collect();
+        let hld = Hld::new(&TreeIndexed::new(100, 0, &edges));
+        assert_eq!(hld.path_ranges(0, 99, true), vec![(0, 99)]);
+        assert_eq!(hld.path_ranges(42, 42, false), vec![]);
+
+        let mut rng = XorShift::new(5);
+        let tree = random_tree(1000, &mut rng);
+        let hld = Hld::new(&tree);
+        for v in 0..1000 {
+            assert!(hld.path_ranges(0, v, true).len() <= 11);
+        }
+    }
+}
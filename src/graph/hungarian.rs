@@ -0,0 +1,174 @@
+//! This module contains the Hungarian (Kuhn–Munkres) algorithm
+//! for the minimum cost assignment problem.
+
+/// Return the minimum total cost of assigning every row (worker) of
+/// `cost_matrix` to a distinct column (job) together with the
+/// assignment: row `i` gets column `assignment[i]`.
+///
+/// If there are more rows than columns, the matrix is padded with
+/// zero-cost columns and the rows left without a real column get
+/// `usize::MAX`. If there are more columns than rows, some columns
+/// stay unassigned. Runs in O(n² · m) for n rows and m columns
+/// using vertex potentials.
+///
+/// # Panics
+///
+/// If the rows of `cost_matrix` have different lengths.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::hungarian;
+/// let cost = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+/// assert_eq!(hungarian(&cost), (5, vec![1, 0, 2]));
+/// ```
+pub fn hungarian(cost_matrix: &[Vec<i64>]) -> (i64, Vec<usize>) {
+    let n_rows = cost_matrix.len();
+    let n_cols = cost_matrix.first().map_or(0, |row| row.len());
+    assert!(
+        cost_matrix.iter().all(|row| row.len() == n_cols),
+        "rows of the cost matrix must have equal lengths"
+    );
+    let m = n_cols.max(n_rows);
+    let cost = |i: usize, j: usize| if j < n_cols { cost_matrix[i][j] } else { 0 };
+
+    // Rows and columns are numbered from 1; column 0 is a fictitious
+    // column holding the row being inserted.
+    let mut row_pot = vec![0; n_rows + 1];
+    let mut col_pot = vec![0; m + 1];
+    let mut owner = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+    for i in 1..=n_rows {
+        owner[0] = i;
+        let mut j0 = 0;
+        let mut min_slack = vec![i64::MAX; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = owner[j0];
+            let mut delta = i64::MAX;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let slack = cost(i0 - 1, j - 1) - row_pot[i0] - col_pot[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = j0;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    row_pot[owner[j]] += delta;
+                    col_pot[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if owner[j0] == 0 {
+                break;
+            }
+        }
+        while j0 != 0 {
+            let j1 = way[j0];
+            owner[j0] = owner[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assignment = vec![usize::MAX; n_rows];
+    let mut total = 0;
+    for j in 1..=n_cols {
+        if owner[j] != 0 {
+            assignment[owner[j] - 1] = j - 1;
+            total += cost_matrix[owner[j] - 1][j - 1];
+        }
+    }
+    (total, assignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hungarian;
+    use graph::random::XorShift;
+
+    /// The minimum cost over all injective assignments of rows to
+    /// columns, by trying every one of them.
+    fn brute_force(cost: &[Vec<i64>], row: usize, used: &mut Vec<bool>) -> i64 {
+        if row == cost.len() {
+            return 0;
+        }
+        let mut best = i64::MAX;
+        for j in 0..used.len() {
+            if !used[j] {
+                used[j] = true;
+                best = best.min(cost[row][j] + brute_force(cost, row + 1, used));
+                used[j] = false;
+            }
+        }
+        best
+    }
+
+    fn check(cost: &[Vec<i64>]) {
+        let (total, assignment) = hungarian(cost);
+        let mut seen = vec![false; cost[0].len()];
+        let mut sum = 0;
+        for (i, &j) in assignment.iter().enumerate() {
+            assert!(!seen[j]);
+            seen[j] = true;
+            sum += cost[i][j];
+        }
+        assert_eq!(sum, total);
+        assert_eq!(total, brute_force(cost, 0, &mut vec![false; cost[0].len()]));
+    }
+
+    #[test]
+    fn small_matrices_work() {
+        assert_eq!(hungarian(&[vec![7]]), (7, vec![0]));
+        assert_eq!(hungarian(&[]), (0, vec![]));
+        let cost = vec![
+            vec![9, 2, 7, 8],
+            vec![6, 4, 3, 7],
+            vec![5, 8, 1, 8],
+            vec![7, 6, 9, 4],
+        ];
+        assert_eq!(hungarian(&cost), (13, vec![1, 0, 2, 3]));
+    }
+
+    #[test]
+    fn equal_costs_give_perfect_matching() {
+        let (total, mut assignment) = hungarian(&vec![vec![3; 5]; 5]);
+        assert_eq!(total, 15);
+        assignment.sort();
+        assert_eq!(assignment, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn greedy_is_not_optimal() {
+        // Greedily taking the cheapest cell 1 forces a cost of 100.
+        let cost = vec![vec![1, 2], vec![2, 100]];
+        assert_eq!(hungarian(&cost), (4, vec![1, 0]));
+    }
+
+    #[test]
+    fn rectangular_matrices_work() {
+        let (total, assignment) = hungarian(&[vec![5], vec![2], vec![4]]);
+        assert_eq!(total, 2);
+        assert_eq!(assignment, vec![usize::MAX, 0, usize::MAX]);
+
+        let mut rng = XorShift::new(23);
+        for _ in 0..100 {
+            let n_rows = 1 + rng.next_below(5);
+            let n_cols = n_rows + rng.next_below(3);
+            let cost: Vec<Vec<i64>> = (0..n_rows)
+                .map(|_| (0..n_cols).map(|_| rng.next_below(20) as i64 - 5).collect())
+                .collect();
+            check(&cost);
+        }
+    }
+}
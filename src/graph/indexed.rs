@@ -0,0 +1,154 @@
+//! This module defines a static undirected graph whose vertices
+//! are indexed with integers 0, 1, ..., N-1, interchangeable with
+//! `StaticGraph`.
+
+use graph::StaticGraph;
+
+/// The integer-indexed static graph with the same adjacency layout
+/// as `StaticGraph`. The two types convert into each other without
+/// copying, so a `GraphIndexed` can be passed to any algorithm that
+/// accepts a `StaticGraph`.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::{diameter, GraphIndexed};
+/// let graph = GraphIndexed::new(3, &[(0, 1), (1, 2)]);
+/// assert_eq!(graph.neighbors(1), &[0, 2]);
+/// let graph = StaticGraph::from(graph);
+/// assert_eq!(diameter(&graph), Some(2));
+/// ```
+#[derive(Clone)]
+pub struct GraphIndexed {
+    graph: StaticGraph,
+}
+
+impl GraphIndexed {
+    /// Return a new instance of GraphIndexed. A self-loop `(v, v)`
+    /// puts `v` into its own neighbor list twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices;
+    /// * `edges` - pairs of adjacent vertices.
+    ///
+    /// # Panics
+    ///
+    /// If `edges` contains an element >= `n_vert`.
+    ///
+    pub fn new(n_vert: usize, edges: &[(usize, usize)]) -> GraphIndexed {
+        GraphIndexed {
+            graph: StaticGraph::new(n_vert, edges),
+        }
+    }
+
+    /// Return a copy of `graph` as a GraphIndexed.
+    pub fn from_static_graph(graph: &StaticGraph) -> GraphIndexed {
+        GraphIndexed {
+            graph: graph.clone(),
+        }
+    }
+
+    /// Return the number of vertices in given Graph instance.
+    pub fn n_vert(&self) -> usize {
+        self.graph.n_vert()
+    }
+
+    /// Return `true` if given Graph instance has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.n_vert() == 0
+    }
+
+    /// Return the number of edges in given Graph instance.
+    pub fn n_edges(&self) -> usize {
+        self.graph.n_edges()
+    }
+
+    /// Return the number of neighbors of `vert`; a self-loop
+    /// counts twice.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` >= `self.n_vert()`.
+    ///
+    pub fn degree(&self, vert: usize) -> usize {
+        self.neighbors(vert).len()
+    }
+
+    /// Return the slice of neighboring vertices to the given vertex.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` >= `self.n_vert()`.
+    ///
+    pub fn neighbors(&self, vert: usize) -> &[usize] {
+        self.graph.neighbors(vert)
+    }
+}
+
+impl From<StaticGraph> for GraphIndexed {
+    fn from(graph: StaticGraph) -> GraphIndexed {
+        GraphIndexed { graph }
+    }
+}
+
+impl From<GraphIndexed> for StaticGraph {
+    fn from(graph: GraphIndexed) -> StaticGraph {
+        graph.graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphIndexed;
+    use graph::random::XorShift;
+    use graph::{eccentricity, StaticGraph};
+
+    fn sorted(neighbors: &[usize]) -> Vec<usize> {
+        let mut neighbors = neighbors.to_vec();
+        neighbors.sort_unstable();
+        neighbors
+    }
+
+    #[test]
+    fn round_trip_keeps_neighbors() {
+        let mut rng = XorShift::new(71);
+        for _ in 0..20 {
+            let n_vert = 1 + rng.next_below(10);
+            let edges: Vec<(usize, usize)> = (0..rng.next_below(20))
+                .map(|_| (rng.next_below(n_vert), rng.next_below(n_vert)))
+                .collect();
+            let graph = StaticGraph::new(n_vert, &edges);
+            let back = StaticGraph::from(GraphIndexed::from(StaticGraph::new(n_vert, &edges)));
+            let by_ref = StaticGraph::from_graph_indexed(&GraphIndexed::from_static_graph(&graph));
+            for v in 0..n_vert {
+                assert_eq!(sorted(back.neighbors(v)), sorted(graph.neighbors(v)));
+                assert_eq!(by_ref.neighbors(v), graph.neighbors(v));
+            }
+            assert_eq!(back.n_edges(), graph.n_edges());
+        }
+    }
+
+    #[test]
+    fn sizes_and_degrees_work() {
+        let graph = GraphIndexed::new(4, &[(0, 1), (0, 2), (2, 2)]);
+        assert_eq!(graph.n_edges(), 3);
+        assert_eq!(graph.degree(0), 2);
+        assert_eq!(graph.degree(2), 3);
+        assert_eq!(graph.degree(3), 0);
+        assert!(!graph.is_empty());
+        let empty = GraphIndexed::new(0, &[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.n_edges(), 0);
+    }
+
+    #[test]
+    fn static_graph_algorithms_accept_converted_graphs() {
+        let graph = GraphIndexed::new(4, &[(0, 1), (1, 2), (2, 3)]);
+        let graph: StaticGraph = graph.into();
+        assert_eq!(
+            eccentricity(&graph),
+            vec![Some(3), Some(2), Some(2), Some(3)]
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! This module contains reading and writing of undirected graphs
+//! in the edge list text format: a header line `n_vert n_edges`
+//! followed by one line `u v` per edge.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use graph::StaticGraph;
+
+/// The reason an edge list could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Reading the input failed.
+    Io(io::ErrorKind),
+    /// A line does not consist of exactly two numbers.
+    WrongTokenCount,
+    /// A token is not a non-negative integer.
+    InvalidNumber,
+    /// An edge refers to a vertex >= `n_vert`.
+    VertexOutOfRange,
+    /// The number of edge lines differs from the header.
+    EdgeCountMismatch,
+}
+
+/// The error returned when parsing an edge list fails. Lines are
+/// numbered from 1; blank lines are skipped but counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self.kind {
+            ParseErrorKind::Io(kind) => return write!(f, "line {}: {:?}", self.line, kind),
+            ParseErrorKind::WrongTokenCount => "expected two numbers",
+            ParseErrorKind::InvalidNumber => "invalid number",
+            ParseErrorKind::VertexOutOfRange => "vertex is out of range",
+            ParseErrorKind::EdgeCountMismatch => "number of edges differs from the header",
+        };
+        write!(f, "line {}: {}", self.line, reason)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse a graph from a string in the edge list format.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::from_edge_list_str;
+/// let graph = from_edge_list_str("3 2\n0 1\n2 1\n").unwrap();
+/// assert_eq!(graph.n_vert(), 3);
+/// assert_eq!(graph.neighbors(1), &[0, 2]);
+/// assert_eq!(from_edge_list_str("3 1\n0 3\n").err().unwrap().line, 2);
+/// ```
+pub fn from_edge_list_str(s: &str) -> Result<StaticGraph, ParseError> {
+    from_edge_list_reader(s.as_bytes())
+}
+
+/// Parse a graph from a stream in the edge list format, reading
+/// it line by line.
+///
+/// # Arguments
+///
+/// * `reader` - the source of the text.
+///
+pub fn from_edge_list_reader(reader: impl BufRead) -> Result<StaticGraph, ParseError> {
+    let mut header = None;
+    let mut edges = Vec::new();
+    let mut line_no = 0;
+    for line in reader.lines() {
+        line_no += 1;
+        let error = |kind| ParseError {
+            line: line_no,
+            kind,
+        };
+        let line = line.map_err(|e| error(ParseErrorKind::Io(e.kind())))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (a, b) = parse_pair(&line).map_err(error)?;
+        match header {
+            None => header = Some((a, b)),
+            Some((n_vert, n_edges)) => {
+                if edges.len() == n_edges {
+                    return Err(error(ParseErrorKind::EdgeCountMismatch));
+                }
+                if a >= n_vert || b >= n_vert {
+                    return Err(error(ParseErrorKind::VertexOutOfRange));
+                }
+                edges.push((a, b));
+            }
+        }
+    }
+
+    let error = |kind| ParseError {
+        line: line_no.max(1),
+        kind,
+    };
+    match header {
+        None => Err(error(ParseErrorKind::WrongTokenCount)),
+        Some((_, n_edges)) if n_edges != edges.len() => {
+            Err(error(ParseErrorKind::EdgeCountMismatch))
+        }
+        Some((n_vert, _)) => Ok(StaticGraph::new(n_vert, &edges)),
+    }
+}
+
+/// Return the edge list representation of `graph`. Every edge is
+/// written once as `u v` with `u <= v`.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::to_edge_list_str;
+/// let graph = StaticGraph::new(3, &[(1, 0), (2, 1)]);
+/// assert_eq!(to_edge_list_str(&graph), "3 2\n0 1\n1 2\n");
+/// ```
+pub fn to_edge_list_str(graph: &StaticGraph) -> String {
+    let mut s = format!("{} {}\n", graph.n_vert(), graph.n_edges());
+    for (u, v) in graph.edges() {
+        s.push_str(&format!("{} {}\n", u, v));
+    }
+    s
+}
+
+/// Parse a line consisting of exactly two numbers.
+fn parse_pair(line: &str) -> Result<(usize, usize), ParseErrorKind> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if tokens.len() != 2 {
+        return Err(ParseErrorKind::WrongTokenCount);
+    }
+    let parse = |token: &str| {
+        token
+            .parse::<usize>()
+            .map_err(|_| ParseErrorKind::InvalidNumber)
+    };
+    Ok((parse(tokens[0])?, parse(tokens[1])?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_edge_list_reader, from_edge_list_str, to_edge_list_str};
+    use super::{ParseError, ParseErrorKind};
+    use graph::random::XorShift;
+    use graph::StaticGraph;
+    use std::io::BufReader;
+
+    fn error(line: usize, kind: ParseErrorKind) -> Result<(), ParseError> {
+        Err(ParseError { line, kind })
+    }
+
+    fn parse(s: &str) -> Result<(), ParseError> {
+        from_edge_list_str(s).map(|_| ())
+    }
+
+    #[test]
+    fn round_trip_works() {
+        let mut rng = XorShift::new(73);
+        for _ in 0..50 {
+            let n_vert = 1 + rng.next_below(10);
+            let edges: Vec<(usize, usize)> = (0..rng.next_below(20))
+                .map(|_| (rng.next_below(n_vert), rng.next_below(n_vert)))
+                .collect();
+            let graph = StaticGraph::new(n_vert, &edges);
+            let text = to_edge_list_str(&graph);
+            let parsed = from_edge_list_reader(BufReader::new(text.as_bytes())).unwrap();
+            assert_eq!(parsed.n_vert(), graph.n_vert());
+            assert_eq!(parsed.n_edges(), graph.n_edges());
+            for v in 0..n_vert {
+                let mut expected = graph.neighbors(v).to_vec();
+                let mut actual = parsed.neighbors(v).to_vec();
+                expected.sort();
+                actual.sort();
+                assert_eq!(actual, expected);
+            }
+            assert_eq!(to_edge_list_str(&parsed), text);
+        }
+    }
+
+    #[test]
+    fn empty_graph_works() {
+        let graph = StaticGraph::new(0, &[]);
+        assert_eq!(to_edge_list_str(&graph), "0 0\n");
+        assert!(from_edge_list_str("0 0\n").unwrap().is_empty());
+        assert_eq!(from_edge_list_str("4 0").unwrap().n_vert(), 4);
+        assert_eq!(from_edge_list_str("\n2 1\n\n0 1\n\n").unwrap().n_edges(), 1);
+    }
+
+    #[test]
+    fn malformed_input_fails() {
+        assert_eq!(parse(""), error(1, ParseErrorKind::WrongTokenCount));
+        assert_eq!(parse("3\n"), error(1, ParseErrorKind::WrongTokenCount));
+        assert_eq!(
+            parse("3 1\n0 1 2\n"),
+            error(2, ParseErrorKind::WrongTokenCount)
+        );
+        assert_eq!(parse("3 1\n0 x\n"), error(2, ParseErrorKind::InvalidNumber));
+        assert_eq!(parse("3 -1\n"), error(1, ParseErrorKind::InvalidNumber));
+        assert_eq!(
+            parse("3 2\n0 1\n"),
+            error(2, ParseErrorKind::EdgeCountMismatch)
+        );
+        assert_eq!(
+            parse("3 1\n0 1\n\n1 2\n"),
+            error(4, ParseErrorKind::EdgeCountMismatch)
+        );
+        assert_eq!(
+            from_edge_list_str("3 1\n0 1 2\n")
+                .err()
+                .unwrap()
+                .to_string(),
+            "line 2: expected two numbers"
+        );
+    }
+
+    #[test]
+    fn out_of_range_vertex_fails() {
+        assert_eq!(
+            parse("3 2\n0 1\n1 3\n"),
+            error(3, ParseErrorKind::VertexOutOfRange)
+        );
+        assert_eq!(
+            parse("0 1\n0 0\n"),
+            error(2, ParseErrorKind::VertexOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! This module defines the k-core decomposition of a graph.
+//! The k-core is the maximal subgraph in which every vertex
+//! has degree at least k; the core number of a vertex is the
+//! largest k such that the vertex belongs to the k-core.
+
+use graph::StaticGraph;
+
+/// The k-core decomposition of an undirected graph computed with
+/// the Batagelj–Zaversnik peeling algorithm in O(V + E): vertices
+/// are removed in the order of their current degree, and the degree
+/// at the moment of removal is the core number.
+///
+/// # Examples
+/// ```
+/// use ralgo::{KCore, StaticGraph};
+/// let graph = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let kcore = KCore::new(&graph);
+/// assert_eq!(kcore.core_number(0), 2);
+/// assert_eq!(kcore.core_number(3), 1);
+/// assert_eq!(kcore.core_number(4), 0);
+/// assert_eq!(kcore.k_core_vertices(2), vec![0, 1, 2]);
+/// ```
+pub struct KCore {
+    core: Vec<usize>,
+}
+
+impl KCore {
+    /// Return the k-core decomposition of given graph.
+    pub fn new(graph: &StaticGraph) -> KCore {
+        let n_vert = graph.n_vert();
+        let mut degree: Vec<usize> = (0..n_vert).map(|v| graph.neighbors(v).len()).collect();
+        let max_degree = degree.iter().cloned().max().unwrap_or(0);
+
+        // Bucket sort the vertices by degree: `bin_start[d]` is the
+        // position in `order` where vertices of degree `d` begin.
+        let mut bin_start = vec![0; max_degree + 1];
+        for &d in degree.iter() {
+            bin_start[d] += 1;
+        }
+        let mut start = 0;
+        for count in bin_start.iter_mut() {
+            let size = *count;
+            *count = start;
+            start += size;
+        }
+        let mut order = vec![0; n_vert];
+        let mut pos = vec![0; n_vert];
+        {
+            let mut next = bin_start.clone();
+            for v in 0..n_vert {
+                pos[v] = next[degree[v]];
+                order[pos[v]] = v;
+                next[degree[v]] += 1;
+            }
+        }
+
+        for i in 0..n_vert {
+            let v = order[i];
+            for &u in graph.neighbors(v) {
+                if degree[u] > degree[v] {
+                    // Move `u` to the start of its bin and shrink the bin.
+                    let du = degree[u];
+                    let first = order[bin_start[du]];
+                    if first != u {
+                        order.swap(pos[u], bin_start[du]);
+                        pos[first] = pos[u];
+                        pos[u] = bin_start[du];
+                    }
+                    bin_start[du] += 1;
+                    degree[u] -= 1;
+                }
+            }
+        }
+
+        KCore { core: degree }
+    }
+
+    /// Return the core number of given vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `vert` - the vertex in question.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` >= number of vertices in the graph.
+    ///
+    pub fn core_number(&self, vert: usize) -> usize {
+        self.core[vert]
+    }
+
+    /// Return the vertices of the k-core, i.e. the vertices
+    /// with core number >= `k`, in increasing order.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - the order of the core.
+    ///
+    pub fn k_core_vertices(&self, k: usize) -> Vec<usize> {
+        (0..self.core.len())
+            .filter(|&v| self.core[v] >= k)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KCore;
+    use graph::StaticGraph;
+
+    #[test]
+    fn isolated_vertices_have_zero_core() {
+        let kcore = KCore::new(&StaticGraph::new(4, &[(0, 1)]));
+        assert_eq!(kcore.core_number(2), 0);
+        assert_eq!(kcore.core_number(3), 0);
+        assert_eq!(kcore.k_core_vertices(0), vec![0, 1, 2, 3]);
+        assert_eq!(kcore.k_core_vertices(1), vec![0, 1]);
+    }
+
+    #[test]
+    fn star_and_clique_work() {
+        let star = StaticGraph::new(5, &[(0, 1), (0, 2), (0, 3), (0, 4)]);
+        let kcore = KCore::new(&star);
+        for v in 0..5 {
+            assert_eq!(kcore.core_number(v), 1);
+        }
+
+        let mut edges = Vec::new();
+        for u in 0..6 {
+            for v in u + 1..6 {
+                edges.push((u, v));
+            }
+        }
+        let kcore = KCore::new(&StaticGraph::new(6, &edges));
+        for v in 0..6 {
+            assert_eq!(kcore.core_number(v), 5);
+        }
+    }
+
+    #[test]
+    fn complete_bipartite_with_tail_works() {
+        let mut edges = Vec::new();
+        for u in 0..3 {
+            for v in 3..6 {
+                edges.push((u, v));
+            }
+        }
+        edges.push((5, 6));
+        edges.push((6, 7));
+        let kcore = KCore::new(&StaticGraph::new(8, &edges));
+        for v in 0..6 {
+            assert_eq!(kcore.core_number(v), 3);
+        }
+        assert_eq!(kcore.core_number(6), 1);
+        assert_eq!(kcore.core_number(7), 1);
+        assert_eq!(kcore.k_core_vertices(2), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(kcore.k_core_vertices(4), vec![]);
+    }
+}
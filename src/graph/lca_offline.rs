@@ -0,0 +1,274 @@
+//! This module contains lowest common ancestor queries on a rooted
+//! tree answered offline with Tarjan's algorithm.
+
+use std::collections::HashMap;
+
+use graph::{TreeIndexed, UnionFind};
+
+/// The answers to a batch of lowest common ancestor queries, keyed
+/// by the unordered pair of vertices. All queries are answered at
+/// once in O((n + q) α(n)) and stored in a hash map.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{LcaOffline, TreeIndexed};
+/// let tree = TreeIndexed::new(5, 0, &[(0, 1), (0, 2), (2, 3), (2, 4)]);
+/// let lca = LcaOffline::new(&tree, &[(3, 4), (1, 4)]);
+/// assert_eq!(lca.ancestor(4, 3), Some(2));
+/// assert_eq!(lca.ancestor(1, 4), Some(0));
+/// assert_eq!(lca.ancestor(1, 3), None);
+/// ```
+pub struct LcaOffline {
+    answers: HashMap<(usize, usize), usize>,
+}
+
+impl LcaOffline {
+    /// Answer `queries` on `tree`.
+    ///
+    /// # Panics
+    ///
+    /// If a query contains a vertex `>= tree.n_vert()`.
+    ///
+    pub fn new(tree: &TreeIndexed, queries: &[(usize, usize)]) -> LcaOffline {
+        let mut answers = HashMap::with_capacity(queries.len());
+        tarjan(tree, queries, |index, ancestor| {
+            let (u, v) = queries[index];
+            answers.insert(ordered(u, v), ancestor);
+        });
+        LcaOffline { answers }
+    }
+
+    /// Return the lowest common ancestor of `v` and `u`, or `None`
+    /// if the pair was not queried.
+    pub fn ancestor(&self, v: usize, u: usize) -> Option<usize> {
+        self.answers.get(&ordered(u, v)).cloned()
+    }
+}
+
+/// The answers to a batch of lowest common ancestor queries stored
+/// in a flat triangular table with a slot for every pair of
+/// vertices. Lookups avoid hashing, at the price of O(n²) memory
+/// regardless of the number of queries, so this is meant for trees
+/// of up to a few thousand vertices. Whether that pays off depends on
+/// the workload; the ignored test `lookup_timings` compares both
+/// tables (`cargo test --release -- --ignored --nocapture`).
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{LcaOfflineFast, TreeIndexed};
+/// let tree = TreeIndexed::new(5, 0, &[(0, 1), (0, 2), (2, 3), (2, 4)]);
+/// let lca = LcaOfflineFast::new(&tree, &[(3, 4), (1, 4)]);
+/// assert_eq!(lca.ancestor(4, 3), Some(2));
+/// assert_eq!(lca.ancestor(1, 3), None);
+/// ```
+pub struct LcaOfflineFast {
+    answers: Vec<Option<usize>>,
+}
+
+impl LcaOfflineFast {
+    /// Answer `queries` on `tree`.
+    ///
+    /// # Panics
+    ///
+    /// If a query contains a vertex `>= tree.n_vert()`.
+    ///
+    pub fn new(tree: &TreeIndexed, queries: &[(usize, usize)]) -> LcaOfflineFast {
+        let n = tree.n_vert();
+        let mut answers = vec![None; n * (n + 1) / 2];
+        tarjan(tree, queries, |index, ancestor| {
+            let (u, v) = queries[index];
+            answers[triangular(u, v)] = Some(ancestor);
+        });
+        LcaOfflineFast { answers }
+    }
+
+    /// Return the lowest common ancestor of `v` and `u`, or `None`
+    /// if the pair was not queried.
+    pub fn ancestor(&self, v: usize, u: usize) -> Option<usize> {
+        self.answers[triangular(u, v)]
+    }
+}
+
+/// The answers to a batch of lowest common ancestor queries stored
+/// in the order of the queries, so a lookup is a plain index and
+/// memory is O(n + q). A pair queried in both orders gets two
+/// equal answers.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{LcaOfflineDense, TreeIndexed};
+/// let tree = TreeIndexed::new(5, 0, &[(0, 1), (0, 2), (2, 3), (2, 4)]);
+/// let lca = LcaOfflineDense::new(&tree, &[(3, 4), (1, 4)]);
+/// assert_eq!(lca.result(0), 2);
+/// assert_eq!(lca.result(1), 0);
+/// ```
+pub struct LcaOfflineDense {
+    answers: Vec<usize>,
+}
+
+impl LcaOfflineDense {
+    /// Answer `queries` on `tree`.
+    ///
+    /// # Panics
+    ///
+    /// If a query contains a vertex `>= tree.n_vert()`.
+    ///
+    pub fn new(tree: &TreeIndexed, queries: &[(usize, usize)]) -> LcaOfflineDense {
+        let mut answers = vec![0; queries.len()];
+        tarjan(tree, queries, |index, ancestor| answers[index] = ancestor);
+        LcaOfflineDense { answers }
+    }
+
+    /// Return the lowest common ancestor for `queries[query_index]`.
+    ///
+    /// # Panics
+    ///
+    /// If `query_index` is out of range.
+    ///
+    pub fn result(&self, query_index: usize) -> usize {
+        self.answers[query_index]
+    }
+}
+
+/// Return the pair with the smaller vertex first.
+fn ordered(u: usize, v: usize) -> (usize, usize) {
+    (u.min(v), u.max(v))
+}
+
+/// Return the index of the unordered pair in a triangular table.
+fn triangular(u: usize, v: usize) -> usize {
+    let (lo, hi) = ordered(u, v);
+    hi * (hi + 1) / 2 + lo
+}
+
+/// Run Tarjan's offline algorithm, calling `answer(index, lca)` for
+/// every query. Vertices are finished in postorder; a finished
+/// subtree is merged into its parent, so the set of a finished
+/// vertex is labeled with its deepest ancestor that is not finished
+/// yet, which is the answer for a query with the current vertex.
+fn tarjan<F: FnMut(usize, usize)>(tree: &TreeIndexed, queries: &[(usize, usize)], mut answer: F) {
+    let n = tree.n_vert();
+    let mut partners = vec![Vec::new(); n];
+    for (index, &(u, v)) in queries.iter().enumerate() {
+        assert!(u < n && v < n, "query ({}, {}) is out of range", u, v);
+        partners[u].push(index);
+        partners[v].push(index);
+    }
+
+    let mut sets = UnionFind::new(n);
+    let mut ancestor: Vec<usize> = (0..n).collect();
+    let mut finished = vec![false; n];
+    for u in tree.postorder() {
+        finished[u] = true;
+        for &index in partners[u].iter() {
+            let (a, b) = queries[index];
+            let other = if a == u { b } else { a };
+            if finished[other] {
+                answer(index, ancestor[sets.find(other)]);
+            }
+        }
+        if let Some(p) = tree.parent(u) {
+            let repr = sets.union(p, u);
+            ancestor[repr] = p;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LcaOffline, LcaOfflineDense, LcaOfflineFast};
+    use graph::random::XorShift;
+    use graph::{LcaOnline, TreeIndexed};
+    use std::time::Instant;
+
+    #[test]
+    fn random_trees_match_online() {
+        let mut rng = XorShift::new(107);
+        for n in 1..40 {
+            let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+            let tree = TreeIndexed::new(n, rng.next_below(n), &edges);
+            let queries: Vec<(usize, usize)> = (0..30)
+                .map(|_| (rng.next_below(n), rng.next_below(n)))
+                .collect();
+            let online = LcaOnline::new(&tree);
+            let offline = LcaOffline::new(&tree, &queries);
+            let fast = LcaOfflineFast::new(&tree, &queries);
+            let dense = LcaOfflineDense::new(&tree, &queries);
+            for (i, &(u, v)) in queries.iter().enumerate() {
+                let expected = Some(online.lca(u, v));
+                assert_eq!(offline.ancestor(u, v), expected);
+                assert_eq!(offline.ancestor(v, u), expected);
+                assert_eq!(fast.ancestor(v, u), expected);
+                assert_eq!(Some(dense.result(i)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn unqueried_pairs_are_none() {
+        let tree = TreeIndexed::new(4, 0, &[(0, 1), (1, 2), (1, 3)]);
+        let offline = LcaOffline::new(&tree, &[(2, 2), (3, 0)]);
+        let fast = LcaOfflineFast::new(&tree, &[(2, 2), (3, 0)]);
+        assert_eq!(offline.ancestor(2, 2), Some(2));
+        assert_eq!(fast.ancestor(0, 3), Some(0));
+        assert_eq!(offline.ancestor(2, 3), None);
+        assert_eq!(fast.ancestor(2, 3), None);
+        assert_eq!(fast.ancestor(3, 3), None);
+    }
+
+    #[test]
+    fn dense_results_follow_query_order() {
+        let tree = TreeIndexed::new(6, 0, &[(0, 1), (1, 2), (1, 3), (0, 4), (4, 5)]);
+        let queries = [(2, 3), (3, 2), (5, 2), (4, 4), (2, 5), (1, 3)];
+        let dense = LcaOfflineDense::new(&tree, &queries);
+        let results: Vec<usize> = (0..queries.len()).map(|i| dense.result(i)).collect();
+        assert_eq!(results, vec![1, 1, 0, 4, 0, 1]);
+    }
+
+    #[test]
+    #[ignore]
+    fn lookup_timings() {
+        let n = 1000;
+        let mut rng = XorShift::new(113);
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+        let tree = TreeIndexed::new(n, 0, &edges);
+        let queries: Vec<(usize, usize)> = (0..10_000)
+            .map(|_| (rng.next_below(n), rng.next_below(n)))
+            .collect();
+        let rounds = 100;
+
+        let start = Instant::now();
+        let offline = LcaOffline::new(&tree, &queries);
+        let built = start.elapsed();
+        let mut offline_sum = 0;
+        for _ in 0..rounds {
+            for &(u, v) in &queries {
+                offline_sum += offline.ancestor(u, v).unwrap();
+            }
+        }
+        println!(
+            "LcaOffline: build {:?}, {} lookup rounds {:?}",
+            built,
+            rounds,
+            start.elapsed() - built
+        );
+
+        let start = Instant::now();
+        let fast = LcaOfflineFast::new(&tree, &queries);
+        let built = start.elapsed();
+        let mut fast_sum = 0;
+        for _ in 0..rounds {
+            for &(u, v) in &queries {
+                fast_sum += fast.ancestor(u, v).unwrap();
+            }
+        }
+        println!(
+            "LcaOfflineFast: build {:?}, {} lookup rounds {:?}",
+            built,
+            rounds,
+            start.elapsed() - built
+        );
+
+        assert_eq!(offline_sum, fast_sum);
+    }
+}
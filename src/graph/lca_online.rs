@@ -0,0 +1,116 @@
+//! This module contains lowest common ancestor queries on a rooted
+//! tree answered online with binary lifting.
+
+use graph::TreeIndexed;
+
+/// The lowest common ancestor oracle over a rooted tree. Stores the
+/// 2^j-th ancestor of every vertex, so preprocessing takes
+/// O(n log n) time and memory and every query takes O(log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{LcaOnline, TreeIndexed};
+/// let tree = TreeIndexed::new(6, 0, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]);
+/// let lca = LcaOnline::new(&tree);
+/// assert_eq!(lca.lca(3, 4), 1);
+/// assert_eq!(lca.lca(4, 5), 0);
+/// assert_eq!(lca.lca(1, 3), 1);
+/// ```
+pub struct LcaOnline {
+    up: Vec<Vec<usize>>,
+    entry: Vec<usize>,
+    exit: Vec<usize>,
+}
+
+impl LcaOnline {
+    /// Return the oracle for `tree`.
+    pub fn new(tree: &TreeIndexed) -> LcaOnline {
+        let n = tree.n_vert();
+        let preorder = tree.preorder();
+        let mut entry = vec![0; n];
+        for (i, &v) in preorder.iter().enumerate() {
+            entry[v] = i;
+        }
+        let mut size = vec![1; n];
+        for &v in preorder.iter().rev() {
+            if let Some(p) = tree.parent(v) {
+                size[p] += size[v];
+            }
+        }
+        let exit = (0..n).map(|v| entry[v] + size[v] - 1).collect();
+
+        // The root is its own parent, so lifting stops there.
+        let mut up = vec![(0..n)
+            .map(|v| tree.parent(v).unwrap_or(v))
+            .collect::<Vec<usize>>()];
+        while 1 << up.len() < n {
+            let last = &up[up.len() - 1];
+            let next = (0..n).map(|v| last[last[v]]).collect();
+            up.push(next);
+        }
+        LcaOnline { up, entry, exit }
+    }
+
+    /// Return the lowest common ancestor of `u` and `v`.
+    pub fn lca(&self, mut u: usize, v: usize) -> usize {
+        if self.is_ancestor(u, v) {
+            return u;
+        }
+        for level in self.up.iter().rev() {
+            if !self.is_ancestor(level[u], v) {
+                u = level[u];
+            }
+        }
+        self.up[0][u]
+    }
+
+    /// Return `true` if `a` is an ancestor of `b` or `a == b`.
+    pub fn is_ancestor(&self, a: usize, b: usize) -> bool {
+        self.entry[a] <= self.entry[b] && self.exit[b] <= self.exit[a]
+    }
+
+    /// Return the position of `vert` in the depth-first preorder.
+    pub(crate) fn entry(&self, vert: usize) -> usize {
+        self.entry[vert]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LcaOnline;
+    use graph::random::XorShift;
+    use graph::TreeIndexed;
+
+    #[test]
+    fn random_trees_match_naive() {
+        let mut rng = XorShift::new(83);
+        for n in 1..40 {
+            let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+            let tree = TreeIndexed::new(n, 0, &edges);
+            let lca = LcaOnline::new(&tree);
+            for _ in 0..50 {
+                let (mut u, mut v) = (rng.next_below(n), rng.next_below(n));
+                let (a, b) = (u, v);
+                while u != v {
+                    if tree.depth(u) < tree.depth(v) {
+                        std::mem::swap(&mut u, &mut v);
+                    }
+                    u = tree.parent(u).unwrap();
+                }
+                assert_eq!(lca.lca(a, b), u);
+                assert!(lca.is_ancestor(u, b));
+            }
+        }
+    }
+
+    #[test]
+    fn path_works() {
+        let edges: Vec<(usize, usize)> = (1..100).map(|v| (v - 1, v)).collect();
+        let tree = TreeIndexed::new(100, 99, &edges);
+        let lca = LcaOnline::new(&tree);
+        assert_eq!(lca.lca(0, 50), 50);
+        assert_eq!(lca.lca(13, 0), 13);
+        assert_eq!(lca.lca(99, 99), 99);
+        assert!(!lca.is_ancestor(0, 1));
+    }
+}
@@ -0,0 +1,280 @@
+//! This module contains Dinic's maximum flow algorithm on
+//! a network that can be reused for many source-sink pairs.
+
+use std::collections::VecDeque;
+
+/// An arc of the residual network. The reverse arc of arc `i`
+/// is arc `i ^ 1`.
+struct Arc {
+    to: usize,
+    capacity: i64,
+    flow: i64,
+}
+
+/// A flow network with integer capacities. Every call to
+/// `max_flow` starts from the zero flow, so the same network
+/// can be queried for different sources and sinks.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::MaxFlow;
+/// let mut network = MaxFlow::new(4);
+/// network.add_edge(0, 1, 3);
+/// network.add_edge(0, 2, 2);
+/// network.add_edge(1, 2, 1);
+/// network.add_edge(1, 3, 2);
+/// network.add_edge(2, 3, 3);
+/// assert_eq!(network.max_flow(0, 3), 5);
+/// assert_eq!(network.min_cut(), vec![true, false, false, false]);
+/// ```
+pub struct MaxFlow {
+    arcs: Vec<Arc>,
+    adj: Vec<Vec<usize>>,
+    source: usize,
+}
+
+impl MaxFlow {
+    /// Return a new network without edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices.
+    ///
+    pub fn new(n_vert: usize) -> MaxFlow {
+        MaxFlow {
+            arcs: Vec::new(),
+            adj: vec![Vec::new(); n_vert],
+            source: 0,
+        }
+    }
+
+    /// Return the number of vertices of the network.
+    pub fn n_vert(&self) -> usize {
+        self.adj.len()
+    }
+
+    /// Add a directed edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - the tail of the edge;
+    /// * `v` - the head of the edge;
+    /// * `capacity` - the maximum flow through the edge.
+    ///
+    /// # Panics
+    ///
+    /// If `u` or `v` is out of range or `capacity` is negative.
+    ///
+    pub fn add_edge(&mut self, u: usize, v: usize, capacity: i64) {
+        self.add_arcs(u, v, capacity, 0);
+    }
+
+    /// Add an undirected edge, which can carry up to `capacity`
+    /// units of flow in either direction.
+    ///
+    /// # Panics
+    ///
+    /// If `u` or `v` is out of range or `capacity` is negative.
+    ///
+    pub fn add_undirected_edge(&mut self, u: usize, v: usize, capacity: i64) {
+        self.add_arcs(u, v, capacity, capacity);
+    }
+
+    /// Return the value of the maximum flow from `source` to `sink`
+    /// in O(V² E).
+    ///
+    /// # Panics
+    ///
+    /// If `source` or `sink` is out of range, or they are equal.
+    ///
+    pub fn max_flow(&mut self, source: usize, sink: usize) -> i64 {
+        assert!(
+            source < self.n_vert() && sink < self.n_vert(),
+            "source or sink is out of range"
+        );
+        assert!(source != sink, "source and sink must differ");
+        for arc in self.arcs.iter_mut() {
+            arc.flow = 0;
+        }
+        self.source = source;
+
+        let mut total = 0;
+        loop {
+            let level = self.levels();
+            if level[sink] == usize::MAX {
+                break;
+            }
+            let mut next = vec![0; self.n_vert()];
+            loop {
+                let pushed = self.augment(source, sink, i64::MAX, &level, &mut next);
+                if pushed == 0 {
+                    break;
+                }
+                total += pushed;
+            }
+        }
+        total
+    }
+
+    /// Return the source side of a minimum cut after the last
+    /// `max_flow` call: `true` for the vertices reachable from
+    /// the source in the residual network.
+    pub fn min_cut(&self) -> Vec<bool> {
+        self.levels().iter().map(|&l| l != usize::MAX).collect()
+    }
+
+    /// Add an arc of given capacity and its reverse arc.
+    fn add_arcs(&mut self, u: usize, v: usize, capacity: i64, reverse_capacity: i64) {
+        assert!(
+            u < self.n_vert() && v < self.n_vert(),
+            "edge ({}, {}) is out of range",
+            u,
+            v
+        );
+        assert!(capacity >= 0, "capacity must be non-negative");
+        self.adj[u].push(self.arcs.len());
+        self.arcs.push(Arc {
+            to: v,
+            capacity,
+            flow: 0,
+        });
+        self.adj[v].push(self.arcs.len());
+        self.arcs.push(Arc {
+            to: u,
+            capacity: reverse_capacity,
+            flow: 0,
+        });
+    }
+
+    /// Return the BFS distances from the source in the residual
+    /// network, `usize::MAX` for unreachable vertices.
+    fn levels(&self) -> Vec<usize> {
+        let mut level = vec![usize::MAX; self.n_vert()];
+        if self.n_vert() == 0 {
+            return level;
+        }
+        level[self.source] = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back(self.source);
+        while let Some(u) = queue.pop_front() {
+            for &i in self.adj[u].iter() {
+                let arc = &self.arcs[i];
+                if arc.flow < arc.capacity && level[arc.to] == usize::MAX {
+                    level[arc.to] = level[u] + 1;
+                    queue.push_back(arc.to);
+                }
+            }
+        }
+        level
+    }
+
+    /// Push up to `limit` units along level-increasing paths from
+    /// `u` to `sink` and return the amount pushed.
+    fn augment(
+        &mut self,
+        u: usize,
+        sink: usize,
+        limit: i64,
+        level: &[usize],
+        next: &mut [usize],
+    ) -> i64 {
+        if u == sink {
+            return limit;
+        }
+        while next[u] < self.adj[u].len() {
+            let i = self.adj[u][next[u]];
+            let (to, residual) = (self.arcs[i].to, self.arcs[i].capacity - self.arcs[i].flow);
+            if residual > 0 && level[to] == level[u] + 1 {
+                let pushed = self.augment(to, sink, limit.min(residual), level, next);
+                if pushed > 0 {
+                    self.arcs[i].flow += pushed;
+                    self.arcs[i ^ 1].flow -= pushed;
+                    return pushed;
+                }
+            }
+            next[u] += 1;
+        }
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MaxFlow;
+    use graph::random::XorShift;
+
+    #[test]
+    fn textbook_network_works() {
+        let mut network = MaxFlow::new(6);
+        for &(u, v, c) in [
+            (0, 1, 16),
+            (0, 2, 13),
+            (1, 2, 10),
+            (2, 1, 4),
+            (1, 3, 12),
+            (3, 2, 9),
+            (2, 4, 14),
+            (4, 3, 7),
+            (3, 5, 20),
+            (4, 5, 4),
+        ]
+        .iter()
+        {
+            network.add_edge(u, v, c);
+        }
+        assert_eq!(network.max_flow(0, 5), 23);
+        assert_eq!(network.max_flow(0, 5), 23);
+        assert_eq!(network.max_flow(5, 0), 0);
+        assert_eq!(
+            network.min_cut(),
+            vec![false, false, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn disconnected_and_undirected_networks_work() {
+        let mut network = MaxFlow::new(4);
+        network.add_undirected_edge(0, 1, 5);
+        network.add_undirected_edge(2, 3, 5);
+        assert_eq!(network.max_flow(0, 3), 0);
+        assert_eq!(network.min_cut(), vec![true, true, false, false]);
+        assert_eq!(network.max_flow(1, 0), 5);
+    }
+
+    #[test]
+    fn random_networks_match_min_cut() {
+        let mut rng = XorShift::new(67);
+        for _ in 0..100 {
+            let n_vert = 2 + rng.next_below(6);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.next_below(15))
+                .map(|_| {
+                    let c = rng.next_below(10) as i64;
+                    (rng.next_below(n_vert), rng.next_below(n_vert), c)
+                })
+                .collect();
+            let mut network = MaxFlow::new(n_vert);
+            for &(u, v, c) in edges.iter() {
+                network.add_edge(u, v, c);
+            }
+            let (s, t) = (0, n_vert - 1);
+            let flow = network.max_flow(s, t);
+            let cut_value = |side: &dyn Fn(usize) -> bool| -> i64 {
+                edges
+                    .iter()
+                    .filter(|&&(u, v, _)| side(u) && !side(v))
+                    .map(|e| e.2)
+                    .sum()
+            };
+
+            let cut = network.min_cut();
+            assert!(cut[s] && !cut[t]);
+            assert_eq!(cut_value(&|v| cut[v]), flow);
+            let best = (0..1usize << n_vert)
+                .filter(|mask| mask >> s & 1 == 1 && mask >> t & 1 == 0)
+                .map(|mask| cut_value(&|v| mask >> v & 1 == 1))
+                .min()
+                .unwrap();
+            assert_eq!(flow, best);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! This module contains the minimum cost flow algorithm based on
+//! successive shortest augmenting paths.
+
+use std::collections::VecDeque;
+
+/// An arc of the residual network. The reverse arc of arc `i`
+/// is arc `i ^ 1`.
+struct Arc {
+    to: usize,
+    cap: i64,
+    cost: i64,
+}
+
+/// A flow network with costs per unit of flow, sending flow from
+/// `source` to `sink` along the cheapest augmenting paths. Shortest
+/// paths are found with SPFA, so arcs may have negative costs as
+/// long as the network has no negative cycle.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::MinCostFlow;
+/// let mut network = MinCostFlow::new(4, 0, 3);
+/// network.add_edge(0, 1, 2, 1);
+/// network.add_edge(0, 2, 1, 2);
+/// network.add_edge(1, 3, 1, 3);
+/// network.add_edge(1, 2, 1, 1);
+/// network.add_edge(2, 3, 2, 1);
+/// assert_eq!(network.min_cost_flow(3), Some((3, 10)));
+/// ```
+pub struct MinCostFlow {
+    source: usize,
+    sink: usize,
+    arcs: Vec<Arc>,
+    adj: Vec<Vec<usize>>,
+}
+
+impl MinCostFlow {
+    /// Return a new network without edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices;
+    /// * `source` - the vertex the flow leaves;
+    /// * `sink` - the vertex the flow enters.
+    ///
+    /// # Panics
+    ///
+    /// If `source` or `sink` is out of range, or they are equal.
+    ///
+    pub fn new(n_vert: usize, source: usize, sink: usize) -> MinCostFlow {
+        assert!(
+            source < n_vert && sink < n_vert,
+            "source or sink is out of range"
+        );
+        assert!(source != sink, "source and sink must differ");
+        MinCostFlow {
+            source,
+            sink,
+            arcs: Vec::new(),
+            adj: vec![Vec::new(); n_vert],
+        }
+    }
+
+    /// Add a directed edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - the tail of the edge;
+    /// * `v` - the head of the edge;
+    /// * `capacity` - the maximum flow through the edge;
+    /// * `cost` - the cost of one unit of flow through the edge.
+    ///
+    /// # Panics
+    ///
+    /// If `u` or `v` is out of range or `capacity` is negative.
+    ///
+    pub fn add_edge(&mut self, u: usize, v: usize, capacity: i64, cost: i64) {
+        assert!(
+            u < self.adj.len() && v < self.adj.len(),
+            "vertex is out of range"
+        );
+        assert!(capacity >= 0, "capacity must be non-negative");
+        self.adj[u].push(self.arcs.len());
+        self.arcs.push(Arc {
+            to: v,
+            cap: capacity,
+            cost,
+        });
+        self.adj[v].push(self.arcs.len());
+        self.arcs.push(Arc {
+            to: u,
+            cap: 0,
+            cost: -cost,
+        });
+    }
+
+    /// Send `desired_flow` units from the source to the sink at
+    /// the minimum total cost and return `(flow, cost)`, or `None`
+    /// if the network can not carry that much flow. Runs in
+    /// O(F · V · E) for the flow value F.
+    ///
+    /// The flow is added on top of the flow sent by previous calls;
+    /// on `None` the flow sent so far is kept in the network.
+    ///
+    pub fn min_cost_flow(&mut self, desired_flow: i64) -> Option<(i64, i64)> {
+        let (mut flow, mut cost) = (0, 0);
+        while flow < desired_flow {
+            let (dist, pred) = self.shortest_paths()?;
+            let mut push = desired_flow - flow;
+            let mut v = self.sink;
+            while v != self.source {
+                push = push.min(self.arcs[pred[v]].cap);
+                v = self.arcs[pred[v] ^ 1].to;
+            }
+            let mut v = self.sink;
+            while v != self.source {
+                self.arcs[pred[v]].cap -= push;
+                self.arcs[pred[v] ^ 1].cap += push;
+                v = self.arcs[pred[v] ^ 1].to;
+            }
+            flow += push;
+            cost += push * dist;
+        }
+        Some((flow, cost))
+    }
+
+    /// Return the cost of the cheapest residual path to the sink and
+    /// the arc entering every vertex on the shortest path tree, or
+    /// `None` if the sink is unreachable.
+    fn shortest_paths(&self) -> Option<(i64, Vec<usize>)> {
+        let n_vert = self.adj.len();
+        let mut dist = vec![None; n_vert];
+        let mut pred = vec![usize::MAX; n_vert];
+        let mut queued = vec![false; n_vert];
+        let mut queue = VecDeque::new();
+        dist[self.source] = Some(0);
+        queue.push_back(self.source);
+        while let Some(u) = queue.pop_front() {
+            queued[u] = false;
+            let du = dist[u].unwrap();
+            for &i in self.adj[u].iter() {
+                let arc = &self.arcs[i];
+                if arc.cap > 0 && dist[arc.to].is_none_or(|d| du + arc.cost < d) {
+                    dist[arc.to] = Some(du + arc.cost);
+                    pred[arc.to] = i;
+                    if !queued[arc.to] {
+                        queued[arc.to] = true;
+                        queue.push_back(arc.to);
+                    }
+                }
+            }
+        }
+        dist[self.sink].map(|d| (d, pred))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MinCostFlow;
+
+    #[test]
+    fn assignment_problem_works() {
+        // Workers 1..=3, jobs 4..=6, source 0, sink 7.
+        let cost = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+        let mut network = MinCostFlow::new(8, 0, 7);
+        for (i, row) in cost.iter().enumerate() {
+            network.add_edge(0, 1 + i, 1, 0);
+            network.add_edge(4 + i, 7, 1, 0);
+            for (j, &c) in row.iter().enumerate() {
+                network.add_edge(1 + i, 4 + j, 1, c);
+            }
+        }
+        assert_eq!(network.min_cost_flow(3), Some((3, 5)));
+    }
+
+    #[test]
+    fn cheap_paths_are_used_first() {
+        let mut network = MinCostFlow::new(4, 0, 3);
+        network.add_edge(0, 1, 2, 1);
+        network.add_edge(1, 3, 2, 1);
+        network.add_edge(0, 2, 5, 10);
+        network.add_edge(2, 3, 5, 10);
+        assert_eq!(network.min_cost_flow(2), Some((2, 4)));
+        assert_eq!(network.min_cost_flow(3), Some((3, 60)));
+    }
+
+    #[test]
+    fn negative_costs_work() {
+        let mut network = MinCostFlow::new(3, 0, 2);
+        network.add_edge(0, 1, 1, -5);
+        network.add_edge(1, 2, 1, 2);
+        network.add_edge(0, 2, 1, 0);
+        assert_eq!(network.min_cost_flow(2), Some((2, -3)));
+    }
+
+    #[test]
+    fn infeasible_and_zero_flow_work() {
+        let mut network = MinCostFlow::new(3, 0, 2);
+        network.add_edge(0, 1, 3, 1);
+        network.add_edge(1, 2, 2, 1);
+        assert_eq!(network.min_cost_flow(0), Some((0, 0)));
+        assert_eq!(network.min_cost_flow(5), None);
+        assert_eq!(MinCostFlow::new(2, 0, 1).min_cost_flow(1), None);
+    }
+}
@@ -1,5 +1,114 @@
+pub mod adjacency;
+pub mod apsp;
+pub mod arborescence;
+pub mod astar;
+pub mod bellman_ford;
+pub mod bfs;
+pub mod bipartite;
+pub mod clique;
+pub mod closure;
+pub mod coloring;
+pub mod connectivity;
+pub mod cycles;
+pub mod dag;
+pub mod degree;
+pub mod dfs;
+pub mod digraph;
+pub mod dijkstra;
+pub mod dominators;
+pub mod dot;
+pub mod dynamic_connectivity;
+pub mod eccentricity;
+pub mod feedback;
+pub mod girth;
+pub mod gomory_hu;
+pub mod hld;
+pub mod hungarian;
+pub mod indexed;
+pub mod io;
+pub mod johnson;
+pub mod kcore;
+pub mod lca_offline;
+pub mod lca_online;
+pub mod max_flow;
+pub mod min_cost_flow;
+pub mod mst;
+pub mod power;
+pub mod prufer;
+pub mod random;
 pub mod static_graph;
+pub mod subtree_queries;
+pub mod topological_sort;
+pub mod tree_indexed;
+pub mod tree_path_max_query;
+pub mod tree_path_queries;
+pub mod treewidth;
 pub mod union_find;
+pub mod union_find_aggregate;
+pub mod union_find_by_size;
+pub mod union_find_rollback;
+pub mod union_find_rooted;
+pub mod virtual_tree;
+pub mod weighted_digraph;
+pub mod weighted_graph;
 
+pub use self::adjacency::AdjacencyList;
+pub use self::apsp::{all_pairs_bfs, graph_diameter_fast};
+pub use self::arborescence::min_arborescence;
+pub use self::astar::astar;
+pub use self::bellman_ford::{bellman_ford, NegativeCycle};
+pub use self::bfs::Bfs;
+pub use self::bipartite::{
+    bipartite_max_independent_set, bipartition, complete_bipartite, max_bipartite_matching,
+    BipartiteGraph, NotBipartite,
+};
+pub use self::clique::{all_maximal_cliques, maximum_clique};
+pub use self::closure::{is_reachable, transitive_closure, ReachabilityOracle};
+pub use self::coloring::{chromatic_number_upper_bound, greedy_coloring};
+pub use self::connectivity::{edge_connectivity, vertex_connectivity};
+pub use self::cycles::{enumerate_simple_cycles, minimum_cycle_basis};
+pub use self::dag::{longest_path, longest_path_length, longest_weighted_path, CycleError, Dag};
+pub use self::degree::{degree_sequence, is_graphical, is_k_regular, regular_degree};
+pub use self::dfs::Dfs;
+pub use self::digraph::DiGraph;
+pub use self::dijkstra::Dijkstra;
+pub use self::dominators::DominatorTree;
+pub use self::dot::{digraph_to_dot, to_dot, to_dot_with_labels};
+pub use self::dynamic_connectivity::DynamicConnectivity;
+pub use self::eccentricity::{center_vertices, diameter, eccentricity, radius};
+pub use self::feedback::{feedback_vertex_set, is_feedback_vertex_set};
+pub use self::girth::girth;
+pub use self::gomory_hu::GomoryHuTree;
+pub use self::hld::Hld;
+pub use self::hungarian::hungarian;
+pub use self::indexed::GraphIndexed;
+pub use self::io::{
+    from_edge_list_reader, from_edge_list_str, to_edge_list_str, ParseError, ParseErrorKind,
+};
+pub use self::johnson::johnson;
+pub use self::kcore::KCore;
+pub use self::lca_offline::{LcaOffline, LcaOfflineDense, LcaOfflineFast};
+pub use self::lca_online::LcaOnline;
+pub use self::max_flow::MaxFlow;
+pub use self::min_cost_flow::MinCostFlow;
+pub use self::mst::{is_spanning_tree, minimum_spanning_forest, spanning_tree_weight, verify_mst};
+pub use self::power::graph_power;
+pub use self::prufer::{prufer_decode, prufer_encode};
+pub use self::random::{
+    random_bipartite_graph, random_graph_gnm, random_graph_gnp, random_regular_graph,
+};
 pub use self::static_graph::StaticGraph;
-pub use self::union_find::UnionFind;
+pub use self::subtree_queries::SubtreeQueries;
+pub use self::topological_sort::{all_topological_sorts, count_topological_sorts};
+pub use self::tree_indexed::{NotATree, TreeIndexed};
+pub use self::tree_path_max_query::TreePathMaxQuery;
+pub use self::tree_path_queries::TreePathQueries;
+pub use self::treewidth::{elimination_order, treewidth_approx};
+pub use self::union_find::{DeserializeError, UnionFind};
+pub use self::union_find_aggregate::UnionFindAggregate;
+pub use self::union_find_by_size::UnionFindBySize;
+pub use self::union_find_rollback::UnionFindRollback;
+pub use self::union_find_rooted::UnionFindRooted;
+pub use self::virtual_tree::build_virtual_tree;
+pub use self::weighted_digraph::WeightedDiGraph;
+pub use self::weighted_graph::WeightedGraph;
@@ -0,0 +1,380 @@
+//! This module contains minimum spanning forest construction
+//! with Kruskal's algorithm and helpers for checking and
+//! weighing spanning trees.
+
+use graph::{StaticGraph, UnionFind, WeightedGraph};
+
+/// Return the edges of a minimum spanning forest of the graph,
+/// i.e. a minimum spanning tree of every connected component,
+/// in the order of non-decreasing weight.
+///
+/// Implements Kruskal's algorithm in O(E log E).
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedGraph;
+/// use ralgo::graph::minimum_spanning_forest;
+/// let graph = WeightedGraph::new(4, &[(0, 1, 3), (1, 2, 1), (0, 2, 2), (3, 3, 0)]);
+/// assert_eq!(minimum_spanning_forest(&graph), vec![(1, 2, 1), (0, 2, 2)]);
+/// ```
+pub fn minimum_spanning_forest(graph: &WeightedGraph) -> Vec<(usize, usize, i64)> {
+    let mut edges = graph.edges().to_vec();
+    edges.sort_by_key(|&(_, _, w)| w);
+
+    let mut components = UnionFind::new(graph.n_vert());
+    edges
+        .into_iter()
+        .filter(|&(u, v, _)| {
+            if components.connected(u, v) {
+                false
+            } else {
+                components.union(u, v);
+                true
+            }
+        })
+        .collect()
+}
+
+/// Return `true` if the given edges form a spanning tree of
+/// the graph: all of them belong to the graph, there are exactly
+/// `n_vert - 1` of them, and they connect all vertices without
+/// forming a cycle.
+///
+/// # Arguments
+///
+/// * `graph` - the graph in question;
+/// * `edges` - the candidate tree edges.
+///
+pub fn is_spanning_tree(graph: &StaticGraph, edges: &[(usize, usize)]) -> bool {
+    let n_vert = graph.n_vert();
+    if edges.len() + 1 != n_vert.max(1) {
+        return false;
+    }
+    let mut components = UnionFind::new(n_vert);
+    for &(u, v) in edges {
+        if u >= n_vert || !graph.neighbors(u).contains(&v) || components.connected(u, v) {
+            return false;
+        }
+        components.union(u, v);
+    }
+    true
+}
+
+/// Return the total weight of the given edges. If the graph has
+/// parallel edges, the lightest of them is used.
+///
+/// # Arguments
+///
+/// * `graph` - the graph in question;
+/// * `edges` - the edges to weigh.
+///
+/// # Panics
+///
+/// If any of `edges` is not an edge of the graph.
+///
+pub fn spanning_tree_weight(graph: &WeightedGraph, edges: &[(usize, usize)]) -> i64 {
+    edges
+        .iter()
+        .map(|&(u, v)| {
+            graph
+                .neighbors_with_weights(u)
+                .iter()
+                .filter(|&&(x, _)| x == v)
+                .map(|&(_, w)| w)
+                .min()
+                .unwrap_or_else(|| panic!("({}, {}) is not an edge of the graph", u, v))
+        })
+        .sum()
+}
+
+/// Return `true` if the given edges form a minimum spanning
+/// forest of the graph. The edges must belong to the graph, form
+/// a spanning tree of every connected component, and for every
+/// other edge `(u, v, w)` of the graph no edge on the forest path
+/// between `u` and `v` may be heavier than `w`.
+///
+/// Path maxima are answered by binary lifting, so the check runs
+/// in O((V + E) log V).
+///
+/// # Arguments
+///
+/// * `graph` - the graph in question;
+/// * `mst_edges` - the candidate forest edges with their weights.
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedGraph;
+/// use ralgo::graph::verify_mst;
+/// let graph = WeightedGraph::new(3, &[(0, 1, 3), (1, 2, 1), (0, 2, 2)]);
+/// assert!(verify_mst(&graph, &[(1, 2, 1), (2, 0, 2)]));
+/// assert!(!verify_mst(&graph, &[(1, 2, 1), (0, 1, 3)]));
+/// ```
+pub fn verify_mst(graph: &WeightedGraph, mst_edges: &[(usize, usize, i64)]) -> bool {
+    let n_vert = graph.n_vert();
+    let mut components = UnionFind::new(n_vert);
+    for &(u, v, _) in graph.edges() {
+        components.union(u, v);
+    }
+    if mst_edges.len() + components.n_components() != n_vert {
+        return false;
+    }
+
+    let mut forest = UnionFind::new(n_vert);
+    let mut adj = vec![Vec::new(); n_vert];
+    for &(u, v, w) in mst_edges {
+        if u >= n_vert
+            || !graph.neighbors_with_weights(u).contains(&(v, w))
+            || forest.connected(u, v)
+        {
+            return false;
+        }
+        forest.union(u, v);
+        adj[u].push((v, w));
+        adj[v].push((u, w));
+    }
+
+    let lifting = PathMax::new(&adj);
+    graph
+        .edges()
+        .iter()
+        .all(|&(u, v, w)| lifting.path_max(u, v) <= w)
+}
+
+/// Binary lifting over a rooted forest storing the heaviest edge
+/// on every jump.
+struct PathMax {
+    depth: Vec<usize>,
+    up: Vec<Vec<usize>>,
+    heaviest: Vec<Vec<i64>>,
+}
+
+impl PathMax {
+    /// Root every tree of the forest given by adjacency lists.
+    fn new(adj: &[Vec<(usize, i64)>]) -> PathMax {
+        let n_vert = adj.len();
+        let mut depth = vec![usize::MAX; n_vert];
+        let mut parent = (0..n_vert).collect::<Vec<_>>();
+        let mut weight = vec![i64::MIN; n_vert];
+        for root in 0..n_vert {
+            if depth[root] != usize::MAX {
+                continue;
+            }
+            depth[root] = 0;
+            let mut stack = vec![root];
+            while let Some(u) = stack.pop() {
+                for &(v, w) in adj[u].iter() {
+                    if depth[v] == usize::MAX {
+                        depth[v] = depth[u] + 1;
+                        parent[v] = u;
+                        weight[v] = w;
+                        stack.push(v);
+                    }
+                }
+            }
+        }
+
+        let mut up = vec![parent];
+        let mut heaviest = vec![weight];
+        while 1 << up.len() < n_vert {
+            let (last_up, last_heaviest) = (up.last().unwrap(), heaviest.last().unwrap());
+            let next_up = (0..n_vert).map(|v| last_up[last_up[v]]).collect();
+            let next_heaviest = (0..n_vert)
+                .map(|v| last_heaviest[v].max(last_heaviest[last_up[v]]))
+                .collect();
+            up.push(next_up);
+            heaviest.push(next_heaviest);
+        }
+        PathMax {
+            depth,
+            up,
+            heaviest,
+        }
+    }
+
+    /// Return the heaviest edge weight on the path between `u` and
+    /// `v`, `i64::MIN` if they coincide, or `i64::MAX` if they are
+    /// in different trees.
+    fn path_max(&self, mut u: usize, mut v: usize) -> i64 {
+        if self.depth[u] < self.depth[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+        let mut result = i64::MIN;
+        let diff = self.depth[u] - self.depth[v];
+        for k in 0..self.up.len() {
+            if diff >> k & 1 == 1 {
+                result = result.max(self.heaviest[k][u]);
+                u = self.up[k][u];
+            }
+        }
+        if u == v {
+            return result;
+        }
+        for k in (0..self.up.len()).rev() {
+            if self.up[k][u] != self.up[k][v] {
+                result = result.max(self.heaviest[k][u]).max(self.heaviest[k][v]);
+                u = self.up[k][u];
+                v = self.up[k][v];
+            }
+        }
+        if self.up[0][u] != self.up[0][v] {
+            return i64::MAX;
+        }
+        result.max(self.heaviest[0][u]).max(self.heaviest[0][v])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_spanning_tree, minimum_spanning_forest, spanning_tree_weight, verify_mst};
+    use graph::random::XorShift;
+    use graph::{StaticGraph, WeightedGraph};
+
+    fn unweighted(edges: &[(usize, usize, i64)]) -> Vec<(usize, usize)> {
+        edges.iter().map(|&(u, v, _)| (u, v)).collect()
+    }
+
+    #[test]
+    fn forest_of_three_components_works() {
+        let edges = [
+            (0, 1, 4),
+            (1, 2, 1),
+            (0, 2, 2),
+            (3, 4, 7),
+            (5, 6, 1),
+            (6, 7, 1),
+            (5, 7, 5),
+            (7, 8, -2),
+            (5, 8, 0),
+        ];
+        let graph = WeightedGraph::new(9, &edges);
+        let forest = minimum_spanning_forest(&graph);
+        assert_eq!(forest.len(), 9 - 3);
+        assert_eq!(forest.iter().map(|&(_, _, w)| w).sum::<i64>(), 3 + 7 - 1);
+        assert_eq!(spanning_tree_weight(&graph, &unweighted(&forest)), 9);
+
+        let single = WeightedGraph::new(1, &[]);
+        assert_eq!(minimum_spanning_forest(&single), vec![]);
+    }
+
+    #[test]
+    fn is_spanning_tree_works() {
+        let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+        assert!(is_spanning_tree(&graph, &[(0, 1), (1, 2), (3, 2)]));
+        assert!(!is_spanning_tree(&graph, &[(0, 1), (1, 2), (2, 0)]));
+        assert!(!is_spanning_tree(&graph, &[(0, 1), (1, 2)]));
+        assert!(!is_spanning_tree(&graph, &[(0, 1), (1, 3), (2, 3)]));
+        assert!(is_spanning_tree(&StaticGraph::new(1, &[]), &[]));
+
+        let disconnected = StaticGraph::new(4, &[(0, 1), (0, 1), (1, 0), (2, 3)]);
+        assert!(!is_spanning_tree(&disconnected, &[(0, 1), (1, 0), (2, 3)]));
+    }
+
+    #[test]
+    fn minimum_spanning_tree_matches_brute_force() {
+        let edges = [
+            (0, 1, 6),
+            (0, 2, 3),
+            (1, 2, 5),
+            (1, 3, 2),
+            (2, 3, 8),
+            (2, 4, 4),
+            (3, 4, 3),
+            (3, 5, 9),
+            (4, 5, 1),
+            (0, 5, 7),
+        ];
+        let graph = WeightedGraph::new(6, &edges);
+        let topology = StaticGraph::new(6, &unweighted(&edges));
+
+        let mut best = i64::MAX;
+        for mask in 0u32..1 << edges.len() {
+            if mask.count_ones() != 5 {
+                continue;
+            }
+            let subset: Vec<(usize, usize)> = (0..edges.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| (edges[i].0, edges[i].1))
+                .collect();
+            if is_spanning_tree(&topology, &subset) {
+                best = best.min(spanning_tree_weight(&graph, &subset));
+            }
+        }
+
+        let mst = unweighted(&minimum_spanning_forest(&graph));
+        assert!(is_spanning_tree(&topology, &mst));
+        assert_eq!(spanning_tree_weight(&graph, &mst), best);
+    }
+
+    #[test]
+    fn verify_mst_works() {
+        let edges = [
+            (0, 1, 4),
+            (1, 2, 1),
+            (0, 2, 2),
+            (2, 3, 5),
+            (3, 4, 7),
+            (5, 6, 1),
+        ];
+        let graph = WeightedGraph::new(7, &edges);
+        let valid = [(1, 2, 1), (0, 2, 2), (2, 3, 5), (3, 4, 7), (6, 5, 1)];
+        assert!(verify_mst(&graph, &valid));
+        // A spanning forest that is not minimal.
+        assert!(!verify_mst(
+            &graph,
+            &[(1, 2, 1), (0, 1, 4), (2, 3, 5), (3, 4, 7), (5, 6, 1)]
+        ));
+        // Missing a component, a cycle, and a wrong weight.
+        assert!(!verify_mst(&graph, &valid[..4]));
+        assert!(!verify_mst(
+            &graph,
+            &[(1, 2, 1), (0, 2, 2), (0, 1, 4), (3, 4, 7), (5, 6, 1)]
+        ));
+        assert!(!verify_mst(
+            &graph,
+            &[(1, 2, 1), (0, 2, 3), (2, 3, 5), (3, 4, 7), (5, 6, 1)]
+        ));
+        assert!(verify_mst(&WeightedGraph::new(0, &[]), &[]));
+        assert!(verify_mst(&WeightedGraph::new(3, &[]), &[]));
+    }
+
+    #[test]
+    fn verify_mst_fails_after_adding_lighter_edge() {
+        let edges = vec![(0, 1, 5), (1, 2, 6), (2, 3, 7)];
+        let tree = edges.clone();
+        assert!(verify_mst(&WeightedGraph::new(4, &edges), &tree));
+        let mut edges = edges;
+        edges.push((0, 3, 6));
+        assert!(!verify_mst(&WeightedGraph::new(4, &edges), &tree));
+    }
+
+    #[test]
+    fn verify_mst_matches_forest_weight() {
+        let mut rng = XorShift::new(61);
+        for _ in 0..200 {
+            let n_vert = 1 + rng.next_below(10);
+            let edges: Vec<(usize, usize, i64)> = (0..rng.next_below(25))
+                .map(|_| {
+                    let w = rng.next_below(6) as i64;
+                    (rng.next_below(n_vert), rng.next_below(n_vert), w)
+                })
+                .collect();
+            let graph = WeightedGraph::new(n_vert, &edges);
+            let weight = |forest: &[(usize, usize, i64)]| forest.iter().map(|e| e.2).sum::<i64>();
+            let best = weight(&minimum_spanning_forest(&graph));
+
+            // Any spanning forest is minimal iff it has the minimum weight.
+            let shuffled = WeightedGraph::new(
+                n_vert,
+                &edges
+                    .iter()
+                    .map(|&(u, v, w)| (u, v, w + rng.next_below(3) as i64 * 10))
+                    .collect::<Vec<_>>(),
+            );
+            let forest: Vec<(usize, usize, i64)> = minimum_spanning_forest(&shuffled)
+                .into_iter()
+                .map(|(u, v, w)| (u, v, w % 10))
+                .collect();
+            assert_eq!(verify_mst(&graph, &forest), weight(&forest) == best);
+        }
+    }
+}
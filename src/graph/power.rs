@@ -0,0 +1,120 @@
+//! This module contains the power of an undirected graph.
+
+use std::collections::VecDeque;
+
+use graph::StaticGraph;
+
+/// Return the `k`-th power of the graph: two distinct vertices are
+/// adjacent in it if and only if their distance in the graph is at
+/// most `k`. The result has no self-loops or parallel edges, so the
+/// first power of a simple graph is the graph itself.
+///
+/// Runs a breadth-first search cut off at depth `k` from every
+/// vertex, in O(V * (V + E)) at worst.
+///
+/// # Panics
+///
+/// If `k` == 0.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::graph_power;
+/// let path = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+/// let square = graph_power(&path, 2);
+/// assert_eq!(square.n_edges(), 5);
+/// assert_eq!(square.neighbors(0), &[1, 2]);
+/// ```
+pub fn graph_power(graph: &StaticGraph, k: usize) -> StaticGraph {
+    assert!(k > 0, "the power of a graph must be positive");
+    let n_vert = graph.n_vert();
+    let mut dist = vec![None; n_vert];
+    let mut queue = VecDeque::new();
+    let mut edges = Vec::new();
+    for source in 0..n_vert {
+        let mut visited = vec![source];
+        dist[source] = Some(0);
+        queue.push_back(source);
+        while let Some(u) = queue.pop_front() {
+            let next = dist[u].unwrap() + 1;
+            if next > k {
+                continue;
+            }
+            for &v in graph.neighbors(u) {
+                if dist[v].is_none() {
+                    dist[v] = Some(next);
+                    visited.push(v);
+                    queue.push_back(v);
+                }
+            }
+        }
+        visited.sort_unstable();
+        for v in visited {
+            dist[v] = None;
+            if source < v {
+                edges.push((source, v));
+            }
+        }
+    }
+    StaticGraph::new(n_vert, &edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::graph_power;
+    use graph::{random_graph_gnm, StaticGraph};
+
+    fn sorted_neighbors(graph: &StaticGraph, vert: usize) -> Vec<usize> {
+        let mut neighbors = graph.neighbors(vert).to_vec();
+        neighbors.sort_unstable();
+        neighbors
+    }
+
+    #[test]
+    fn first_power_is_the_graph() {
+        for seed in 0..10 {
+            let graph = random_graph_gnm(15, 30, seed);
+            let power = graph_power(&graph, 1);
+            assert_eq!(power.n_edges(), graph.n_edges());
+            for v in 0..15 {
+                assert_eq!(sorted_neighbors(&power, v), sorted_neighbors(&graph, v));
+            }
+        }
+    }
+
+    #[test]
+    fn path_and_cycle_powers_work() {
+        let edges: Vec<(usize, usize)> = (1..7).map(|v| (v - 1, v)).collect();
+        let square = graph_power(&StaticGraph::new(7, &edges), 2);
+        assert_eq!(square.n_edges(), 6 + 5);
+        for v in 0..7usize {
+            let expected: Vec<usize> = (v.saturating_sub(2)..(v + 3).min(7))
+                .filter(|&u| u != v)
+                .collect();
+            assert_eq!(sorted_neighbors(&square, v), expected);
+        }
+
+        // Every pair of vertices of C_5 is at distance 1 or 2.
+        let cycle = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+        assert!(graph_power(&cycle, 2).is_complete());
+        assert_eq!(graph_power(&cycle, 2).n_edges(), 10);
+    }
+
+    #[test]
+    fn high_powers_of_connected_graphs_are_complete() {
+        let tree = StaticGraph::new(6, &[(0, 1), (1, 2), (2, 3), (1, 4), (4, 5)]);
+        assert!(!graph_power(&tree, 3).is_complete());
+        assert!(graph_power(&tree, 4).is_complete());
+        assert!(graph_power(&tree, 6).is_complete());
+
+        let disconnected = StaticGraph::new(4, &[(0, 1), (2, 3)]);
+        assert_eq!(graph_power(&disconnected, 4).n_edges(), 2);
+        assert_eq!(graph_power(&StaticGraph::new(0, &[]), 3).n_vert(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zeroth_power_panics() {
+        graph_power(&StaticGraph::new(2, &[(0, 1)]), 0);
+    }
+}
@@ -0,0 +1,136 @@
+//! This module contains the Prüfer sequence of a labeled tree,
+//! which identifies a tree on `n` vertices with a sequence of
+//! `n - 2` vertex labels.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use graph::TreeIndexed;
+
+/// Return the Prüfer sequence of `tree`: repeatedly remove the leaf
+/// with the smallest label and record its neighbor, until two
+/// vertices remain. The root of `tree` plays no role. Trees with
+/// fewer than three vertices give an empty sequence. Runs in
+/// O(n log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{prufer_encode, TreeIndexed};
+/// let tree = TreeIndexed::new(5, 0, &[(0, 3), (1, 3), (3, 4), (4, 2)]);
+/// assert_eq!(prufer_encode(&tree), vec![3, 3, 4]);
+/// ```
+pub fn prufer_encode(tree: &TreeIndexed) -> Vec<usize> {
+    let n = tree.n_vert();
+    // The XOR of the remaining neighbors of a leaf is its only neighbor.
+    let mut degree = vec![0; n];
+    let mut neighbor_xor = vec![0; n];
+    for v in 0..n {
+        if let Some(p) = tree.parent(v) {
+            degree[v] += 1;
+            degree[p] += 1;
+            neighbor_xor[v] ^= p;
+            neighbor_xor[p] ^= v;
+        }
+    }
+    let mut leaves: BinaryHeap<Reverse<usize>> =
+        (0..n).filter(|&v| degree[v] == 1).map(Reverse).collect();
+    let mut sequence = Vec::with_capacity(n.saturating_sub(2));
+    while sequence.len() + 2 < n {
+        let Reverse(leaf) = leaves.pop().unwrap();
+        let next = neighbor_xor[leaf];
+        sequence.push(next);
+        neighbor_xor[next] ^= leaf;
+        degree[next] -= 1;
+        if degree[next] == 1 {
+            leaves.push(Reverse(next));
+        }
+    }
+    sequence
+}
+
+/// Return the tree on `n` vertices with given Prüfer sequence,
+/// rooted at `n - 1`. Runs in O(n log n).
+///
+/// # Panics
+///
+/// If `n < 2`, `sequence.len() != n - 2` or a label is `>= n`.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::prufer_decode;
+/// let tree = prufer_decode(&[3, 3, 4], 5);
+/// assert_eq!(tree.root(), 4);
+/// assert_eq!(tree.children(3), &[0, 1]);
+/// assert_eq!(tree.children(4), &[2, 3]);
+/// ```
+pub fn prufer_decode(sequence: &[usize], n: usize) -> TreeIndexed {
+    assert!(n >= 2, "a Prüfer sequence needs at least two vertices");
+    assert_eq!(sequence.len() + 2, n, "the sequence must have n - 2 labels");
+    let mut degree = vec![1; n];
+    for &v in sequence {
+        assert!(v < n, "label {} is out of range", v);
+        degree[v] += 1;
+    }
+    let mut leaves: BinaryHeap<Reverse<usize>> =
+        (0..n).filter(|&v| degree[v] == 1).map(Reverse).collect();
+    let mut edges = Vec::with_capacity(n - 1);
+    for &next in sequence {
+        let Reverse(leaf) = leaves.pop().unwrap();
+        edges.push((leaf, next));
+        degree[next] -= 1;
+        if degree[next] == 1 {
+            leaves.push(Reverse(next));
+        }
+    }
+    // The largest label is never removed, so it is one of the last two.
+    let Reverse(last) = leaves.pop().unwrap();
+    edges.push((last, n - 1));
+    TreeIndexed::new(n, n - 1, &edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prufer_decode, prufer_encode};
+    use graph::random::XorShift;
+    use graph::TreeIndexed;
+
+    #[test]
+    fn path_and_star_work() {
+        for n in 3..10 {
+            let path: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+            let expected: Vec<usize> = (1..n - 1).collect();
+            assert_eq!(prufer_encode(&TreeIndexed::new(n, 0, &path)), expected);
+
+            let star: Vec<(usize, usize)> = (1..n).map(|v| (0, v)).collect();
+            assert_eq!(
+                prufer_encode(&TreeIndexed::new(n, n - 1, &star)),
+                vec![0; n - 2]
+            );
+        }
+        assert!(prufer_encode(&TreeIndexed::new(1, 0, &[])).is_empty());
+        assert!(prufer_encode(&TreeIndexed::new(2, 0, &[(0, 1)])).is_empty());
+        assert_eq!(prufer_decode(&[], 2).parent(0), Some(1));
+    }
+
+    #[test]
+    fn encode_and_decode_are_inverse() {
+        let mut rng = XorShift::new(97);
+        for n in 2..30 {
+            let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+            let tree = TreeIndexed::new(n, n - 1, &edges);
+            let decoded = prufer_decode(&prufer_encode(&tree), n);
+            for v in 0..n {
+                assert_eq!(decoded.parent(v), tree.parent(v));
+            }
+
+            let sequence: Vec<usize> = (0..n - 2).map(|_| rng.next_below(n)).collect();
+            assert_eq!(prufer_encode(&prufer_decode(&sequence, n)), sequence);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_length_panics() {
+        prufer_decode(&[0, 0], 3);
+    }
+}
@@ -0,0 +1,373 @@
+//! This module contains generators of random graphs.
+//! All generators are deterministic given the seed and
+//! use a small built-in xorshift generator, so the same
+//! seed always yields the same graph.
+
+use std::collections::HashSet;
+
+use graph::StaticGraph;
+
+/// The xorshift64* pseudo-random number generator.
+pub(crate) struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// Return a generator initialized with given seed.
+    pub(crate) fn new(seed: u64) -> XorShift {
+        // The state must never be zero.
+        let state = seed ^ 0x9E37_79B9_7F4A_7C15;
+        XorShift {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    /// Return the next pseudo-random 64-bit integer.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Return a pseudo-random integer in range `0..bound`.
+    pub(crate) fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Return a pseudo-random float in range `[0, 1)`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Return a random graph from the Erdős–Rényi G(n, p) model:
+/// every one of the `n * (n - 1) / 2` possible edges is present
+/// independently with probability `p`.
+///
+/// # Arguments
+///
+/// * `n` - number of vertices;
+/// * `p` - probability of each edge;
+/// * `seed` - seed of the random number generator.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::random_graph_gnp;
+/// let graph = random_graph_gnp(10, 1.0, 42);
+/// assert_eq!(graph.n_edges(), 45);
+/// ```
+pub fn random_graph_gnp(n: usize, p: f64, seed: u64) -> StaticGraph {
+    let mut rng = XorShift::new(seed);
+    let mut edges = Vec::new();
+    for u in 0..n {
+        for v in u + 1..n {
+            if rng.next_f64() < p {
+                edges.push((u, v));
+            }
+        }
+    }
+    StaticGraph::new(n, &edges)
+}
+
+/// Return a random graph from the Erdős–Rényi G(n, m) model:
+/// a set of exactly `m` distinct edges chosen uniformly at random.
+///
+/// # Arguments
+///
+/// * `n` - number of vertices;
+/// * `m` - number of edges;
+/// * `seed` - seed of the random number generator.
+///
+/// # Panics
+///
+/// If `m` > `n * (n - 1) / 2`.
+///
+pub fn random_graph_gnm(n: usize, m: usize, seed: u64) -> StaticGraph {
+    let n_pairs = n * n.saturating_sub(1) / 2;
+    assert!(
+        m <= n_pairs,
+        "a simple graph on {} vertices can not have {} edges",
+        n,
+        m
+    );
+
+    let chosen = sample_distinct(n_pairs, m, &mut XorShift::new(seed));
+
+    // Pair index `k` enumerates pairs `(u, v)`, `u < v`, row by row.
+    let mut edges = Vec::with_capacity(m);
+    let mut row_start = 0;
+    let mut u = 0;
+    for index in chosen {
+        while index >= row_start + (n - 1 - u) {
+            row_start += n - 1 - u;
+            u += 1;
+        }
+        edges.push((u, u + 1 + index - row_start));
+    }
+    StaticGraph::new(n, &edges)
+}
+
+/// Return a random bipartite graph with exactly `m` distinct edges
+/// chosen uniformly at random, each joining a left vertex in
+/// `0..left` with a right vertex in `left..left + right`.
+///
+/// # Arguments
+///
+/// * `left` - number of left vertices;
+/// * `right` - number of right vertices;
+/// * `m` - number of edges;
+/// * `seed` - seed of the random number generator.
+///
+/// # Panics
+///
+/// If `m` > `left * right`.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::random_bipartite_graph;
+/// let graph = random_bipartite_graph(3, 4, 5, 42);
+/// assert_eq!(graph.n_vert(), 7);
+/// assert_eq!(graph.n_edges(), 5);
+/// assert!(graph.neighbors(0).iter().all(|&v| v >= 3));
+/// ```
+pub fn random_bipartite_graph(left: usize, right: usize, m: usize, seed: u64) -> StaticGraph {
+    assert!(
+        m <= left * right,
+        "a bipartite graph with sides {} and {} can not have {} edges",
+        left,
+        right,
+        m
+    );
+    let edges: Vec<(usize, usize)> = sample_distinct(left * right, m, &mut XorShift::new(seed))
+        .into_iter()
+        .map(|index| (index / right, left + index % right))
+        .collect();
+    StaticGraph::new(left + right, &edges)
+}
+
+/// Return a random simple `k`-regular graph on `n` vertices, or
+/// `None` if `n * k` is odd or `n <= k`, which includes the graph
+/// without vertices.
+///
+/// Uses the pairing of Steger and Wormald: `k` points per vertex
+/// are paired one random pair at a time, rejecting pairs that would
+/// create a self-loop or a parallel edge, and the whole pairing is
+/// restarted only if no allowed pair is left. The result is close to
+/// uniform for `k` small relative to `n`. Dense graphs are built as
+/// complements of sparse ones, so that `k <= (n - 1) / 2` always
+/// holds for the pairing. A pairing takes O(n k) expected time, plus
+/// O(r²) whenever it is nearly stuck with `r` points left. For
+/// `n = 100` it gets stuck about 30% of the time at `k = 20` and
+/// about 75% at `k = 49`, so a few restarts are expected; there is
+/// no hard bound on their number.
+///
+/// # Arguments
+///
+/// * `n` - number of vertices;
+/// * `k` - degree of every vertex;
+/// * `seed` - seed of the random number generator.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{is_k_regular, random_regular_graph};
+/// let graph = random_regular_graph(10, 3, 42).unwrap();
+/// assert_eq!(graph.n_edges(), 15);
+/// assert!(is_k_regular(&graph, 3));
+/// assert!(random_regular_graph(5, 3, 42).is_none());
+/// ```
+pub fn random_regular_graph(n: usize, k: usize, seed: u64) -> Option<StaticGraph> {
+    if !(n * k).is_multiple_of(2) || n <= k {
+        return None;
+    }
+    if 2 * k + 1 > n {
+        return random_regular_graph(n, n - 1 - k, seed).map(|graph| graph.complement());
+    }
+
+    let mut rng = XorShift::new(seed);
+    loop {
+        if let Some(edges) = try_regular_pairing(n, k, &mut rng) {
+            return Some(StaticGraph::new(n, &edges));
+        }
+    }
+}
+
+/// Pair up `k` points of each of `n` vertices into a simple graph,
+/// or return `None` if the pairing gets stuck.
+fn try_regular_pairing(n: usize, k: usize, rng: &mut XorShift) -> Option<Vec<(usize, usize)>> {
+    let mut points: Vec<usize> = (0..n).flat_map(|v| std::iter::repeat_n(v, k)).collect();
+    let mut adjacent = HashSet::with_capacity(n * k / 2);
+    let mut edges = Vec::with_capacity(n * k / 2);
+    let allowed = |adjacent: &HashSet<(usize, usize)>, u: usize, v: usize| {
+        u != v && !adjacent.contains(&(u.min(v), u.max(v)))
+    };
+    while !points.is_empty() {
+        // Random pairs are almost always allowed; when they keep
+        // failing, choose among all allowed pairs or give up.
+        let mut pair = None;
+        for _ in 0..32 {
+            let (i, j) = (rng.next_below(points.len()), rng.next_below(points.len()));
+            if allowed(&adjacent, points[i], points[j]) {
+                pair = Some((i, j));
+                break;
+            }
+        }
+        if pair.is_none() {
+            let mut candidates = Vec::new();
+            for i in 0..points.len() {
+                for j in i + 1..points.len() {
+                    if allowed(&adjacent, points[i], points[j]) {
+                        candidates.push((i, j));
+                    }
+                }
+            }
+            if candidates.is_empty() {
+                return None;
+            }
+            pair = Some(candidates[rng.next_below(candidates.len())]);
+        }
+
+        let (i, j) = pair.unwrap();
+        let (u, v) = (points[i], points[j]);
+        adjacent.insert((u.min(v), u.max(v)));
+        edges.push((u, v));
+        points.swap_remove(i.max(j));
+        points.swap_remove(i.min(j));
+    }
+    Some(edges)
+}
+
+/// Return `m` distinct integers from `0..n` in increasing order,
+/// every such set being equally likely. Uses Robert Floyd's
+/// algorithm, which takes O(m log m) time regardless of `n`.
+fn sample_distinct(n: usize, m: usize, rng: &mut XorShift) -> Vec<usize> {
+    let mut chosen = HashSet::with_capacity(m);
+    for j in n - m..n {
+        let index = rng.next_below(j + 1);
+        if !chosen.insert(index) {
+            chosen.insert(j);
+        }
+    }
+    let mut chosen: Vec<usize> = chosen.into_iter().collect();
+    chosen.sort_unstable();
+    chosen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{random_bipartite_graph, random_graph_gnm, random_graph_gnp, random_regular_graph};
+    use graph::{bipartition, is_k_regular};
+
+    #[test]
+    fn gnp_extremes_work() {
+        let graph = random_graph_gnp(20, 0.0, 1);
+        assert_eq!(graph.n_vert(), 20);
+        assert_eq!(graph.n_edges(), 0);
+
+        let graph = random_graph_gnp(20, 1.0, 1);
+        assert_eq!(graph.n_edges(), 190);
+        assert!(graph.is_complete());
+    }
+
+    #[test]
+    fn gnp_edge_count_is_close_to_expected() {
+        let n = 200;
+        let graph = random_graph_gnp(n, 0.5, 7);
+        let expected = (n * (n - 1) / 4) as f64;
+        let actual = graph.n_edges() as f64;
+        assert!((actual - expected).abs() < 0.05 * expected);
+    }
+
+    #[test]
+    fn same_seed_gives_same_graph() {
+        let first = random_graph_gnp(30, 0.3, 123);
+        let second = random_graph_gnp(30, 0.3, 123);
+        assert_eq!(first.n_edges(), second.n_edges());
+        for v in 0..30 {
+            assert_eq!(first.neighbors(v), second.neighbors(v));
+        }
+
+        let first = random_graph_gnm(30, 100, 123);
+        let second = random_graph_gnm(30, 100, 123);
+        for v in 0..30 {
+            assert_eq!(first.neighbors(v), second.neighbors(v));
+        }
+    }
+
+    #[test]
+    fn gnm_has_exact_number_of_distinct_edges() {
+        for &(n, m) in &[(0, 0), (1, 0), (10, 0), (10, 17), (10, 45), (50, 600)] {
+            let graph = random_graph_gnm(n, m, 5);
+            assert_eq!(graph.n_vert(), n);
+            assert_eq!(graph.n_edges(), m);
+            for v in 0..n {
+                let mut neighbors = graph.neighbors(v).to_vec();
+                neighbors.sort();
+                neighbors.dedup();
+                assert_eq!(neighbors.len(), graph.neighbors(v).len());
+                assert!(!neighbors.contains(&v));
+            }
+        }
+    }
+
+    #[test]
+    fn regular_graphs_are_simple_and_regular() {
+        for &(n, k) in &[
+            (1, 0),
+            (6, 0),
+            (4, 1),
+            (10, 3),
+            (9, 4),
+            (20, 5),
+            (8, 6),
+            (12, 9),
+            (100, 20),
+            (100, 79),
+        ] {
+            for seed in 0..5 {
+                let graph = random_regular_graph(n, k, seed).unwrap();
+                assert_eq!(graph.n_vert(), n);
+                assert_eq!(graph.n_edges(), n * k / 2);
+                assert!(is_k_regular(&graph, k));
+                for v in 0..n {
+                    let mut neighbors = graph.neighbors(v).to_vec();
+                    neighbors.sort();
+                    neighbors.dedup();
+                    assert_eq!(neighbors.len(), k);
+                    assert!(!neighbors.contains(&v));
+                }
+            }
+        }
+        assert!(random_regular_graph(5, 4, 1).unwrap().is_complete());
+    }
+
+    #[test]
+    fn impossible_regular_graphs_are_none() {
+        assert!(random_regular_graph(5, 3, 1).is_none());
+        assert!(random_regular_graph(4, 4, 1).is_none());
+        assert!(random_regular_graph(3, 5, 1).is_none());
+        assert!(random_regular_graph(0, 1, 1).is_none());
+        assert!(random_regular_graph(0, 0, 1).is_none());
+    }
+
+    #[test]
+    fn bipartite_graphs_have_exact_distinct_edges() {
+        for &(left, right, m) in &[(0, 0, 0), (0, 5, 0), (3, 3, 9), (5, 8, 17), (20, 30, 250)] {
+            for seed in 0..3 {
+                let graph = random_bipartite_graph(left, right, m, seed);
+                assert_eq!(graph.n_vert(), left + right);
+                assert_eq!(graph.n_edges(), m);
+                assert!(bipartition(&graph).is_ok());
+                for u in 0..left {
+                    let mut neighbors = graph.neighbors(u).to_vec();
+                    assert!(neighbors.iter().all(|&v| v >= left));
+                    neighbors.sort();
+                    neighbors.dedup();
+                    assert_eq!(neighbors.len(), graph.neighbors(u).len());
+                }
+            }
+        }
+        let full = random_bipartite_graph(3, 3, 9, 1);
+        assert!((0..3).all(|u| full.neighbors(u) == [3, 4, 5]));
+    }
+}
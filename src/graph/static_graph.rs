@@ -2,6 +2,8 @@
 //! are indexed with integers 0, 1, ..., N-1. This graph can not
 //! be modified once created.
 
+use graph::GraphIndexed;
+
 /// The integer-indexed static graph data structure.
 ///
 /// # Examples
@@ -16,13 +18,18 @@
 /// assert_eq!(graph.neighbors(3), &[2]);
 /// assert_eq!(graph.neighbors(4), &[]);
 /// ```
+#[derive(Clone)]
 pub struct StaticGraph {
     offset: Vec<usize>,
     neigh: Vec<usize>,
+    n_edges: usize,
+    single_loops: bool,
 }
 
 impl StaticGraph {
-    /// Return a new instance of StaticGraph.
+    /// Return a new instance of StaticGraph. A self-loop `(v, v)`
+    /// puts `v` into its own neighbor list twice, so it adds 2 to
+    /// the degree of `v`.
     ///
     /// # Arguments
     ///
@@ -34,39 +41,118 @@ impl StaticGraph {
     /// If `edges` contains an element >= `n_vert`.
     ///
     pub fn new(n_vert: usize, edges: &[(usize, usize)]) -> StaticGraph {
-        let mut offset = vec![0 as usize; n_vert];
+        StaticGraph::build(n_vert, edges, false)
+    }
+
+    /// Return a new instance of StaticGraph where a self-loop
+    /// `(v, v)` puts `v` into its own neighbor list only once.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices;
+    /// * `edges` - pairs of adjacent vertices.
+    ///
+    /// # Panics
+    ///
+    /// If `edges` contains an element >= `n_vert`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::StaticGraph;
+    /// let graph = StaticGraph::new_with_loops(2, &[(0, 0), (0, 1)]);
+    /// assert_eq!(graph.n_edges(), 2);
+    /// assert_eq!(graph.neighbors(0), &[0, 1]);
+    /// ```
+    pub fn new_with_loops(n_vert: usize, edges: &[(usize, usize)]) -> StaticGraph {
+        StaticGraph::build(n_vert, edges, true)
+    }
+
+    /// Return a new instance of StaticGraph whose neighbor lists
+    /// are sorted, so the graph does not depend on the order of
+    /// `edges` or on the order of vertices within an edge. Every
+    /// edge is normalized to `(min, max)` and the edges are sorted
+    /// before building. Self-loops are stored twice, as in `new`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices;
+    /// * `edges` - pairs of adjacent vertices.
+    ///
+    /// # Panics
+    ///
+    /// If `edges` contains an element >= `n_vert`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::StaticGraph;
+    /// let graph = StaticGraph::new_sorted(4, &[(3, 1), (1, 0), (2, 1)]);
+    /// assert_eq!(graph.neighbors(1), &[0, 2, 3]);
+    /// ```
+    pub fn new_sorted(n_vert: usize, edges: &[(usize, usize)]) -> StaticGraph {
+        let mut edges: Vec<(usize, usize)> =
+            edges.iter().map(|&(u, v)| (u.min(v), u.max(v))).collect();
+        edges.sort_unstable();
+        StaticGraph::build(n_vert, &edges, false)
+    }
+
+    /// Return a copy of `graph` as a StaticGraph.
+    pub fn from_graph_indexed(graph: &GraphIndexed) -> StaticGraph {
+        graph.clone().into()
+    }
+
+    /// Build the graph storing every self-loop once or twice.
+    fn build(n_vert: usize, edges: &[(usize, usize)], single_loops: bool) -> StaticGraph {
+        let stored_twice = |u: usize, v: usize| u != v || !single_loops;
+        let mut offset = vec![0; n_vert + 1];
         for &(u, v) in edges {
-            if u < n_vert - 1 {
-                offset[u + 1] += 1;
-            }
-            if v < n_vert - 1 {
+            assert!(
+                u < n_vert && v < n_vert,
+                "edge ({}, {}) is out of range for a graph with {} vertices",
+                u,
+                v,
+                n_vert
+            );
+            offset[u + 1] += 1;
+            if stored_twice(u, v) {
                 offset[v + 1] += 1;
             }
         }
-        for i in 2..n_vert {
+        for i in 1..=n_vert {
             offset[i] += offset[i - 1];
         }
 
         let mut pos = offset.clone();
-        let mut neigh = vec![0; 2 * edges.len()];
-        for (u, v) in edges.iter() {
-            neigh[pos[*u]] = *v;
-            pos[*u] += 1;
-            neigh[pos[*v]] = *u;
-            pos[*v] += 1;
+        let mut neigh = vec![0; offset[n_vert]];
+        for &(u, v) in edges {
+            neigh[pos[u]] = v;
+            pos[u] += 1;
+            if stored_twice(u, v) {
+                neigh[pos[v]] = u;
+                pos[v] += 1;
+            }
         }
 
-        StaticGraph { offset, neigh }
+        StaticGraph {
+            offset,
+            neigh,
+            n_edges: edges.len(),
+            single_loops,
+        }
     }
 
     /// Return the number of vertices in given Graph instance.
     pub fn n_vert(&self) -> usize {
-        self.offset.len()
+        self.offset.len() - 1
+    }
+
+    /// Return `true` if given Graph instance has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.n_vert() == 0
     }
 
     /// Return the number of edges in given Graph instance.
     pub fn n_edges(&self) -> usize {
-        self.neigh.len() / 2
+        self.n_edges
     }
 
     /// Return the slice of neighboring vertices to the given vertex.
@@ -80,11 +166,233 @@ impl StaticGraph {
     /// If `vert` >= `self.n_vert()`.
     ///
     pub fn neighbors(&self, vert: usize) -> &[usize] {
-        if vert < self.offset.len() - 1 {
-            &self.neigh[self.offset[vert]..self.offset[vert + 1]]
-        } else {
-            &self.neigh[self.offset[vert]..]
+        &self.neigh[self.offset[vert]..self.offset[vert + 1]]
+    }
+
+    /// Return the subgraph induced by the given set of vertices
+    /// together with the mapping from old vertex indices to new ones.
+    /// Vertices are renumbered in the order they appear in `vertices`;
+    /// excluded vertices are mapped to `usize::MAX`.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - the vertices to keep (duplicates are ignored).
+    ///
+    /// # Panics
+    ///
+    /// If `vertices` contains an element >= `self.n_vert()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::StaticGraph;
+    /// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+    /// let (sub, mapping) = graph.subgraph(&[3, 2, 0]);
+    /// assert_eq!(sub.n_vert(), 3);
+    /// assert_eq!(sub.n_edges(), 1);
+    /// assert_eq!(mapping, vec![2, usize::MAX, 1, 0]);
+    /// ```
+    pub fn subgraph(&self, vertices: &[usize]) -> (StaticGraph, Vec<usize>) {
+        let mut mapping = vec![usize::MAX; self.n_vert()];
+        let mut n_vert = 0;
+        for &vert in vertices {
+            if mapping[vert] == usize::MAX {
+                mapping[vert] = n_vert;
+                n_vert += 1;
+            }
+        }
+
+        let edges: Vec<(usize, usize)> = self
+            .edges()
+            .into_iter()
+            .filter(|&(u, v)| mapping[u] != usize::MAX && mapping[v] != usize::MAX)
+            .map(|(u, v)| (mapping[u], mapping[v]))
+            .collect();
+
+        (
+            StaticGraph::build(n_vert, &edges, self.single_loops),
+            mapping,
+        )
+    }
+
+    /// Return the subgraph consisting of the given edges and
+    /// the vertices they touch. Vertices are renumbered
+    /// preserving their relative order.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - the edges to keep.
+    ///
+    /// # Panics
+    ///
+    /// If any of `edges` is not an edge of given Graph instance.
+    ///
+    pub fn edge_subgraph(&self, edges: &[(usize, usize)]) -> StaticGraph {
+        let mut mapping = vec![usize::MAX; self.n_vert()];
+        for &(u, v) in edges {
+            assert!(
+                u < self.n_vert() && self.neighbors(u).contains(&v),
+                "({}, {}) is not an edge of the graph",
+                u,
+                v
+            );
+            mapping[u] = 0;
+            mapping[v] = 0;
         }
+
+        let mut n_vert = 0;
+        for index in mapping.iter_mut() {
+            if *index != usize::MAX {
+                *index = n_vert;
+                n_vert += 1;
+            }
+        }
+
+        let edges: Vec<(usize, usize)> = edges
+            .iter()
+            .map(|&(u, v)| (mapping[u], mapping[v]))
+            .collect();
+        StaticGraph::build(n_vert, &edges, self.single_loops)
+    }
+
+    /// Return the complement graph: two distinct vertices are adjacent
+    /// in it iff they are not adjacent in given Graph instance.
+    /// Self-loops and parallel edges are ignored.
+    ///
+    /// Takes O(n²) time and memory, so it is only practical
+    /// for small graphs.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::StaticGraph;
+    /// let graph = StaticGraph::new(3, &[(0, 1)]);
+    /// let complement = graph.complement();
+    /// assert_eq!(complement.n_edges(), 2);
+    /// assert_eq!(complement.neighbors(2).len(), 2);
+    /// ```
+    pub fn complement(&self) -> StaticGraph {
+        let n_vert = self.n_vert();
+        let mut edges = Vec::new();
+        let mut adjacent = vec![false; n_vert];
+        for u in 0..n_vert {
+            for &v in self.neighbors(u) {
+                adjacent[v] = true;
+            }
+            for (v, is_adjacent) in adjacent.iter().enumerate().skip(u + 1) {
+                if !is_adjacent {
+                    edges.push((u, v));
+                }
+            }
+            for &v in self.neighbors(u) {
+                adjacent[v] = false;
+            }
+        }
+        StaticGraph::new(n_vert, &edges)
+    }
+
+    /// Return `true` if every two distinct vertices are adjacent,
+    /// `false` otherwise. Takes O(n²) time in the worst case.
+    pub fn is_complete(&self) -> bool {
+        let n_vert = self.n_vert();
+        let mut adjacent = vec![false; n_vert];
+        for u in 0..n_vert {
+            let mut count = 0;
+            for &v in self.neighbors(u) {
+                if v != u && !adjacent[v] {
+                    adjacent[v] = true;
+                    count += 1;
+                }
+            }
+            for &v in self.neighbors(u) {
+                adjacent[v] = false;
+            }
+            if count != n_vert - 1 {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Return the density of the graph: the number of edges divided
+    /// by the number of vertex pairs, `2 m / (n (n - 1))`. It is 1 for
+    /// a complete graph, and 0.0 for a graph with fewer than two
+    /// vertices. Parallel edges and self-loops may push it above 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::StaticGraph;
+    /// let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+    /// assert_eq!(graph.density(), 0.5);
+    /// ```
+    pub fn density(&self) -> f64 {
+        let n_vert = self.n_vert();
+        if n_vert < 2 {
+            return 0.0;
+        }
+        2.0 * self.n_edges() as f64 / (n_vert * (n_vert - 1)) as f64
+    }
+
+    /// Return the largest vertex degree, or 0 for a graph without
+    /// vertices. The degree of a vertex is the length of its
+    /// neighbor list.
+    pub fn max_degree(&self) -> usize {
+        self.degrees().max().unwrap_or(0)
+    }
+
+    /// Return the smallest vertex degree, or 0 for a graph without
+    /// vertices.
+    pub fn min_degree(&self) -> usize {
+        self.degrees().min().unwrap_or(0)
+    }
+
+    /// Return the mean vertex degree, or 0.0 for a graph without
+    /// vertices.
+    pub fn average_degree(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        self.neigh.len() as f64 / self.n_vert() as f64
+    }
+
+    /// Return the degree histogram: `histogram[d]` is the number of
+    /// vertices of degree `d`, for `d` up to `self.max_degree()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::StaticGraph;
+    /// let star = StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3)]);
+    /// assert_eq!(star.degree_histogram(), vec![0, 3, 0, 1]);
+    /// ```
+    pub fn degree_histogram(&self) -> Vec<usize> {
+        let mut histogram = vec![0; self.max_degree() + 1];
+        for degree in self.degrees() {
+            histogram[degree] += 1;
+        }
+        histogram
+    }
+
+    /// Return an iterator over the degrees of all vertices.
+    fn degrees(&self) -> impl Iterator<Item = usize> + '_ {
+        self.offset.windows(2).map(|w| w[1] - w[0])
+    }
+
+    /// Return every edge of the graph exactly once as a pair
+    /// `(u, v)` with `u <= v`.
+    pub(crate) fn edges(&self) -> Vec<(usize, usize)> {
+        let mut edges = Vec::with_capacity(self.n_edges());
+        for u in 0..self.n_vert() {
+            let mut loops = 0;
+            for &v in self.neighbors(u) {
+                if u < v {
+                    edges.push((u, v));
+                } else if u == v {
+                    loops += 1;
+                    if self.single_loops || loops % 2 == 0 {
+                        edges.push((u, v));
+                    }
+                }
+            }
+        }
+        edges
     }
 }
 
@@ -118,6 +426,73 @@ mod tests {
         assert_eq!(graph.n_edges(), 0);
     }
 
+    #[test]
+    fn self_loops_work() {
+        let graph = ::StaticGraph::new_with_loops(3, &[(1, 1), (0, 1)]);
+        assert_eq!(graph.n_edges(), 2);
+        assert!(vertices_equal(graph.neighbors(1), &[0, 1]));
+        assert_eq!(graph.neighbors(1).len(), 2);
+        assert_eq!(graph.neighbors(2), &[]);
+
+        let doubled = ::StaticGraph::new(3, &[(1, 1), (0, 1)]);
+        assert_eq!(doubled.n_edges(), 2);
+        assert_eq!(doubled.neighbors(1).len(), 3);
+
+        let (sub, _) = graph.subgraph(&[1, 2]);
+        assert_eq!(sub.n_edges(), 1);
+        assert_eq!(sub.neighbors(0), &[0]);
+        let sub = doubled.edge_subgraph(&[(1, 1)]);
+        assert_eq!(sub.n_edges(), 1);
+        assert_eq!(sub.neighbors(0), &[0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "edge (5, 2) is out of range for a graph with 3 vertices")]
+    fn out_of_range_edge_panics() {
+        ::StaticGraph::new(3, &[(0, 1), (5, 2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "edge (0, 3) is out of range for a graph with 3 vertices")]
+    fn out_of_range_loop_graph_edge_panics() {
+        ::StaticGraph::new_with_loops(3, &[(0, 3)]);
+    }
+
+    #[test]
+    fn largest_vertex_index_is_accepted() {
+        let graph = ::StaticGraph::new(3, &[(2, 2), (0, 2)]);
+        assert_eq!(graph.n_edges(), 2);
+        assert!(vertices_equal(graph.neighbors(2), &[0, 2]));
+    }
+
+    #[test]
+    fn zero_vertex_graph_works() {
+        let graph = ::StaticGraph::new(0, &[]);
+        assert_eq!(graph.n_vert(), 0);
+        assert_eq!(graph.n_edges(), 0);
+        assert!(graph.is_empty());
+        assert!(!::StaticGraph::new(1, &[]).is_empty());
+        assert!(::StaticGraph::new_with_loops(0, &[]).is_empty());
+        assert_eq!(::StaticGraph::new_sorted(0, &[]).n_edges(), 0);
+        let indexed = ::graph::GraphIndexed::from_static_graph(&graph);
+        assert!(indexed.is_empty());
+        assert!(::StaticGraph::from(indexed).is_empty());
+        assert!(graph.is_complete());
+        assert!(graph.complement().is_empty());
+        assert!(graph.edge_subgraph(&[]).is_empty());
+        let (sub, mapping) = graph.subgraph(&[]);
+        assert!(sub.is_empty());
+        assert!(mapping.is_empty());
+        assert!(::graph::eccentricity(&graph).is_empty());
+        assert_eq!(::graph::greedy_coloring(&graph), vec![]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_vertex_graph_has_no_neighbors() {
+        ::StaticGraph::new(0, &[]).neighbors(0);
+    }
+
     #[test]
     fn full_graph_works() {
         let graph = ::StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
@@ -128,4 +503,169 @@ mod tests {
         assert!(vertices_equal(graph.neighbors(2), &[0, 1, 3]));
         assert!(vertices_equal(graph.neighbors(3), &[0, 1, 2]));
     }
+
+    fn complete_graph(n_vert: usize) -> ::StaticGraph {
+        let mut edges = Vec::new();
+        for u in 0..n_vert {
+            for v in u + 1..n_vert {
+                edges.push((u, v));
+            }
+        }
+        ::StaticGraph::new(n_vert, &edges)
+    }
+
+    #[test]
+    fn subgraph_of_complete_graph_is_complete() {
+        let graph = complete_graph(6);
+        let (sub, mapping) = graph.subgraph(&[1, 3, 4, 5]);
+        assert_eq!(sub.n_vert(), 4);
+        assert_eq!(sub.n_edges(), 6);
+        for v in 0..4 {
+            assert_eq!(sub.neighbors(v).len(), 3);
+        }
+        assert_eq!(mapping[0], usize::MAX);
+        assert_eq!(mapping[2], usize::MAX);
+        assert_eq!(mapping[4], 2);
+    }
+
+    #[test]
+    fn subgraph_of_path_on_alternating_vertices_is_independent() {
+        let graph = ::StaticGraph::new(7, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+        let (sub, _) = graph.subgraph(&[0, 2, 4, 6]);
+        assert_eq!(sub.n_vert(), 4);
+        assert_eq!(sub.n_edges(), 0);
+    }
+
+    #[test]
+    fn subgraph_on_all_vertices_round_trips() {
+        let graph = ::StaticGraph::new(5, &[(0, 1), (2, 1), (3, 1), (3, 4), (4, 0)]);
+        let all: Vec<usize> = (0..5).collect();
+        let (sub, mapping) = graph.subgraph(&all);
+        assert_eq!(mapping, all);
+        assert_eq!(sub.n_vert(), graph.n_vert());
+        assert_eq!(sub.n_edges(), graph.n_edges());
+        for v in 0..5 {
+            assert!(vertices_equal(sub.neighbors(v), graph.neighbors(v)));
+        }
+    }
+
+    #[test]
+    fn edge_subgraph_works() {
+        let graph = complete_graph(5);
+        let sub = graph.edge_subgraph(&[(1, 3), (4, 3)]);
+        assert_eq!(sub.n_vert(), 3);
+        assert_eq!(sub.n_edges(), 2);
+        assert!(vertices_equal(sub.neighbors(0), &[1]));
+        assert!(vertices_equal(sub.neighbors(1), &[0, 2]));
+        assert!(vertices_equal(sub.neighbors(2), &[1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn edge_subgraph_panics_on_missing_edge() {
+        let graph = ::StaticGraph::new(3, &[(0, 1)]);
+        graph.edge_subgraph(&[(1, 2)]);
+    }
+
+    #[test]
+    fn complement_of_complete_graph_is_empty() {
+        let complement = complete_graph(5).complement();
+        assert_eq!(complement.n_vert(), 5);
+        assert_eq!(complement.n_edges(), 0);
+    }
+
+    #[test]
+    fn complement_of_empty_graph_is_complete() {
+        let complement = ::StaticGraph::new(5, &[]).complement();
+        assert_eq!(complement.n_edges(), 10);
+        assert!(complement.is_complete());
+    }
+
+    #[test]
+    fn complement_of_path_works() {
+        let graph = ::StaticGraph::new(4, &[(0, 1), (1, 2), (2, 3)]);
+        let complement = graph.complement();
+        assert_eq!(complement.n_edges(), 3);
+        assert!(vertices_equal(complement.neighbors(0), &[2, 3]));
+        assert!(vertices_equal(complement.neighbors(1), &[3]));
+        assert!(vertices_equal(complement.neighbors(2), &[0]));
+        assert!(vertices_equal(complement.neighbors(3), &[0, 1]));
+    }
+
+    #[test]
+    fn is_complete_works() {
+        let graph = ::StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        assert!(graph.is_complete());
+        assert!(!::StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3)]).is_complete());
+        assert!(!::StaticGraph::new(3, &[(0, 1), (0, 1), (1, 2)]).is_complete());
+    }
+
+    #[test]
+    fn new_sorted_does_not_depend_on_edge_order() {
+        let edges = [(3, 1), (0, 2), (2, 2), (1, 0), (4, 3), (1, 3), (2, 0)];
+        let graph = ::StaticGraph::new(5, &edges);
+        let sorted = ::StaticGraph::new_sorted(5, &edges);
+        let mut flipped: Vec<(usize, usize)> = edges.iter().rev().map(|&(u, v)| (v, u)).collect();
+        flipped.swap(0, 3);
+        let reordered = ::StaticGraph::new_sorted(5, &flipped);
+        assert_eq!(sorted.n_edges(), edges.len());
+        for v in 0..5 {
+            let mut expected = graph.neighbors(v).to_vec();
+            expected.sort();
+            assert_eq!(sorted.neighbors(v), &expected[..]);
+            assert_eq!(reordered.neighbors(v), sorted.neighbors(v));
+        }
+        assert_eq!(sorted.neighbors(2), &[0, 0, 2, 2]);
+    }
+
+    #[test]
+    fn new_sorted_neighbors_are_sorted() {
+        let mut rng = ::graph::random::XorShift::new(163);
+        let edges: Vec<(usize, usize)> = (0..200)
+            .map(|_| (rng.next_below(30), rng.next_below(30)))
+            .collect();
+        let graph = ::StaticGraph::new_sorted(30, &edges);
+        for v in 0..30 {
+            assert!(graph.neighbors(v).windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
+
+    #[test]
+    fn density_works() {
+        assert_eq!(::StaticGraph::new(0, &[]).density(), 0.0);
+        assert_eq!(::StaticGraph::new(1, &[]).density(), 0.0);
+        assert_eq!(::StaticGraph::new(5, &[]).density(), 0.0);
+        let edges: Vec<(usize, usize)> = (0..6)
+            .flat_map(|u| (u + 1..6).map(move |v| (u, v)))
+            .collect();
+        assert_eq!(::StaticGraph::new(6, &edges).density(), 1.0);
+    }
+
+    #[test]
+    fn star_degrees_work() {
+        let n = 7;
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (0, v)).collect();
+        let star = ::StaticGraph::new(n, &edges);
+        assert_eq!(star.max_degree(), n - 1);
+        assert_eq!(star.min_degree(), 1);
+        assert_eq!(star.average_degree(), 2.0 * (n - 1) as f64 / n as f64);
+        assert_eq!(star.degree_histogram(), vec![0, 6, 0, 0, 0, 0, 1]);
+
+        let empty = ::StaticGraph::new(0, &[]);
+        assert_eq!(empty.max_degree(), 0);
+        assert_eq!(empty.min_degree(), 0);
+        assert_eq!(empty.average_degree(), 0.0);
+        assert_eq!(empty.degree_histogram(), vec![0]);
+    }
+
+    #[test]
+    fn regular_graph_average_degree_works() {
+        for &(n, k) in [(10, 3), (12, 5), (9, 0), (7, 6)].iter() {
+            let graph = ::graph::random_regular_graph(n, k, 5).unwrap();
+            assert_eq!(graph.average_degree(), k as f64);
+            assert_eq!(graph.min_degree(), k);
+            assert_eq!(graph.max_degree(), k);
+            assert_eq!(graph.degree_histogram()[k], n);
+        }
+    }
 }
@@ -0,0 +1,153 @@
+//! This module contains sums and maximums of vertex values over
+//! subtrees under point updates of the values.
+
+use data_structures::{Fenwick, LazyMaxSegTree};
+use graph::TreeIndexed;
+
+/// Subtree queries over the vertex values of a rooted tree. In the
+/// depth-first preorder every subtree occupies a contiguous range
+/// `[entry(v), exit(v)]`, so a subtree query is a range query over
+/// the values laid out in that order: a Fenwick tree answers sums
+/// and a segment tree answers maximums, both in O(log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{SubtreeQueries, TreeIndexed};
+/// let tree = TreeIndexed::new(5, 0, &[(0, 1), (0, 2), (2, 3), (2, 4)]);
+/// let mut queries = SubtreeQueries::new(&tree, &[1, 2, 3, 4, 5]);
+/// assert_eq!(queries.subtree_sum(2), 12);
+/// assert_eq!(queries.subtree_max(0), 5);
+/// queries.update_vertex(3, 10);
+/// assert_eq!(queries.subtree_sum(0), 21);
+/// assert_eq!(queries.subtree_max(2), 10);
+/// ```
+pub struct SubtreeQueries {
+    entry: Vec<usize>,
+    exit: Vec<usize>,
+    value: Vec<i64>,
+    sums: Fenwick,
+    maxima: LazyMaxSegTree,
+}
+
+impl SubtreeQueries {
+    /// Return the queries over `tree` with `vertex_values[v]`
+    /// attached to every vertex `v`.
+    ///
+    /// # Panics
+    ///
+    /// If `vertex_values.len() != tree.n_vert()`.
+    ///
+    pub fn new(tree: &TreeIndexed, vertex_values: &[i64]) -> SubtreeQueries {
+        let n = tree.n_vert();
+        assert_eq!(vertex_values.len(), n, "expected a value for every vertex");
+        let preorder = tree.preorder();
+        let mut entry = vec![0; n];
+        for (i, &v) in preorder.iter().enumerate() {
+            entry[v] = i;
+        }
+        let mut size = vec![1; n];
+        for &v in preorder.iter().rev() {
+            if let Some(p) = tree.parent(v) {
+                size[p] += size[v];
+            }
+        }
+        let exit = (0..n).map(|v| entry[v] + size[v] - 1).collect();
+
+        let by_entry: Vec<i64> = preorder.iter().map(|&v| vertex_values[v]).collect();
+        let mut sums = Fenwick::new(n);
+        for (i, &x) in by_entry.iter().enumerate() {
+            sums.add(i, x);
+        }
+        SubtreeQueries {
+            entry,
+            exit,
+            value: vertex_values.to_vec(),
+            sums,
+            maxima: LazyMaxSegTree::new(&by_entry),
+        }
+    }
+
+    /// Return the sum of the values in the subtree of `root`.
+    pub fn subtree_sum(&self, root: usize) -> i64 {
+        self.sums.range_sum(self.entry[root], self.exit[root])
+    }
+
+    /// Return the largest value in the subtree of `root`.
+    pub fn subtree_max(&self, root: usize) -> i64 {
+        self.maxima.range_max(self.entry[root], self.exit[root])
+    }
+
+    /// Set the value of `vert` to `new_val`.
+    pub fn update_vertex(&mut self, vert: usize, new_val: i64) {
+        let delta = new_val - self.value[vert];
+        let pos = self.entry[vert];
+        self.sums.add(pos, delta);
+        self.maxima.range_add(pos, pos, delta);
+        self.value[vert] = new_val;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubtreeQueries;
+    use graph::random::XorShift;
+    use graph::TreeIndexed;
+
+    #[test]
+    fn whole_tree_and_leaves_work() {
+        let tree = TreeIndexed::new(6, 3, &[(3, 0), (3, 1), (1, 2), (1, 4), (4, 5)]);
+        let values = [7, -2, 4, 1, 9, -6];
+        let queries = SubtreeQueries::new(&tree, &values);
+        assert_eq!(queries.subtree_sum(3), values.iter().sum::<i64>());
+        assert_eq!(queries.subtree_max(3), 9);
+        for &leaf in [0, 2, 5].iter() {
+            assert_eq!(queries.subtree_sum(leaf), values[leaf]);
+            assert_eq!(queries.subtree_max(leaf), values[leaf]);
+        }
+    }
+
+    #[test]
+    fn leaf_update_propagates_up() {
+        let tree = TreeIndexed::new(4, 0, &[(0, 1), (1, 2), (2, 3)]);
+        let mut queries = SubtreeQueries::new(&tree, &[1, 1, 1, 1]);
+        queries.update_vertex(3, 100);
+        let sums: Vec<i64> = (0..4).map(|v| queries.subtree_sum(v)).collect();
+        assert_eq!(sums, vec![103, 102, 101, 100]);
+        queries.update_vertex(3, -5);
+        assert_eq!(queries.subtree_max(1), 1);
+        assert_eq!(queries.subtree_sum(0), -2);
+    }
+
+    #[test]
+    fn random_updates_match_naive() {
+        let mut rng = XorShift::new(47);
+        let n = 60;
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+        let tree = TreeIndexed::new(n, 0, &edges);
+        let mut values: Vec<i64> = (0..n).map(|_| rng.next_below(200) as i64 - 100).collect();
+        let mut queries = SubtreeQueries::new(&tree, &values);
+        for _ in 0..500 {
+            let v = rng.next_below(n);
+            values[v] = rng.next_below(200) as i64 - 100;
+            queries.update_vertex(v, values[v]);
+
+            let root = rng.next_below(n);
+            let subtree: Vec<i64> = (0..n)
+                .filter(|&x| {
+                    let mut x = Some(x);
+                    while let Some(y) = x {
+                        if y == root {
+                            return true;
+                        }
+                        x = tree.parent(y);
+                    }
+                    false
+                })
+                .map(|x| values[x])
+                .collect();
+            assert_eq!(queries.subtree_sum(root), subtree.iter().sum::<i64>());
+            assert_eq!(queries.subtree_max(root), *subtree.iter().max().unwrap());
+        }
+        assert_eq!(queries.subtree_sum(0), values.iter().sum::<i64>());
+    }
+}
@@ -0,0 +1,331 @@
+//! This module defines a static rooted tree whose vertices are
+//! indexed with integers 0, 1, ..., N-1. This tree can not be
+//! modified once created.
+
+use std::error::Error;
+use std::fmt;
+
+use graph::StaticGraph;
+
+/// The error returned when given edges or parent links do not
+/// form a tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotATree;
+
+impl fmt::Display for NotATree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the graph is not a tree")
+    }
+}
+
+impl Error for NotATree {}
+
+/// The integer-indexed static rooted tree. The children of every
+/// vertex are kept in increasing order.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::TreeIndexed;
+/// let tree = TreeIndexed::new(4, 1, &[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(tree.root(), 1);
+/// assert_eq!(tree.parent(3), Some(2));
+/// assert_eq!(tree.parent(1), None);
+/// assert_eq!(tree.children(1), &[0, 2]);
+/// assert_eq!(tree.depth(3), 2);
+/// ```
+pub struct TreeIndexed {
+    root: usize,
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    offset: Vec<usize>,
+    children: Vec<usize>,
+}
+
+impl TreeIndexed {
+    /// Return the tree with given edges rooted at `root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices;
+    /// * `root` - the root of the tree;
+    /// * `edges` - pairs of adjacent vertices.
+    ///
+    /// # Panics
+    ///
+    /// If `try_new` fails with the same arguments, i.e. if the edges
+    /// do not form a tree on `n_vert` vertices rooted at `root`.
+    ///
+    pub fn new(n_vert: usize, root: usize, edges: &[(usize, usize)]) -> TreeIndexed {
+        match TreeIndexed::try_new(n_vert, root, edges) {
+            Ok(tree) => tree,
+            Err(_) => panic!(
+                "the edges do not form a tree on {} vertices rooted at {}",
+                n_vert, root
+            ),
+        }
+    }
+
+    /// Return the tree with given edges rooted at `root`, or
+    /// `NotATree` if `n_vert == 0`, `root >= n_vert`, an edge is out
+    /// of range or the edges do not form a tree on `n_vert` vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::graph::{NotATree, TreeIndexed};
+    /// assert!(TreeIndexed::try_new(2, 0, &[(0, 1)]).is_ok());
+    /// assert_eq!(TreeIndexed::try_new(0, 0, &[]).err(), Some(NotATree));
+    /// assert!(TreeIndexed::try_new(3, 0, &[(0, 1), (1, 0)]).is_err());
+    /// ```
+    pub fn try_new(
+        n_vert: usize,
+        root: usize,
+        edges: &[(usize, usize)],
+    ) -> Result<TreeIndexed, NotATree> {
+        if root >= n_vert || edges.len() + 1 != n_vert {
+            return Err(NotATree);
+        }
+        if edges.iter().any(|&(u, v)| u >= n_vert || v >= n_vert) {
+            return Err(NotATree);
+        }
+        let graph = StaticGraph::new(n_vert, edges);
+        let mut parent = vec![None; n_vert];
+        let mut reached = vec![false; n_vert];
+        let mut stack = vec![root];
+        reached[root] = true;
+        while let Some(u) = stack.pop() {
+            for &v in graph.neighbors(u) {
+                if !reached[v] {
+                    reached[v] = true;
+                    parent[v] = Some(u);
+                    stack.push(v);
+                }
+            }
+        }
+        if !reached.iter().all(|&r| r) {
+            return Err(NotATree);
+        }
+        Ok(TreeIndexed::from_parents(root, parent))
+    }
+
+    /// Return the tree in which `parent[v]` is the parent of every
+    /// vertex `v` and `parent[root] == usize::MAX`, or `NotATree` if
+    /// there is another vertex without a parent, a parent is out of
+    /// range or some vertex does not lead to `root`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::graph::{NotATree, TreeIndexed};
+    /// let tree = TreeIndexed::from_parent_array(&[1, usize::MAX, 1], 1).unwrap();
+    /// assert_eq!(tree.children(1), &[0, 2]);
+    /// assert_eq!(tree.to_parent_array(), vec![1, usize::MAX, 1]);
+    /// let cycle = [usize::MAX, 2, 1];
+    /// assert_eq!(TreeIndexed::from_parent_array(&cycle, 0).err(), Some(NotATree));
+    /// ```
+    pub fn from_parent_array(parent: &[usize], root: usize) -> Result<TreeIndexed, NotATree> {
+        let n_vert = parent.len();
+        if root >= n_vert || parent[root] != usize::MAX {
+            return Err(NotATree);
+        }
+        let mut links = Vec::with_capacity(n_vert);
+        for (v, &p) in parent.iter().enumerate() {
+            if v == root {
+                links.push(None);
+            } else if p < n_vert {
+                links.push(Some(p));
+            } else {
+                return Err(NotATree);
+            }
+        }
+        // Vertices on a cycle are never reached from the root.
+        let tree = TreeIndexed::from_parents(root, links);
+        if tree.preorder().len() != n_vert {
+            return Err(NotATree);
+        }
+        Ok(tree)
+    }
+
+    /// Return the parent of every vertex, with `usize::MAX` for the root.
+    pub fn to_parent_array(&self) -> Vec<usize> {
+        self.parent
+            .iter()
+            .map(|p| p.unwrap_or(usize::MAX))
+            .collect()
+    }
+
+    /// Return the tree given by the parent of every vertex, which
+    /// must be `None` exactly for `root` and lead to it from every
+    /// vertex. Children are ordered by index.
+    pub(crate) fn from_parents(root: usize, parent: Vec<Option<usize>>) -> TreeIndexed {
+        let n_vert = parent.len();
+        let mut offset = vec![0; n_vert + 1];
+        for &p in parent.iter().flatten() {
+            offset[p + 1] += 1;
+        }
+        for i in 1..=n_vert {
+            offset[i] += offset[i - 1];
+        }
+        let mut pos = offset.clone();
+        let mut children = vec![0; n_vert.saturating_sub(1)];
+        for (v, &p) in parent.iter().enumerate() {
+            if let Some(p) = p {
+                children[pos[p]] = v;
+                pos[p] += 1;
+            }
+        }
+
+        let mut tree = TreeIndexed {
+            root,
+            parent,
+            depth: vec![0; n_vert],
+            offset,
+            children,
+        };
+        for v in tree.preorder() {
+            if let Some(p) = tree.parent[v] {
+                tree.depth[v] = tree.depth[p] + 1;
+            }
+        }
+        tree
+    }
+
+    /// Return the number of vertices in the tree.
+    pub fn n_vert(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Return the root of the tree.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// Return the parent of `vert`, or `None` for the root.
+    pub fn parent(&self, vert: usize) -> Option<usize> {
+        self.parent[vert]
+    }
+
+    /// Return the children of `vert`.
+    pub fn children(&self, vert: usize) -> &[usize] {
+        &self.children[self.offset[vert]..self.offset[vert + 1]]
+    }
+
+    /// Return the number of edges between `vert` and the root.
+    pub fn depth(&self, vert: usize) -> usize {
+        self.depth[vert]
+    }
+
+    /// Return the vertices in depth-first preorder: every vertex
+    /// comes before its descendants, children in order. Every
+    /// subtree is a contiguous range of this order.
+    pub fn preorder(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.n_vert());
+        let mut stack = vec![self.root];
+        while let Some(u) = stack.pop() {
+            order.push(u);
+            stack.extend(self.children(u).iter().rev());
+        }
+        order
+    }
+
+    /// Return the vertices in depth-first postorder: every vertex
+    /// comes after its descendants, children in order.
+    pub fn postorder(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.n_vert());
+        let mut stack = vec![self.root];
+        while let Some(u) = stack.pop() {
+            order.push(u);
+            stack.extend(self.children(u).iter());
+        }
+        order.reverse();
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NotATree, TreeIndexed};
+
+    #[test]
+    fn structure_works() {
+        let tree = TreeIndexed::new(6, 0, &[(0, 1), (0, 2), (1, 3), (1, 4), (5, 2)]);
+        assert_eq!(tree.n_vert(), 6);
+        assert_eq!(tree.children(0), &[1, 2]);
+        assert_eq!(tree.children(1), &[3, 4]);
+        assert_eq!(tree.children(2), &[5]);
+        assert_eq!(tree.children(3), &[]);
+        let depths: Vec<usize> = (0..6).map(|v| tree.depth(v)).collect();
+        assert_eq!(depths, vec![0, 1, 1, 2, 2, 2]);
+        assert_eq!(tree.preorder(), vec![0, 1, 3, 4, 2, 5]);
+        assert_eq!(tree.postorder(), vec![3, 4, 1, 5, 2, 0]);
+
+        let single = TreeIndexed::new(1, 0, &[]);
+        assert_eq!(single.parent(0), None);
+        assert_eq!(single.preorder(), vec![0]);
+    }
+
+    #[test]
+    fn path_traversals_work() {
+        let edges: Vec<(usize, usize)> = (1..6).map(|v| (v - 1, v)).collect();
+        let tree = TreeIndexed::new(6, 2, &edges);
+        assert_eq!(tree.preorder()[0], 2);
+        assert_eq!(tree.postorder()[5], 2);
+        assert_eq!(tree.postorder(), vec![0, 1, 5, 4, 3, 2]);
+
+        let from_end = TreeIndexed::new(6, 0, &edges);
+        assert_eq!(from_end.preorder(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(from_end.postorder(), vec![5, 4, 3, 2, 1, 0]);
+        for order in [tree.preorder(), tree.postorder()].iter_mut() {
+            order.sort_unstable();
+            assert_eq!(*order, vec![0, 1, 2, 3, 4, 5]);
+        }
+    }
+
+    #[test]
+    fn parent_arrays_work() {
+        let tree = TreeIndexed::new(6, 4, &[(0, 1), (0, 2), (1, 3), (1, 4), (5, 2)]);
+        let parent = tree.to_parent_array();
+        assert_eq!(parent, vec![1, 4, 0, 1, usize::MAX, 2]);
+        let copy = TreeIndexed::from_parent_array(&parent, 4).unwrap();
+        assert_eq!(copy.to_parent_array(), parent);
+        assert_eq!(copy.preorder(), tree.preorder());
+
+        let single = TreeIndexed::from_parent_array(&[usize::MAX], 0).unwrap();
+        assert_eq!(single.n_vert(), 1);
+        assert_eq!(single.parent(0), None);
+    }
+
+    #[test]
+    fn invalid_parent_arrays_are_rejected() {
+        let max = usize::MAX;
+        assert_eq!(
+            TreeIndexed::from_parent_array(&[max, 0, max], 0).err(),
+            Some(NotATree)
+        );
+        assert!(TreeIndexed::from_parent_array(&[max, 3, 1, 2], 0).is_err());
+        assert!(TreeIndexed::from_parent_array(&[max, 1], 0).is_err());
+        assert!(TreeIndexed::from_parent_array(&[max, 5], 0).is_err());
+        assert!(TreeIndexed::from_parent_array(&[1, max], 0).is_err());
+        assert!(TreeIndexed::from_parent_array(&[], 0).is_err());
+    }
+
+    #[test]
+    fn invalid_edge_lists_are_rejected() {
+        assert_eq!(TreeIndexed::try_new(0, 0, &[]).err(), Some(NotATree));
+        assert!(TreeIndexed::try_new(2, 2, &[(0, 1)]).is_err());
+        assert!(TreeIndexed::try_new(2, 0, &[(0, 2)]).is_err());
+        assert!(TreeIndexed::try_new(4, 0, &[(0, 1), (1, 0), (2, 3)]).is_err());
+        assert!(TreeIndexed::try_new(3, 0, &[(0, 1)]).is_err());
+        assert_eq!(TreeIndexed::try_new(1, 0, &[]).unwrap().n_vert(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_vertex_tree_panics() {
+        TreeIndexed::new(0, 0, &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cycle_panics() {
+        TreeIndexed::new(4, 0, &[(0, 1), (1, 2), (2, 0)]);
+    }
+}
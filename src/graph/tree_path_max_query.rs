@@ -0,0 +1,115 @@
+//! This module contains maximum edge weight queries on the paths
+//! of a tree with static weights.
+
+use data_structures::DisjointSparseTable;
+use graph::{Hld, TreeIndexed};
+
+/// The maximum edge weight oracle over the paths of a rooted tree.
+/// The weights are laid out in the heavy-light order, every edge at
+/// the position of its lower endpoint, and a sparse table answers
+/// the maximum of each of the O(log n) ranges of a path in O(1).
+/// Preprocessing takes O(n log n) and a query O(log n). Unlike
+/// `TreePathQueries`, the weights can not be updated.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{TreeIndexed, TreePathMaxQuery};
+/// let tree = TreeIndexed::new(5, 0, &[(0, 1), (1, 2), (0, 3), (3, 4)]);
+/// // The weight of the edge between `v` and its parent is `weights[v]`.
+/// let query = TreePathMaxQuery::new(&tree, &[0, 8, 2, 5, 1]);
+/// assert_eq!(query.query(2, 4), 8);
+/// assert_eq!(query.query(3, 4), 1);
+/// ```
+pub struct TreePathMaxQuery {
+    hld: Hld,
+    table: DisjointSparseTable,
+}
+
+impl TreePathMaxQuery {
+    /// Return the oracle over `tree` with given edge weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `tree` - the tree;
+    /// * `edge_weights` - `edge_weights[v]` is the weight of the edge
+    ///   between `v` and its parent; the entry of the root is ignored.
+    ///
+    /// # Panics
+    ///
+    /// If `edge_weights.len() != tree.n_vert()`.
+    ///
+    pub fn new(tree: &TreeIndexed, edge_weights: &[i64]) -> TreePathMaxQuery {
+        let n = tree.n_vert();
+        assert_eq!(edge_weights.len(), n, "expected a weight for every vertex");
+        let hld = Hld::new(tree);
+        let mut by_position = vec![0; n];
+        for v in 0..n {
+            by_position[hld.position(v)] = edge_weights[v];
+        }
+        TreePathMaxQuery {
+            hld,
+            table: DisjointSparseTable::new(&by_position, i64::max),
+        }
+    }
+
+    /// Return the largest edge weight on the path between `u` and
+    /// `v`, or 0 if `u == v` and the path has no edges.
+    pub fn query(&self, u: usize, v: usize) -> i64 {
+        self.hld
+            .path_ranges(u, v, false)
+            .iter()
+            .map(|&(lo, hi)| self.table.query(lo, hi))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TreePathMaxQuery;
+    use graph::random::XorShift;
+    use graph::TreeIndexed;
+
+    #[test]
+    fn path_tree_works() {
+        let edges: Vec<(usize, usize)> = (1..8).map(|v| (v - 1, v)).collect();
+        let tree = TreeIndexed::new(8, 0, &edges);
+        let query = TreePathMaxQuery::new(&tree, &[0, 3, 9, 4, 1, 7, 2, 5]);
+        assert_eq!(query.query(7, 0), 9);
+        assert_eq!(query.query(7, 3), 7);
+        assert_eq!(query.query(4, 4), 0);
+    }
+
+    #[test]
+    fn heaviest_edge_at_the_top_works() {
+        let tree = TreeIndexed::new(6, 0, &[(0, 1), (1, 2), (1, 3), (0, 4), (4, 5)]);
+        let query = TreePathMaxQuery::new(&tree, &[-1, 100, 1, 2, 3, 4]);
+        assert_eq!(query.query(2, 5), 100);
+        assert_eq!(query.query(2, 3), 2);
+        assert_eq!(query.query(0, 5), 4);
+    }
+
+    #[test]
+    fn random_trees_match_naive() {
+        let mut rng = XorShift::new(101);
+        for n in 1..40 {
+            let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+            let tree = TreeIndexed::new(n, 0, &edges);
+            let weight: Vec<i64> = (0..n).map(|_| rng.next_below(100) as i64 - 50).collect();
+            let query = TreePathMaxQuery::new(&tree, &weight);
+            for _ in 0..30 {
+                let (mut u, mut v) = (rng.next_below(n), rng.next_below(n));
+                let (a, b) = (u, v);
+                let mut expected = None;
+                while u != v {
+                    if tree.depth(u) < tree.depth(v) {
+                        std::mem::swap(&mut u, &mut v);
+                    }
+                    expected = expected.max(Some(weight[u]));
+                    u = tree.parent(u).unwrap();
+                }
+                assert_eq!(query.query(a, b), expected.unwrap_or(0));
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! This module contains sums and maximums of edge weights along
+//! tree paths under point updates of the weights.
+
+use data_structures::{Fenwick, LazyMaxSegTree};
+use graph::{Hld, TreeIndexed};
+
+/// Path queries over the edge weights of a rooted tree. The weights
+/// are laid out in the heavy-light order, every edge at the position
+/// of its lower endpoint, with a Fenwick tree for sums and a segment
+/// tree for maximums. A path splits into O(log n) ranges, so queries
+/// take O(log^2 n) and updates O(log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{TreeIndexed, TreePathQueries};
+/// let tree = TreeIndexed::new(4, 0, &[(0, 1), (0, 2), (2, 3)]);
+/// // The weight of the edge between `v` and its parent is `weights[v]`.
+/// let mut queries = TreePathQueries::new(&tree, &[0, 5, 2, 7]);
+/// assert_eq!(queries.path_sum(1, 3), 14);
+/// assert_eq!(queries.path_max(1, 3), 7);
+/// queries.update_edge(3, 2, 1);
+/// assert_eq!(queries.path_max(1, 3), 5);
+/// ```
+pub struct TreePathQueries {
+    hld: Hld,
+    parent: Vec<Option<usize>>,
+    weight: Vec<i64>,
+    sums: Fenwick,
+    maxima: LazyMaxSegTree,
+}
+
+impl TreePathQueries {
+    /// Return the queries over `tree` with given edge weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `tree` - the tree;
+    /// * `edge_weights` - `edge_weights[v]` is the weight of the edge
+    ///   between `v` and its parent; the entry of the root is ignored.
+    ///
+    /// # Panics
+    ///
+    /// If `edge_weights.len() != tree.n_vert()`.
+    ///
+    pub fn new(tree: &TreeIndexed, edge_weights: &[i64]) -> TreePathQueries {
+        let n = tree.n_vert();
+        assert_eq!(edge_weights.len(), n, "expected a weight for every vertex");
+        let hld = Hld::new(tree);
+        let mut weight = edge_weights.to_vec();
+        weight[tree.root()] = 0;
+        let mut by_position = vec![0; n];
+        let mut sums = Fenwick::new(n);
+        for v in 0..n {
+            by_position[hld.position(v)] = weight[v];
+            sums.add(hld.position(v), weight[v]);
+        }
+        TreePathQueries {
+            parent: (0..n).map(|v| tree.parent(v)).collect(),
+            maxima: LazyMaxSegTree::new(&by_position),
+            hld,
+            weight,
+            sums,
+        }
+    }
+
+    /// Return the sum of the edge weights on the path between
+    /// `u` and `v`, which is 0 if `u == v`.
+    pub fn path_sum(&self, u: usize, v: usize) -> i64 {
+        self.hld
+            .path_ranges(u, v, false)
+            .iter()
+            .map(|&(lo, hi)| self.sums.range_sum(lo, hi))
+            .sum()
+    }
+
+    /// Return the largest edge weight on the path between `u`
+    /// and `v`, or 0 if `u == v` and the path has no edges.
+    pub fn path_max(&self, u: usize, v: usize) -> i64 {
+        self.hld
+            .path_ranges(u, v, false)
+            .iter()
+            .map(|&(lo, hi)| self.maxima.range_max(lo, hi))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Set the weight of the edge between `u` and `v` to `new_weight`.
+    ///
+    /// # Panics
+    ///
+    /// If `u` and `v` are not adjacent.
+    ///
+    pub fn update_edge(&mut self, u: usize, v: usize, new_weight: i64) {
+        let lower = if self.parent[u] == Some(v) {
+            u
+        } else if self.parent[v] == Some(u) {
+            v
+        } else {
+            panic!("{} and {} are not adjacent", u, v);
+        };
+        let delta = new_weight - self.weight[lower];
+        let pos = self.hld.position(lower);
+        self.sums.add(pos, delta);
+        self.maxima.range_add(pos, pos, delta);
+        self.weight[lower] = new_weight;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TreePathQueries;
+    use graph::random::XorShift;
+    use graph::TreeIndexed;
+
+    /// Return the edge weights on the path between `u` and `v`.
+    fn naive_path(tree: &TreeIndexed, weight: &[i64], mut u: usize, mut v: usize) -> Vec<i64> {
+        let mut path = Vec::new();
+        while u != v {
+            if tree.depth(u) < tree.depth(v) {
+                std::mem::swap(&mut u, &mut v);
+            }
+            path.push(weight[u]);
+            u = tree.parent(u).unwrap();
+        }
+        path
+    }
+
+    #[test]
+    fn star_path_sums_work() {
+        let edges: Vec<(usize, usize)> = (1..6).map(|v| (0, v)).collect();
+        let tree = TreeIndexed::new(6, 0, &edges);
+        let queries = TreePathQueries::new(&tree, &[100, 1, 2, 3, 4, 5]);
+        let total: i64 = (1..6).map(|v| queries.path_sum(0, v)).sum();
+        assert_eq!(total, 15);
+        assert_eq!(queries.path_sum(2, 5), 7);
+        for v in 0..6 {
+            assert_eq!(queries.path_sum(v, v), 0);
+        }
+    }
+
+    #[test]
+    fn path_max_ignores_edges_off_the_path() {
+        // 0 - 1 - 2 and a heavy edge 1 - 3 hanging off the path.
+        let tree = TreeIndexed::new(4, 0, &[(0, 1), (1, 2), (1, 3)]);
+        let mut queries = TreePathQueries::new(&tree, &[0, 4, 6, 50]);
+        assert_eq!(queries.path_max(0, 2), 6);
+        assert_eq!(queries.path_max(2, 3), 50);
+        queries.update_edge(1, 2, 70);
+        assert_eq!(queries.path_max(0, 2), 70);
+        assert_eq!(queries.path_sum(0, 2), 74);
+        assert_eq!(queries.path_max(1, 1), 0);
+    }
+
+    #[test]
+    fn random_updates_match_naive() {
+        let mut rng = XorShift::new(31);
+        let n = 60;
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+        let tree = TreeIndexed::new(n, 0, &edges);
+        let mut weight: Vec<i64> = (0..n).map(|_| rng.next_below(200) as i64 - 100).collect();
+        weight[0] = 0;
+        let mut queries = TreePathQueries::new(&tree, &weight);
+        for _ in 0..500 {
+            let v = 1 + rng.next_below(n - 1);
+            let w = rng.next_below(200) as i64 - 100;
+            queries.update_edge(tree.parent(v).unwrap(), v, w);
+            weight[v] = w;
+
+            let (a, b) = (rng.next_below(n), rng.next_below(n));
+            let path = naive_path(&tree, &weight, a, b);
+            assert_eq!(queries.path_sum(a, b), path.iter().sum::<i64>());
+            assert_eq!(
+                queries.path_max(a, b),
+                path.iter().cloned().max().unwrap_or(0)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn updating_a_non_edge_panics() {
+        let tree = TreeIndexed::new(3, 0, &[(0, 1), (1, 2)]);
+        TreePathQueries::new(&tree, &[0, 1, 2]).update_edge(0, 2, 5);
+    }
+}
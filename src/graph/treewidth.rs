@@ -0,0 +1,140 @@
+//! This module contains the minimum-degree elimination heuristic,
+//! which gives an upper bound on the treewidth of a graph.
+
+use std::collections::BTreeSet;
+
+use graph::StaticGraph;
+
+/// Return an upper bound on the treewidth of the graph: the width
+/// of the tree decomposition given by `elimination_order`, i.e. the
+/// largest number of neighbors a vertex has when it is eliminated.
+/// The bound is exact for forests (1, or 0 without edges) and
+/// complete graphs (`n - 1`), but can exceed the treewidth in general.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::treewidth_approx;
+/// let cycle = StaticGraph::new(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// assert_eq!(treewidth_approx(&cycle), 2);
+/// ```
+pub fn treewidth_approx(graph: &StaticGraph) -> usize {
+    eliminate(graph).1
+}
+
+/// Return the order in which the minimum-degree heuristic eliminates
+/// the vertices: it repeatedly removes a vertex of the smallest degree
+/// (the smallest index on ties) after turning its neighbors into
+/// a clique. Self-loops and parallel edges are ignored.
+///
+/// Runs in O(V^2 + V * W^2 * log V) for the width W found.
+///
+/// # Examples
+/// ```
+/// use ralgo::StaticGraph;
+/// use ralgo::graph::elimination_order;
+/// let star = StaticGraph::new(4, &[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(elimination_order(&star), vec![1, 2, 0, 3]);
+/// ```
+pub fn elimination_order(graph: &StaticGraph) -> Vec<usize> {
+    eliminate(graph).0
+}
+
+/// Run the minimum-degree heuristic and return the elimination
+/// order together with its width.
+fn eliminate(graph: &StaticGraph) -> (Vec<usize>, usize) {
+    let n = graph.n_vert();
+    let mut adjacent: Vec<BTreeSet<usize>> = (0..n)
+        .map(|u| {
+            graph
+                .neighbors(u)
+                .iter()
+                .cloned()
+                .filter(|&v| v != u)
+                .collect()
+        })
+        .collect();
+    let mut eliminated = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut width = 0;
+    for _ in 0..n {
+        let vert = (0..n)
+            .filter(|&v| !eliminated[v])
+            .min_by_key(|&v| adjacent[v].len())
+            .unwrap();
+        let neighbors: Vec<usize> = adjacent[vert].iter().cloned().collect();
+        width = width.max(neighbors.len());
+        for &u in &neighbors {
+            adjacent[u].remove(&vert);
+            for &v in &neighbors {
+                if u != v {
+                    adjacent[u].insert(v);
+                }
+            }
+        }
+        adjacent[vert].clear();
+        eliminated[vert] = true;
+        order.push(vert);
+    }
+    (order, width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{elimination_order, treewidth_approx};
+    use graph::{random_graph_gnp, StaticGraph};
+
+    fn grid(rows: usize, cols: usize) -> StaticGraph {
+        let mut edges = Vec::new();
+        for r in 0..rows {
+            for c in 0..cols {
+                let v = r * cols + c;
+                if c + 1 < cols {
+                    edges.push((v, v + 1));
+                }
+                if r + 1 < rows {
+                    edges.push((v, v + cols));
+                }
+            }
+        }
+        StaticGraph::new(rows * cols, &edges)
+    }
+
+    #[test]
+    fn forests_have_width_one() {
+        let edges: Vec<(usize, usize)> = (1..10).map(|v| (v - 1, v)).collect();
+        assert_eq!(treewidth_approx(&StaticGraph::new(10, &edges)), 1);
+
+        let edges: Vec<(usize, usize)> = (1..30).map(|v| ((v - 1) / 3, v)).collect();
+        assert_eq!(treewidth_approx(&StaticGraph::new(30, &edges)), 1);
+
+        assert_eq!(treewidth_approx(&StaticGraph::new(5, &[])), 0);
+        assert_eq!(treewidth_approx(&StaticGraph::new(0, &[])), 0);
+    }
+
+    #[test]
+    fn complete_graphs_work() {
+        for n in 1..8 {
+            assert_eq!(treewidth_approx(&random_graph_gnp(n, 1.0, 0)), n - 1);
+        }
+    }
+
+    #[test]
+    fn grids_are_close_to_side_length() {
+        for k in 2..8 {
+            let width = treewidth_approx(&grid(k, k));
+            assert!(k <= width && width <= 2 * k, "k = {}, width = {}", k, width);
+        }
+        assert_eq!(treewidth_approx(&grid(2, 10)), 2);
+    }
+
+    #[test]
+    fn order_is_a_permutation() {
+        for seed in 0..10 {
+            let graph = random_graph_gnp(20, 0.2, seed);
+            let mut order = elimination_order(&graph);
+            order.sort_unstable();
+            assert_eq!(order, (0..20).collect::<Vec<usize>>());
+        }
+    }
+}
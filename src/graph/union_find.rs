@@ -2,6 +2,22 @@
 //! (aka disjoint set union). The elements in the set
 //! are indexed with integers 0, 1, ..., N-1.
 
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when bytes do not encode a UnionFind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeserializeError;
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the bytes do not encode a union-find structure")
+    }
+}
+
+impl Error for DeserializeError {}
+
 /// The integer-indexed union-find data structure
 /// (aka disjoint set union).
 ///
@@ -64,6 +80,22 @@ impl UnionFind {
         root
     }
 
+    /// Return the representative of the connected component
+    /// that given element belongs to without compressing the path,
+    /// so it only needs a shared reference. Union by height keeps
+    /// the path length O(log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `ind` - the element in question.
+    ///
+    pub fn find_no_compress(&self, mut ind: usize) -> usize {
+        while self.root[ind] != ind {
+            ind = self.root[ind];
+        }
+        ind
+    }
+
     /// Return `true` if two given elements belong to the same
     /// connected component, `false` otherwise.
     ///
@@ -76,6 +108,18 @@ impl UnionFind {
         self.find(left) == self.find(right)
     }
 
+    /// Same as `connected`, but does not compress paths and
+    /// only needs a shared reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - the fist element in question;
+    /// * `right` - the second element.
+    ///
+    pub fn connected_immutable(&self, left: usize, right: usize) -> bool {
+        self.find_no_compress(left) == self.find_no_compress(right)
+    }
+
     /// Connect two components that two given elements belong to.
     /// Return the representative of the merged component.
     ///
@@ -94,21 +138,89 @@ impl UnionFind {
         self.count -= 1;
         if self.height[left] < self.height[right] {
             self.root[left] = right;
-            return right;
+            right
         } else if self.height[left] > self.height[right] {
             self.root[right] = left;
-            return left;
+            left
         } else {
             self.root[right] = left;
-            self.height[right] += 1;
-            return left;
+            self.height[left] += 1;
+            left
         }
     }
+
+    /// Return the structure encoded as bytes: the number of elements
+    /// as a 4-byte integer, then the root of every element and then
+    /// the height of every element as 8-byte integers, all in
+    /// little-endian order. The encoding takes `4 + 16 * n` bytes.
+    ///
+    /// # Panics
+    ///
+    /// If the number of elements does not fit in 32 bits.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let n = self.root.len();
+        assert!(n <= u32::MAX as usize, "too many elements to encode");
+        let mut bytes = Vec::with_capacity(4 + 16 * n);
+        bytes.extend_from_slice(&(n as u32).to_le_bytes());
+        for &x in self.root.iter().chain(self.height.iter()) {
+            bytes.extend_from_slice(&(x as u64).to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Return the structure encoded by `to_bytes`. Fails if the
+    /// length of `bytes` does not match the encoded number of
+    /// elements or the roots do not form a valid forest.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - the encoded structure.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::UnionFind;
+    /// let mut uf = UnionFind::new(3);
+    /// uf.union(0, 2);
+    /// let bytes = uf.to_bytes();
+    /// assert_eq!(bytes.len(), 4 + 16 * 3);
+    /// let mut copy = UnionFind::from_bytes(&bytes).unwrap();
+    /// assert!(copy.connected(2, 0));
+    /// assert!(UnionFind::from_bytes(&bytes[..10]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<UnionFind, DeserializeError> {
+        if bytes.len() < 4 {
+            return Err(DeserializeError);
+        }
+        let (header, body) = bytes.split_at(4);
+        let n = u32::from_le_bytes(header.try_into().unwrap()) as usize;
+        if body.len() != 16 * n {
+            return Err(DeserializeError);
+        }
+        let mut values = body
+            .chunks(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()) as usize);
+        let root: Vec<usize> = values.by_ref().take(n).collect();
+        let height: Vec<usize> = values.collect();
+
+        // Heights strictly increase towards the roots, which also
+        // rules out cycles in the parent links.
+        let valid = (0..n).all(|i| root[i] < n && (root[i] == i || height[root[i]] > height[i]));
+        if !valid {
+            return Err(DeserializeError);
+        }
+        let count = (0..n).filter(|&i| root[i] == i).count();
+        Ok(UnionFind {
+            root,
+            height,
+            count,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::UnionFind;
+    use super::{DeserializeError, UnionFind};
 
     #[test]
     fn init_works() {
@@ -162,4 +274,83 @@ mod tests {
             assert_eq!(uf.find(i - 2), i - 2);
         }
     }
+
+    #[test]
+    fn immutable_queries_work() {
+        let mut uf = UnionFind::new(1024);
+        for step in (0..10).map(|k| 1 << k) {
+            for i in (0..1024).step_by(2 * step) {
+                uf.union(i, i + step);
+            }
+        }
+        let uf = &uf;
+        for i in 0..1024 {
+            assert_eq!(uf.find_no_compress(i), uf.find_no_compress(0));
+            assert!(uf.connected_immutable(i, 1023 - i));
+        }
+
+        // Union by height keeps paths short even without compression.
+        let mut chain = UnionFind::new(1024);
+        for i in 1..1024 {
+            chain.union(i, i - 1);
+        }
+        let depth = |mut i: usize| {
+            let mut depth = 0;
+            while chain.root[i] != i {
+                i = chain.root[i];
+                depth += 1;
+            }
+            depth
+        };
+        assert!((0..1024).all(|i| depth(i) <= 10));
+
+        let mut uf = UnionFind::new(4);
+        uf.union(0, 1);
+        assert!(uf.connected_immutable(1, 0));
+        assert!(!uf.connected_immutable(1, 2));
+        assert_eq!(uf.find_no_compress(3), 3);
+    }
+
+    #[test]
+    fn serialization_round_trips() {
+        let mut uf = UnionFind::new(10);
+        for &(u, v) in &[(0, 1), (2, 3), (1, 3), (5, 6), (7, 5), (9, 9)] {
+            uf.union(u, v);
+        }
+        uf.find(3);
+        let mut copy = UnionFind::from_bytes(&uf.to_bytes()).unwrap();
+        assert_eq!(copy.n_components(), uf.n_components());
+        for i in 0..10 {
+            for k in 0..10 {
+                assert_eq!(copy.connected(i, k), uf.connected(i, k));
+            }
+        }
+
+        let empty = UnionFind::new(0).to_bytes();
+        assert_eq!(empty, vec![0, 0, 0, 0]);
+        assert_eq!(UnionFind::from_bytes(&empty).unwrap().n_components(), 0);
+    }
+
+    #[test]
+    fn invalid_bytes_are_rejected() {
+        let mut uf = UnionFind::new(4);
+        uf.union(0, 1);
+        let bytes = uf.to_bytes();
+        assert_eq!(bytes.len(), 4 + 16 * 4);
+        for len in 0..bytes.len() {
+            assert_eq!(
+                UnionFind::from_bytes(&bytes[..len]).err(),
+                Some(DeserializeError)
+            );
+        }
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(UnionFind::from_bytes(&extended).is_err());
+
+        // Elements 0 and 1 pointing at each other.
+        let mut cyclic = UnionFind::new(2).to_bytes();
+        cyclic[4] = 1;
+        cyclic[12] = 0;
+        assert!(UnionFind::from_bytes(&cyclic).is_err());
+    }
 }
@@ -0,0 +1,164 @@
+//! This module defines a union-find data structure that
+//! maintains an aggregate value for every component.
+
+use graph::UnionFind;
+
+/// The integer-indexed union-find data structure where every
+/// component carries a value that is combined when two
+/// components are united (e.g. the sum or maximum of the
+/// values of its elements).
+///
+/// # Examples
+///
+/// ```
+/// use ralgo::UnionFindAggregate;
+/// let mut uf = UnionFindAggregate::new(vec![1, 2, 3, 4], |a, b| a + b);
+/// uf.union(0, 1);
+/// uf.union(3, 1);
+/// assert_eq!(uf.aggregate(0), 7);
+/// assert_eq!(uf.aggregate(2), 3);
+/// ```
+pub struct UnionFindAggregate<T> {
+    components: UnionFind,
+    aggregate: Vec<T>,
+    combine: fn(T, T) -> T,
+}
+
+impl<T: Clone> UnionFindAggregate<T> {
+    /// Return a UnionFindAggregate structure with every element
+    /// in its own component.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - the initial value of every element;
+    /// * `combine` - the function merging the values of two
+    ///   components; it should be associative and commutative,
+    ///   since the order of unions is arbitrary.
+    ///
+    pub fn new(values: Vec<T>, combine: fn(T, T) -> T) -> UnionFindAggregate<T> {
+        UnionFindAggregate {
+            components: UnionFind::new(values.len()),
+            aggregate: values,
+            combine,
+        }
+    }
+
+    /// Return the current number of connected components.
+    pub fn n_components(&self) -> usize {
+        self.components.n_components()
+    }
+
+    /// Return the representative of the connected component
+    /// that given element belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `ind` - the element in question.
+    ///
+    pub fn find(&mut self, ind: usize) -> usize {
+        self.components.find(ind)
+    }
+
+    /// Return `true` if two given elements belong to the same
+    /// connected component, `false` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - the fist element in question;
+    /// * `right` - the second element.
+    ///
+    pub fn connected(&mut self, left: usize, right: usize) -> bool {
+        self.components.connected(left, right)
+    }
+
+    /// Connect two components that two given elements belong to
+    /// and combine their aggregates as `combine(left, right)`.
+    /// Uniting a component with itself changes nothing.
+    /// Return the representative of the merged component.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - the first element;
+    /// * `right` - the second element.
+    ///
+    pub fn union(&mut self, left: usize, right: usize) -> usize {
+        let left = self.components.find(left);
+        let right = self.components.find(right);
+        if left == right {
+            return left;
+        }
+        let merged = (self.combine)(self.aggregate[left].clone(), self.aggregate[right].clone());
+        let root = self.components.union(left, right);
+        self.aggregate[root] = merged;
+        root
+    }
+
+    /// Return the aggregate of the component that given element
+    /// belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `ind` - the element in question.
+    ///
+    pub fn aggregate(&mut self, ind: usize) -> T {
+        let root = self.components.find(ind);
+        self.aggregate[root].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFindAggregate;
+
+    #[test]
+    fn component_sums_work() {
+        let mut uf = UnionFindAggregate::new(vec![1, 10, 100, 1000, 10000], |a, b| a + b);
+        uf.union(0, 2);
+        uf.union(3, 4);
+        assert_eq!(uf.aggregate(2), 101);
+        assert_eq!(uf.aggregate(4), 11000);
+        assert_eq!(uf.aggregate(1), 10);
+        uf.union(2, 3);
+        assert_eq!(uf.aggregate(0), 11101);
+        assert_eq!(uf.n_components(), 2);
+    }
+
+    #[test]
+    fn component_maximums_work() {
+        let mut uf = UnionFindAggregate::new(vec![3, 9, 2, 7], std::cmp::max);
+        uf.union(0, 2);
+        assert_eq!(uf.aggregate(0), 3);
+        uf.union(2, 1);
+        assert_eq!(uf.aggregate(2), 9);
+        assert_eq!(uf.aggregate(3), 7);
+    }
+
+    #[test]
+    fn self_union_keeps_aggregate() {
+        let mut uf = UnionFindAggregate::new(vec![5, 6], |a, b| a + b);
+        uf.union(0, 0);
+        assert_eq!(uf.aggregate(0), 5);
+        uf.union(0, 1);
+        uf.union(1, 0);
+        uf.union(1, 1);
+        assert_eq!(uf.aggregate(0), 11);
+    }
+
+    #[test]
+    fn chain_of_unions_works() {
+        let n = 1000;
+        let mut uf = UnionFindAggregate::new((1..=n).collect(), |a, b| a + b);
+        for i in 1..n {
+            uf.union(i - 1, i);
+        }
+        assert_eq!(uf.n_components(), 1);
+        assert_eq!(uf.aggregate(n / 2), n * (n + 1) / 2);
+
+        let mut names = UnionFindAggregate::new(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            |a, b| a + &b,
+        );
+        names.union(1, 2);
+        assert_eq!(names.aggregate(2), "bc");
+    }
+}
@@ -0,0 +1,213 @@
+//! This module defines a union-find data structure with union
+//! by size whose paths are only compressed on request.
+
+/// The integer-indexed union-find data structure with union by
+/// size. Unions and `find_pure` never compress paths, so the parent
+/// chains stay exactly as the unions built them and every element
+/// is at depth O(log n): an element only gets deeper when its
+/// component is attached to one at least as large, which at least
+/// doubles its size. `find_compress` additionally shortens the path
+/// it walks for later queries.
+///
+/// # Examples
+///
+/// ```
+/// use ralgo::graph::UnionFindBySize;
+/// let mut uf = UnionFindBySize::new(5);
+/// uf.union(0, 1);
+/// uf.union(2, 1);
+/// assert_eq!(uf.component_size(2), 3);
+/// assert_eq!(uf.find_pure(2), uf.find_compress(0));
+/// assert!(!uf.connected(0, 3));
+/// ```
+pub struct UnionFindBySize {
+    root: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFindBySize {
+    /// Return a UnionFindBySize structure with given capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - the number of components to start with.
+    ///
+    pub fn new(count: usize) -> UnionFindBySize {
+        UnionFindBySize {
+            root: (0..count).collect(),
+            size: vec![1; count],
+            count,
+        }
+    }
+
+    /// Return the current number of connected components.
+    pub fn n_components(&self) -> usize {
+        self.count
+    }
+
+    /// Return the representative of the connected component
+    /// that given element belongs to without modifying the structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `ind` - the element in question.
+    ///
+    pub fn find_pure(&self, mut ind: usize) -> usize {
+        while self.root[ind] != ind {
+            ind = self.root[ind];
+        }
+        ind
+    }
+
+    /// Return the representative of the connected component
+    /// that given element belongs to, pointing every element on
+    /// the way directly to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `ind` - the element in question.
+    ///
+    pub fn find_compress(&mut self, mut ind: usize) -> usize {
+        let root = self.find_pure(ind);
+        while self.root[ind] != ind {
+            let next = self.root[ind];
+            self.root[ind] = root;
+            ind = next;
+        }
+        root
+    }
+
+    /// Return `true` if two given elements belong to the same
+    /// connected component, `false` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - the fist element in question;
+    /// * `right` - the second element.
+    ///
+    pub fn connected(&self, left: usize, right: usize) -> bool {
+        self.find_pure(left) == self.find_pure(right)
+    }
+
+    /// Return the number of elements in the connected component
+    /// that given element belongs to.
+    pub fn component_size(&self, ind: usize) -> usize {
+        self.size[self.find_pure(ind)]
+    }
+
+    /// Connect two components that two given elements belong to,
+    /// attaching the smaller one to the larger one (the one of
+    /// `left` on ties). Return the representative of the merged
+    /// component.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - the first element;
+    /// * `right` - the second element.
+    ///
+    pub fn union(&mut self, left: usize, right: usize) -> usize {
+        let left = self.find_pure(left);
+        let right = self.find_pure(right);
+        if left == right {
+            return left;
+        }
+
+        self.count -= 1;
+        let (big, small) = if self.size[left] < self.size[right] {
+            (right, left)
+        } else {
+            (left, right)
+        };
+        self.root[small] = big;
+        self.size[big] += self.size[small];
+        big
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFindBySize;
+    use graph::random::XorShift;
+    use graph::UnionFind;
+
+    #[test]
+    fn union_works() {
+        let mut uf = UnionFindBySize::new(8);
+        uf.union(0, 1);
+        uf.union(1, 2);
+        uf.union(2, 3);
+        uf.union(4, 5);
+        uf.union(5, 6);
+
+        assert_eq!(uf.n_components(), 3);
+        for i in 0..8 {
+            for k in 0..8 {
+                let same = (i < 4 && k < 4) || ((4..7).contains(&i) && (4..7).contains(&k));
+                assert_eq!(uf.connected(i, k), same || i == k);
+            }
+        }
+        assert_eq!(uf.component_size(3), 4);
+        assert_eq!(uf.component_size(6), 3);
+        assert_eq!(uf.component_size(7), 1);
+    }
+
+    #[test]
+    fn pure_and_compressing_finds_agree() {
+        let mut rng = XorShift::new(181);
+        let n = 300;
+        let mut uf = UnionFindBySize::new(n);
+        for _ in 0..250 {
+            uf.union(rng.next_below(n), rng.next_below(n));
+            let i = rng.next_below(n);
+            let root = uf.find_pure(i);
+            assert_eq!(uf.find_compress(i), root);
+            assert_eq!(uf.root[i], root);
+        }
+        for i in 0..n {
+            assert_eq!(uf.find_pure(i), uf.find_compress(i));
+        }
+    }
+
+    #[test]
+    fn components_match_union_find() {
+        let mut rng = XorShift::new(191);
+        let n = 200;
+        let mut by_size = UnionFindBySize::new(n);
+        let mut reference = UnionFind::new(n);
+        for _ in 0..150 {
+            let (a, b) = (rng.next_below(n), rng.next_below(n));
+            by_size.union(a, b);
+            reference.union(a, b);
+            assert_eq!(by_size.n_components(), reference.n_components());
+        }
+        for _ in 0..2000 {
+            let (a, b) = (rng.next_below(n), rng.next_below(n));
+            assert_eq!(by_size.connected(a, b), reference.connected(a, b));
+        }
+    }
+
+    #[test]
+    fn depth_stays_logarithmic_without_compression() {
+        let n = 1 << 12;
+        let mut uf = UnionFindBySize::new(n);
+        for i in 1..n {
+            uf.union(i, i - 1);
+        }
+        let mut rng = XorShift::new(193);
+        let mut chains = UnionFindBySize::new(n);
+        for _ in 0..3 * n {
+            chains.union(rng.next_below(n), rng.next_below(n));
+        }
+        for uf in [uf, chains].iter() {
+            for mut i in 0..n {
+                let mut depth = 0;
+                while uf.root[i] != i {
+                    i = uf.root[i];
+                    depth += 1;
+                }
+                assert!(depth <= 12);
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! This module defines a union-find data structure whose
+//! unions can be undone in the reverse order.
+
+/// The integer-indexed union-find data structure with rollback.
+/// Paths are never compressed, so every union changes O(1) cells
+/// and can be undone; union by height keeps `find` in O(log n).
+///
+/// # Examples
+///
+/// ```
+/// use ralgo::graph::UnionFindRollback;
+/// let mut uf = UnionFindRollback::new(4);
+/// uf.union(0, 1);
+/// let snapshot = uf.snapshot();
+/// uf.union(1, 2);
+/// assert!(uf.connected(0, 2));
+/// uf.rollback(snapshot);
+/// assert!(!uf.connected(0, 2));
+/// assert!(uf.connected(0, 1));
+/// ```
+pub struct UnionFindRollback {
+    root: Vec<usize>,
+    height: Vec<usize>,
+    count: usize,
+    // For every union: the attached root and whether the height
+    // of its new parent grew, or `None` if nothing changed.
+    history: Vec<Option<(usize, bool)>>,
+}
+
+impl UnionFindRollback {
+    /// Return a UnionFindRollback structure with given capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - the number of components to start with.
+    ///
+    pub fn new(count: usize) -> UnionFindRollback {
+        UnionFindRollback {
+            root: (0..count).collect(),
+            height: vec![0; count],
+            count,
+            history: Vec::new(),
+        }
+    }
+
+    /// Return the current number of connected components.
+    pub fn n_components(&self) -> usize {
+        self.count
+    }
+
+    /// Return the representative of the connected component
+    /// that given element belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `ind` - the element in question.
+    ///
+    pub fn find(&self, mut ind: usize) -> usize {
+        while self.root[ind] != ind {
+            ind = self.root[ind];
+        }
+        ind
+    }
+
+    /// Return `true` if two given elements belong to the same
+    /// connected component, `false` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - the fist element in question;
+    /// * `right` - the second element.
+    ///
+    pub fn connected(&self, left: usize, right: usize) -> bool {
+        self.find(left) == self.find(right)
+    }
+
+    /// Connect two components that two given elements belong to.
+    /// Return the representative of the merged component.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - the first element;
+    /// * `right` - the second element.
+    ///
+    pub fn union(&mut self, left: usize, right: usize) -> usize {
+        let mut left = self.find(left);
+        let mut right = self.find(right);
+        if left == right {
+            self.history.push(None);
+            return left;
+        }
+        if self.height[left] < self.height[right] {
+            std::mem::swap(&mut left, &mut right);
+        }
+        let grows = self.height[left] == self.height[right];
+        self.root[right] = left;
+        if grows {
+            self.height[left] += 1;
+        }
+        self.count -= 1;
+        self.history.push(Some((right, grows)));
+        left
+    }
+
+    /// Return the number of unions performed so far, to be passed
+    /// to `rollback` later.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undo the unions performed after given snapshot was taken,
+    /// most recent first.
+    ///
+    /// # Panics
+    ///
+    /// If fewer than `snapshot` unions are on record.
+    ///
+    pub fn rollback(&mut self, snapshot: usize) {
+        assert!(snapshot <= self.history.len(), "snapshot is in the future");
+        while self.history.len() > snapshot {
+            if let Some((child, grew)) = self.history.pop().unwrap() {
+                let parent = self.root[child];
+                if grew {
+                    self.height[parent] -= 1;
+                }
+                self.root[child] = child;
+                self.count += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFindRollback;
+
+    #[test]
+    fn union_works() {
+        let mut uf = UnionFindRollback::new(6);
+        uf.union(0, 1);
+        uf.union(2, 3);
+        uf.union(1, 3);
+        assert_eq!(uf.n_components(), 3);
+        assert!(uf.connected(0, 2));
+        assert!(!uf.connected(0, 4));
+    }
+
+    #[test]
+    fn rollback_restores_state() {
+        let mut uf = UnionFindRollback::new(5);
+        uf.union(0, 1);
+        let snapshot = uf.snapshot();
+        let roots: Vec<usize> = (0..5).map(|i| uf.find(i)).collect();
+        uf.union(2, 3);
+        uf.union(0, 0);
+        uf.union(3, 1);
+        uf.union(4, 2);
+        assert_eq!(uf.n_components(), 1);
+        uf.rollback(snapshot);
+        assert_eq!(uf.n_components(), 4);
+        assert_eq!((0..5).map(|i| uf.find(i)).collect::<Vec<_>>(), roots);
+        uf.rollback(0);
+        assert_eq!(uf.n_components(), 5);
+    }
+
+    #[test]
+    fn nested_rollbacks_work() {
+        let mut uf = UnionFindRollback::new(8);
+        let outer = uf.snapshot();
+        for i in 1..4 {
+            uf.union(i - 1, i);
+        }
+        let inner = uf.snapshot();
+        for i in 5..8 {
+            uf.union(i - 1, i);
+        }
+        uf.union(0, 7);
+        assert_eq!(uf.n_components(), 1);
+        uf.rollback(inner);
+        assert!(uf.connected(0, 3));
+        assert!(!uf.connected(4, 5));
+        uf.rollback(outer);
+        assert!(!uf.connected(0, 1));
+    }
+}
@@ -0,0 +1,110 @@
+//! This module defines a union-find data structure where the
+//! caller decides which element represents a merged component.
+
+use graph::UnionFind;
+
+/// The integer-indexed union-find data structure whose `union`
+/// keeps the root of its first argument as the root of the merged
+/// component, independently of how the underlying structure links
+/// its trees. This is useful e.g. for merging a subtree into its
+/// parent, where the parent must stay the root.
+///
+/// # Examples
+///
+/// ```
+/// use ralgo::graph::UnionFindRooted;
+/// let mut uf = UnionFindRooted::new(4);
+/// uf.union(1, 0);
+/// uf.union(1, 2);
+/// assert_eq!(uf.find(0), 1);
+/// uf.union(3, 2);
+/// assert_eq!(uf.find(1), 3);
+/// ```
+pub struct UnionFindRooted {
+    components: UnionFind,
+    root: Vec<usize>,
+}
+
+impl UnionFindRooted {
+    /// Return a UnionFindRooted structure with every element
+    /// in its own component, being its root.
+    pub fn new(count: usize) -> UnionFindRooted {
+        UnionFindRooted {
+            components: UnionFind::new(count),
+            root: (0..count).collect(),
+        }
+    }
+
+    /// Return the current number of connected components.
+    pub fn n_components(&self) -> usize {
+        self.components.n_components()
+    }
+
+    /// Return the root of the connected component that given
+    /// element belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `ind` - the element in question.
+    ///
+    pub fn find(&mut self, ind: usize) -> usize {
+        let repr = self.components.find(ind);
+        self.root[repr]
+    }
+
+    /// Connect two components that two given elements belong to.
+    /// The root of the component of `major` becomes the root of
+    /// the merged component, and is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - the element whose root is kept;
+    /// * `minor` - the other element.
+    ///
+    pub fn union(&mut self, major: usize, minor: usize) -> usize {
+        let root = self.find(major);
+        // The underlying structure may link either tree below the
+        // other, so the kept root is stored at whichever
+        // representative it picks.
+        let repr = self.components.union(major, minor);
+        self.root[repr] = root;
+        root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFindRooted;
+    use graph::random::XorShift;
+
+    #[test]
+    fn equal_heights_keep_the_major_root() {
+        let mut uf = UnionFindRooted::new(3);
+        uf.union(1, 0);
+        uf.union(1, 2);
+        assert_eq!(uf.find(0), 1);
+        assert_eq!(uf.find(2), 1);
+        assert_eq!(uf.n_components(), 1);
+    }
+
+    #[test]
+    fn random_unions_match_naive() {
+        let mut rng = XorShift::new(109);
+        let n = 30;
+        let mut uf = UnionFindRooted::new(n);
+        let mut root: Vec<usize> = (0..n).collect();
+        for _ in 0..200 {
+            let (major, minor) = (rng.next_below(n), rng.next_below(n));
+            let (kept, dropped) = (root[major], root[minor]);
+            for r in root.iter_mut() {
+                if *r == dropped {
+                    *r = kept;
+                }
+            }
+            assert_eq!(uf.union(major, minor), kept);
+            for (v, &r) in root.iter().enumerate() {
+                assert_eq!(uf.find(v), r);
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! This module contains the virtual (auxiliary) tree of a set of
+//! vertices of a rooted tree.
+
+use graph::{LcaOnline, TreeIndexed};
+
+/// Return the virtual tree of `vertices`: the tree on the given
+/// vertices and the lowest common ancestors of all their pairs, in
+/// which the parent of every vertex is its closest ancestor among
+/// them. It has at most `2k - 1` vertices for `k` distinct given
+/// vertices and is built in O(k log k + k log n).
+///
+/// Vertices of the virtual tree are numbered in the depth-first
+/// preorder of `tree`, so its root is 0. The second element of the
+/// result maps every vertex of the virtual tree to the vertex of
+/// `tree` it stands for.
+///
+/// # Arguments
+///
+/// * `tree` - the tree;
+/// * `lca` - the lowest common ancestor oracle over `tree`;
+/// * `vertices` - the vertices to keep (duplicates are ignored).
+///
+/// # Panics
+///
+/// If `vertices` is empty.
+///
+/// # Examples
+/// ```
+/// use ralgo::graph::{build_virtual_tree, LcaOnline, TreeIndexed};
+/// let tree = TreeIndexed::new(6, 0, &[(0, 1), (1, 2), (1, 3), (3, 4), (0, 5)]);
+/// let lca = LcaOnline::new(&tree);
+/// let (virtual_tree, mapping) = build_virtual_tree(&tree, &lca, &[2, 4]);
+/// assert_eq!(mapping, vec![1, 2, 4]);
+/// assert_eq!(virtual_tree.children(0), &[1, 2]);
+/// ```
+pub fn build_virtual_tree(
+    tree: &TreeIndexed,
+    lca: &LcaOnline,
+    vertices: &[usize],
+) -> (TreeIndexed, Vec<usize>) {
+    assert!(!vertices.is_empty(), "the virtual tree needs a vertex");
+    let by_entry = |list: &mut Vec<usize>| {
+        list.sort_unstable_by_key(|&v| lca.entry(v));
+        list.dedup();
+    };
+    let mut kept = vertices.to_vec();
+    by_entry(&mut kept);
+    for i in 1..kept.len() {
+        let ancestor = lca.lca(kept[i - 1], kept[i]);
+        kept.push(ancestor);
+    }
+    by_entry(&mut kept);
+
+    // In preorder, the parent of every vertex is the lowest common
+    // ancestor of it and the previous vertex.
+    let mut index = vec![usize::MAX; tree.n_vert()];
+    for (i, &v) in kept.iter().enumerate() {
+        index[v] = i;
+    }
+    let parent = (0..kept.len())
+        .map(|i| match i {
+            0 => None,
+            _ => Some(index[lca.lca(kept[i - 1], kept[i])]),
+        })
+        .collect();
+    (TreeIndexed::from_parents(0, parent), kept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_virtual_tree;
+    use graph::random::XorShift;
+    use graph::{LcaOnline, TreeIndexed};
+
+    fn sample_tree() -> TreeIndexed {
+        TreeIndexed::new(
+            8,
+            0,
+            &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (5, 6), (5, 7)],
+        )
+    }
+
+    #[test]
+    fn small_sets_work() {
+        let tree = sample_tree();
+        let lca = LcaOnline::new(&tree);
+        let (single, mapping) = build_virtual_tree(&tree, &lca, &[6]);
+        assert_eq!(single.n_vert(), 1);
+        assert_eq!(mapping, vec![6]);
+
+        let (pair, mapping) = build_virtual_tree(&tree, &lca, &[7, 6, 7]);
+        assert_eq!(pair.n_vert(), 3);
+        assert_eq!(mapping[pair.root()], 5);
+        assert_eq!(pair.children(pair.root()).len(), 2);
+
+        let (chain, mapping) = build_virtual_tree(&tree, &lca, &[6, 2]);
+        assert_eq!(mapping, vec![2, 6]);
+        assert_eq!(chain.parent(1), Some(0));
+    }
+
+    #[test]
+    fn full_vertex_set_gives_the_tree() {
+        let tree = sample_tree();
+        let lca = LcaOnline::new(&tree);
+        let all: Vec<usize> = (0..8).rev().collect();
+        let (copy, mapping) = build_virtual_tree(&tree, &lca, &all);
+        assert_eq!(copy.n_vert(), 8);
+        for v in 0..8 {
+            assert_eq!(copy.parent(v).map(|p| mapping[p]), tree.parent(mapping[v]));
+        }
+    }
+
+    #[test]
+    fn pairwise_lcas_are_kept() {
+        let mut rng = XorShift::new(89);
+        let n = 80;
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (rng.next_below(v), v)).collect();
+        let tree = TreeIndexed::new(n, 0, &edges);
+        let lca = LcaOnline::new(&tree);
+        for k in 1..12 {
+            let vertices: Vec<usize> = (0..k).map(|_| rng.next_below(n)).collect();
+            let (small, mapping) = build_virtual_tree(&tree, &lca, &vertices);
+            assert!(small.n_vert() < 2 * k);
+            let small_lca = LcaOnline::new(&small);
+            let mut index = vec![usize::MAX; n];
+            for (i, &v) in mapping.iter().enumerate() {
+                index[v] = i;
+            }
+            for &u in vertices.iter() {
+                for &v in vertices.iter() {
+                    let expected = lca.lca(u, v);
+                    assert_eq!(mapping[small_lca.lca(index[u], index[v])], expected);
+                }
+            }
+        }
+    }
+}
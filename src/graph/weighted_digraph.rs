@@ -0,0 +1,220 @@
+//! This module defines a static directed graph data structure
+//! with integer edge weights whose vertices are indexed with
+//! integers 0, 1, ..., N-1. This graph can not be modified
+//! once created.
+
+use graph::DiGraph;
+
+/// The integer-indexed static directed graph with weighted edges.
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedDiGraph;
+/// let graph = WeightedDiGraph::new(3, &[(0, 1, 5), (0, 2, -1), (2, 1, 3)]);
+/// assert_eq!(graph.n_vert(), 3);
+/// assert_eq!(graph.n_edges(), 3);
+/// assert_eq!(graph.successors_with_weights(0), &[(1, 5), (2, -1)]);
+/// assert_eq!(graph.successors_with_weights(1), &[]);
+/// assert_eq!(graph.predecessors_with_weights(1), &[(0, 5), (2, 3)]);
+/// ```
+pub struct WeightedDiGraph {
+    offset: Vec<usize>,
+    succ: Vec<(usize, i64)>,
+    pred_offset: Vec<usize>,
+    pred: Vec<(usize, i64)>,
+}
+
+impl WeightedDiGraph {
+    /// Return a new instance of WeightedDiGraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices;
+    /// * `edges` - triples `(from, to, weight)` of directed edges.
+    ///
+    /// # Panics
+    ///
+    /// If `edges` contains a vertex >= `n_vert`.
+    ///
+    pub fn new(n_vert: usize, edges: &[(usize, usize, i64)]) -> WeightedDiGraph {
+        for &(u, v, _) in edges {
+            assert!(
+                u < n_vert && v < n_vert,
+                "edge ({}, {}) is out of range for a graph with {} vertices",
+                u,
+                v,
+                n_vert
+            );
+        }
+        let (offset, succ) = compress(n_vert, edges.iter().cloned());
+        let (pred_offset, pred) = compress(n_vert, edges.iter().map(|&(u, v, w)| (v, u, w)));
+        WeightedDiGraph {
+            offset,
+            succ,
+            pred_offset,
+            pred,
+        }
+    }
+
+    /// Return a WeightedDiGraph with the edges of `graph`. The weights
+    /// are given in the order the edges are listed by `successors`:
+    /// all edges going out of vertex 0, then out of vertex 1 and so on.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - the graph providing the edges;
+    /// * `weights` - the weight of every edge.
+    ///
+    /// # Panics
+    ///
+    /// If `weights.len()` != `graph.n_edges()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::{DiGraph, WeightedDiGraph};
+    /// let graph = DiGraph::new(3, &[(0, 2), (0, 1), (2, 1)]);
+    /// let weighted = WeightedDiGraph::from_digraph(&graph, &[10, 20, 30]);
+    /// assert_eq!(weighted.successors_with_weights(0), &[(2, 10), (1, 20)]);
+    /// assert_eq!(weighted.predecessors_with_weights(1), &[(0, 20), (2, 30)]);
+    /// ```
+    pub fn from_digraph(graph: &DiGraph, weights: &[i64]) -> WeightedDiGraph {
+        assert_eq!(
+            weights.len(),
+            graph.n_edges(),
+            "expected a weight for every edge"
+        );
+        let edges: Vec<(usize, usize, i64)> = (0..graph.n_vert())
+            .flat_map(|u| graph.successors(u).iter().map(move |&v| (u, v)))
+            .zip(weights.iter())
+            .map(|((u, v), &w)| (u, v, w))
+            .collect();
+        WeightedDiGraph::new(graph.n_vert(), &edges)
+    }
+
+    /// Return the number of vertices in given WeightedDiGraph instance.
+    pub fn n_vert(&self) -> usize {
+        self.offset.len() - 1
+    }
+
+    /// Return the number of edges in given WeightedDiGraph instance.
+    pub fn n_edges(&self) -> usize {
+        self.succ.len()
+    }
+
+    /// Return the slice of pairs `(to, weight)` for all edges
+    /// going out of the given vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `vert` - the vertex in question.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` >= `self.n_vert()`.
+    ///
+    pub fn successors_with_weights(&self, vert: usize) -> &[(usize, i64)] {
+        &self.succ[self.offset[vert]..self.offset[vert + 1]]
+    }
+
+    /// Return the slice of pairs `(from, weight)` for all edges
+    /// coming into the given vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `vert` - the vertex in question.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` >= `self.n_vert()`.
+    ///
+    pub fn predecessors_with_weights(&self, vert: usize) -> &[(usize, i64)] {
+        &self.pred[self.pred_offset[vert]..self.pred_offset[vert + 1]]
+    }
+}
+
+/// Build the compressed adjacency representation: the pairs
+/// `(to, weight)` of vertex `v` are `targets[offset[v]..offset[v + 1]]`.
+fn compress<I>(n_vert: usize, edges: I) -> (Vec<usize>, Vec<(usize, i64)>)
+where
+    I: Iterator<Item = (usize, usize, i64)> + Clone,
+{
+    let mut offset = vec![0; n_vert + 1];
+    for (u, _, _) in edges.clone() {
+        offset[u + 1] += 1;
+    }
+    for i in 1..=n_vert {
+        offset[i] += offset[i - 1];
+    }
+
+    let mut pos = offset.clone();
+    let mut targets = vec![(0, 0); offset[n_vert]];
+    for (u, v, w) in edges {
+        targets[pos[u]] = (v, w);
+        pos[u] += 1;
+    }
+    (offset, targets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WeightedDiGraph;
+    use graph::random::XorShift;
+    use graph::DiGraph;
+
+    #[test]
+    fn simple_graph_works() {
+        let graph = WeightedDiGraph::new(4, &[(0, 1, 2), (1, 2, -3), (0, 2, 7), (3, 0, 0)]);
+        assert_eq!(graph.n_vert(), 4);
+        assert_eq!(graph.n_edges(), 4);
+        assert_eq!(graph.successors_with_weights(0), &[(1, 2), (2, 7)]);
+        assert_eq!(graph.successors_with_weights(1), &[(2, -3)]);
+        assert_eq!(graph.successors_with_weights(2), &[]);
+        assert_eq!(graph.successors_with_weights(3), &[(0, 0)]);
+    }
+
+    #[test]
+    fn predecessors_work() {
+        let graph =
+            WeightedDiGraph::new(4, &[(0, 1, 2), (1, 2, -3), (0, 2, 7), (3, 0, 0), (2, 2, 4)]);
+        assert_eq!(graph.n_edges(), 5);
+        assert_eq!(graph.predecessors_with_weights(0), &[(3, 0)]);
+        assert_eq!(graph.predecessors_with_weights(1), &[(0, 2)]);
+        assert_eq!(
+            graph.predecessors_with_weights(2),
+            &[(1, -3), (0, 7), (2, 4)]
+        );
+        assert_eq!(graph.predecessors_with_weights(3), &[]);
+        assert_eq!(WeightedDiGraph::new(0, &[]).n_vert(), 0);
+    }
+
+    #[test]
+    fn from_digraph_round_trips_weights() {
+        let mut rng = XorShift::new(107);
+        let n_vert = 8;
+        let edges: Vec<(usize, usize, i64)> = (0..30)
+            .map(|_| {
+                let w = rng.next_below(100) as i64 - 50;
+                (rng.next_below(n_vert), rng.next_below(n_vert), w)
+            })
+            .collect();
+        let weighted = WeightedDiGraph::new(n_vert, &edges);
+        let unweighted: Vec<(usize, usize)> = edges.iter().map(|&(u, v, _)| (u, v)).collect();
+        let graph = DiGraph::new(n_vert, &unweighted);
+        let weights: Vec<i64> = (0..n_vert)
+            .flat_map(|u| weighted.successors_with_weights(u).iter().map(|&(_, w)| w))
+            .collect();
+        let rebuilt = WeightedDiGraph::from_digraph(&graph, &weights);
+        assert_eq!(rebuilt.n_edges(), edges.len());
+        for v in 0..n_vert {
+            assert_eq!(
+                rebuilt.successors_with_weights(v),
+                weighted.successors_with_weights(v)
+            );
+            let mut expected = weighted.predecessors_with_weights(v).to_vec();
+            let mut actual = rebuilt.predecessors_with_weights(v).to_vec();
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! This module defines a static undirected graph data structure
+//! with integer edge weights whose vertices are indexed with
+//! integers 0, 1, ..., N-1. This graph can not be modified
+//! once created.
+
+use graph::random::XorShift;
+use graph::StaticGraph;
+
+/// The integer-indexed static undirected graph with weighted edges.
+///
+/// # Examples
+/// ```
+/// use ralgo::WeightedGraph;
+/// let graph = WeightedGraph::new(3, &[(0, 1, 5), (2, 1, -1)]);
+/// assert_eq!(graph.n_vert(), 3);
+/// assert_eq!(graph.n_edges(), 2);
+/// assert_eq!(graph.neighbors_with_weights(1), &[(0, 5), (2, -1)]);
+/// assert_eq!(graph.neighbors_with_weights(2), &[(1, -1)]);
+/// ```
+pub struct WeightedGraph {
+    offset: Vec<usize>,
+    neigh: Vec<(usize, i64)>,
+    edges: Vec<(usize, usize, i64)>,
+}
+
+impl WeightedGraph {
+    /// Return a new instance of WeightedGraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_vert` - number of vertices;
+    /// * `edges` - triples `(u, v, weight)` of adjacent vertices.
+    ///
+    /// # Panics
+    ///
+    /// If `edges` contains a vertex >= `n_vert`.
+    ///
+    pub fn new(n_vert: usize, edges: &[(usize, usize, i64)]) -> WeightedGraph {
+        let mut offset = vec![0; n_vert + 1];
+        for &(u, v, _) in edges {
+            assert!(
+                u < n_vert && v < n_vert,
+                "edge ({}, {}) is out of range for a graph with {} vertices",
+                u,
+                v,
+                n_vert
+            );
+            offset[u + 1] += 1;
+            offset[v + 1] += 1;
+        }
+        for i in 1..=n_vert {
+            offset[i] += offset[i - 1];
+        }
+
+        let mut pos = offset.clone();
+        let mut neigh = vec![(0, 0); 2 * edges.len()];
+        for &(u, v, w) in edges {
+            neigh[pos[u]] = (v, w);
+            pos[u] += 1;
+            neigh[pos[v]] = (u, w);
+            pos[v] += 1;
+        }
+
+        WeightedGraph {
+            offset,
+            neigh,
+            edges: edges.to_vec(),
+        }
+    }
+
+    /// Return a WeightedGraph with the edges of `graph`,
+    /// all of which have the same weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - the graph providing the edges;
+    /// * `weight` - the weight of every edge.
+    ///
+    /// # Examples
+    /// ```
+    /// use ralgo::{StaticGraph, WeightedGraph};
+    /// let graph = StaticGraph::new(3, &[(0, 1), (2, 1)]);
+    /// let weighted = WeightedGraph::from_static_uniform(&graph, 7);
+    /// assert_eq!(weighted.neighbors_with_weights(1), &[(0, 7), (2, 7)]);
+    /// ```
+    pub fn from_static_uniform(graph: &StaticGraph, weight: i64) -> WeightedGraph {
+        let edges: Vec<(usize, usize, i64)> = graph
+            .edges()
+            .into_iter()
+            .map(|(u, v)| (u, v, weight))
+            .collect();
+        WeightedGraph::new(graph.n_vert(), &edges)
+    }
+
+    /// Return a WeightedGraph with the edges of `graph` whose weights
+    /// are drawn uniformly from `[min_w, max_w]`. The same seed always
+    /// yields the same weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - the graph providing the edges;
+    /// * `min_w` - the smallest possible weight;
+    /// * `max_w` - the largest possible weight;
+    /// * `seed` - seed of the random number generator.
+    ///
+    /// # Panics
+    ///
+    /// If `min_w > max_w`.
+    ///
+    pub fn from_static_random(
+        graph: &StaticGraph,
+        min_w: i64,
+        max_w: i64,
+        seed: u64,
+    ) -> WeightedGraph {
+        assert!(min_w <= max_w, "empty weight range [{}, {}]", min_w, max_w);
+        let span = (max_w as i128 - min_w as i128 + 1) as u128;
+        let mut rng = XorShift::new(seed);
+        let edges: Vec<(usize, usize, i64)> = graph
+            .edges()
+            .into_iter()
+            .map(|(u, v)| {
+                let offset = rng.next_u64() as u128 % span;
+                (u, v, (min_w as i128 + offset as i128) as i64)
+            })
+            .collect();
+        WeightedGraph::new(graph.n_vert(), &edges)
+    }
+
+    /// Return the number of vertices in given WeightedGraph instance.
+    pub fn n_vert(&self) -> usize {
+        self.offset.len() - 1
+    }
+
+    /// Return the number of edges in given WeightedGraph instance.
+    pub fn n_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Return the slice of pairs `(neighbor, weight)` for all edges
+    /// incident to the given vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `vert` - the vertex in question.
+    ///
+    /// # Panics
+    ///
+    /// If `vert` >= `self.n_vert()`.
+    ///
+    pub fn neighbors_with_weights(&self, vert: usize) -> &[(usize, i64)] {
+        &self.neigh[self.offset[vert]..self.offset[vert + 1]]
+    }
+
+    /// Return the edges of the graph in the order they were given.
+    pub fn edges(&self) -> &[(usize, usize, i64)] {
+        &self.edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WeightedGraph;
+    use graph::{random_graph_gnm, Dijkstra, StaticGraph};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn simple_graph_works() {
+        let graph = WeightedGraph::new(4, &[(0, 1, 2), (1, 2, -3), (0, 2, 7)]);
+        assert_eq!(graph.n_vert(), 4);
+        assert_eq!(graph.n_edges(), 3);
+        assert_eq!(graph.neighbors_with_weights(0), &[(1, 2), (2, 7)]);
+        assert_eq!(graph.neighbors_with_weights(1), &[(0, 2), (2, -3)]);
+        assert_eq!(graph.neighbors_with_weights(2), &[(1, -3), (0, 7)]);
+        assert_eq!(graph.neighbors_with_weights(3), &[]);
+        assert_eq!(graph.edges(), &[(0, 1, 2), (1, 2, -3), (0, 2, 7)]);
+    }
+
+    #[test]
+    fn empty_graph_works() {
+        let graph = WeightedGraph::new(0, &[]);
+        assert_eq!(graph.n_vert(), 0);
+        assert_eq!(graph.n_edges(), 0);
+    }
+
+    #[test]
+    fn uniform_weights_work() {
+        let graph = StaticGraph::new(4, &[(0, 1), (1, 2), (2, 0), (3, 3)]);
+        let weighted = WeightedGraph::from_static_uniform(&graph, -4);
+        assert_eq!(weighted.n_edges(), 4);
+        for v in 0..4 {
+            let neighbors = weighted.neighbors_with_weights(v);
+            assert!(neighbors.iter().all(|&(_, w)| w == -4));
+            let mut actual: Vec<usize> = neighbors.iter().map(|&(u, _)| u).collect();
+            let mut expected = graph.neighbors(v).to_vec();
+            actual.sort();
+            expected.sort();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn random_weights_are_in_range() {
+        let graph = random_graph_gnm(30, 200, 7);
+        let weighted = WeightedGraph::from_static_random(&graph, -3, 5, 11);
+        assert_eq!(weighted.n_edges(), 200);
+        assert!(weighted
+            .edges()
+            .iter()
+            .all(|&(_, _, w)| (-3..=5).contains(&w)));
+        for w in -3..=5 {
+            assert!(weighted.edges().iter().any(|e| e.2 == w));
+        }
+        let again = WeightedGraph::from_static_random(&graph, -3, 5, 11);
+        assert_eq!(again.edges(), weighted.edges());
+        let extreme = WeightedGraph::from_static_random(&graph, i64::MIN, i64::MAX, 1);
+        assert_eq!(extreme.n_edges(), 200);
+        let single = WeightedGraph::from_static_random(&graph, 9, 9, 1);
+        assert!(single.edges().iter().all(|e| e.2 == 9));
+    }
+
+    #[test]
+    fn uniform_dijkstra_matches_bfs() {
+        let graph = random_graph_gnm(40, 60, 13);
+        let weighted = WeightedGraph::from_static_uniform(&graph, 3);
+        let paths = Dijkstra::new(&weighted, 0);
+
+        let mut hops = vec![None; 40];
+        hops[0] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(u) = queue.pop_front() {
+            for &v in graph.neighbors(u) {
+                if hops[v].is_none() {
+                    hops[v] = Some(hops[u].unwrap() + 1);
+                    queue.push_back(v);
+                }
+            }
+        }
+        for (v, h) in hops.into_iter().enumerate() {
+            assert_eq!(paths.distance(v), h.map(|h| 3 * h));
+        }
+    }
+}
@@ -1,7 +1,25 @@
+pub mod algo;
+pub mod data_structures;
+pub mod geometry;
 pub mod graph;
+pub mod math;
 pub mod sort;
+pub mod string;
 
 pub use sort::mergesort;
+pub use sort::mergesort_clone;
 
+pub use graph::BipartiteGraph;
+pub use graph::Dag;
+pub use graph::DiGraph;
+pub use graph::Dijkstra;
+pub use graph::DominatorTree;
+pub use graph::DynamicConnectivity;
+pub use graph::KCore;
 pub use graph::StaticGraph;
 pub use graph::UnionFind;
+pub use graph::UnionFindAggregate;
+pub use graph::UnionFindBySize;
+pub use graph::UnionFindRollback;
+pub use graph::WeightedDiGraph;
+pub use graph::WeightedGraph;
@@ -0,0 +1,286 @@
+//! This module defines a dense matrix of unsigned integers with
+//! multiplication and fast exponentiation, e.g. for counting
+//! walks of a given length in a graph.
+
+use std::ops::{Index, IndexMut};
+
+/// A dense `rows x cols` matrix of `u64`, indexed by `(row, col)`.
+///
+/// # Examples
+/// ```
+/// use ralgo::math::Matrix;
+/// // Adjacency matrix of the path 0 - 1 - 2.
+/// let mut a = Matrix::new(3, 3);
+/// a[(0, 1)] = 1;
+/// a[(1, 0)] = 1;
+/// a[(1, 2)] = 1;
+/// a[(2, 1)] = 1;
+/// // Two walks of length 4 lead from 0 back to 0.
+/// assert_eq!(a.pow(4)[(0, 0)], 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<u64>,
+}
+
+impl Matrix {
+    /// Return a new zero matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - number of rows;
+    /// * `cols` - number of columns.
+    ///
+    pub fn new(rows: usize, cols: usize) -> Matrix {
+        Matrix {
+            rows,
+            cols,
+            data: vec![0; rows * cols],
+        }
+    }
+
+    /// Return the `n x n` identity matrix.
+    pub fn identity(n: usize) -> Matrix {
+        let mut matrix = Matrix::new(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = 1;
+        }
+        matrix
+    }
+
+    /// Return the number of rows of given matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Return the number of columns of given matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Return the product `self * other` in O(n * m * k).
+    ///
+    /// # Panics
+    ///
+    /// If `self.cols() != other.rows()`, or if an entry of the
+    /// product exceeds `u64::MAX`; use `mul_mod` when it may.
+    ///
+    pub fn mul(&self, other: &Matrix) -> Matrix {
+        self.product(other, |acc, a, b| {
+            a.checked_mul(b)
+                .and_then(|ab| acc.checked_add(ab))
+                .expect("matrix product overflows u64")
+        })
+    }
+
+    /// Return the product `self * other` with every entry taken
+    /// modulo `modulus`, in O(n * m * k).
+    ///
+    /// # Panics
+    ///
+    /// If `self.cols() != other.rows()` or `modulus` is zero.
+    ///
+    pub fn mul_mod(&self, other: &Matrix, modulus: u64) -> Matrix {
+        assert!(modulus > 0, "modulus must be positive");
+        let m = modulus as u128;
+        self.product(other, |acc, a, b| {
+            ((acc as u128 + a as u128 % m * (b as u128 % m)) % m) as u64
+        })
+    }
+
+    /// Return `self` raised to the power `exp` by repeated squaring,
+    /// using O(log exp) multiplications. For an adjacency matrix `A`,
+    /// `A.pow(k)[(i, j)]` is the number of walks of length exactly `k`
+    /// from `i` to `j`.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square, or if an entry of an intermediate
+    /// product exceeds `u64::MAX`; use `pow_mod` when it may.
+    ///
+    pub fn pow(&self, exp: u64) -> Matrix {
+        self.power(exp, |a, b| a.mul(b))
+    }
+
+    /// Return `self` raised to the power `exp` with every entry taken
+    /// modulo `modulus`, using O(log exp) multiplications.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square or `modulus` is zero.
+    ///
+    pub fn pow_mod(&self, exp: u64, modulus: u64) -> Matrix {
+        assert!(modulus > 0, "modulus must be positive");
+        let mut result = self.power(exp, |a, b| a.mul_mod(b, modulus));
+        // The identity is not reduced when `exp` is zero.
+        for x in result.data.iter_mut() {
+            *x %= modulus;
+        }
+        result
+    }
+
+    /// Multiply the matrices, accumulating every term `a * b` into
+    /// an entry `acc` with `add_product(acc, a, b)`.
+    fn product<F>(&self, other: &Matrix, add_product: F) -> Matrix
+    where
+        F: Fn(u64, u64, u64) -> u64,
+    {
+        assert_eq!(
+            self.cols, other.rows,
+            "can not multiply {}x{} matrix by {}x{} matrix",
+            self.rows, self.cols, other.rows, other.cols
+        );
+        let mut product = Matrix::new(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                if a == 0 {
+                    continue;
+                }
+                for j in 0..other.cols {
+                    product[(i, j)] = add_product(product[(i, j)], a, other[(k, j)]);
+                }
+            }
+        }
+        product
+    }
+
+    /// Raise the matrix to the power `exp` by repeated squaring
+    /// with the multiplication `mul`.
+    fn power<F>(&self, mut exp: u64, mul: F) -> Matrix
+    where
+        F: Fn(&Matrix, &Matrix) -> Matrix,
+    {
+        assert_eq!(self.rows, self.cols, "only square matrices have powers");
+        let mut base = self.clone();
+        let mut result = Matrix::identity(self.rows);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul(&result, &base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = mul(&base, &base);
+            }
+        }
+        result
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = u64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &u64 {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut u64 {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix;
+
+    fn from_rows(rows: &[&[u64]]) -> Matrix {
+        let mut matrix = Matrix::new(rows.len(), rows[0].len());
+        for (i, row) in rows.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                matrix[(i, j)] = x;
+            }
+        }
+        matrix
+    }
+
+    #[test]
+    fn identity_is_neutral() {
+        let a = from_rows(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(Matrix::identity(2).mul(&a), a);
+        assert_eq!(a.mul(&Matrix::identity(3)), a);
+        assert_eq!(
+            a.mul(&from_rows(&[&[1], &[0], &[2]])),
+            from_rows(&[&[7], &[16]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_dimensions_panic() {
+        Matrix::new(2, 3).mul(&Matrix::new(2, 3));
+    }
+
+    #[test]
+    fn complete_graph_walks_work() {
+        let mut k4 = Matrix::new(4, 4);
+        for i in 0..4 {
+            for j in 0..4 {
+                if i != j {
+                    k4[(i, j)] = 1;
+                }
+            }
+        }
+        let squared = k4.pow(2);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(squared[(i, j)], if i == j { 3 } else { 2 });
+            }
+        }
+        assert_eq!(k4.pow(0), Matrix::identity(4));
+        assert_eq!(k4.pow(1), k4);
+        // Closed walks of length k in K_n: ((n-1)^k + (n-1)(-1)^k) / n.
+        assert_eq!(k4.pow(5)[(0, 0)], (243 - 3) / 4);
+    }
+
+    #[test]
+    fn one_by_one_matches_scalar() {
+        let a = from_rows(&[&[3]]);
+        for exp in 0..20 {
+            assert_eq!(a.pow(exp)[(0, 0)], 3u64.pow(exp as u32));
+        }
+        assert_eq!(Matrix::new(0, 0).pow(5), Matrix::new(0, 0));
+    }
+
+    #[test]
+    fn modular_arithmetic_works() {
+        // Fibonacci numbers: [[1, 1], [1, 0]]^n = [[F(n+1), F(n)], [F(n), F(n-1)]].
+        let fib = from_rows(&[&[1, 1], &[1, 0]]);
+        assert_eq!(fib.pow(90)[(0, 1)], 2_880_067_194_370_816_120);
+        let modulus = 1_000_000_007;
+        assert_eq!(
+            fib.pow_mod(90, modulus)[(0, 1)],
+            2_880_067_194_370_816_120 % modulus
+        );
+        // F(1000) mod 10^9 + 7.
+        assert_eq!(fib.pow_mod(1000, modulus)[(0, 1)], 517_691_607);
+
+        let big = from_rows(&[&[u64::MAX, 2], &[3, u64::MAX - 1]]);
+        let m = u64::MAX - 58;
+        let expected = |i: usize, j: usize| {
+            let sum: u128 = (0..2)
+                .map(|k| (big[(i, k)] as u128 % m as u128) * (big[(k, j)] as u128 % m as u128))
+                .map(|x| x % m as u128)
+                .sum();
+            (sum % m as u128) as u64
+        };
+        let squared = big.mul_mod(&big, m);
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_eq!(squared[(i, j)], expected(i, j));
+            }
+        }
+        assert_eq!(Matrix::identity(2).pow_mod(0, 1), Matrix::new(2, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflowing_product_panics() {
+        from_rows(&[&[2]]).pow(64);
+    }
+}
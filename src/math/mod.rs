@@ -0,0 +1,7 @@
+pub mod matrix;
+pub mod mod_int;
+pub mod primes;
+
+pub use self::matrix::Matrix;
+pub use self::mod_int::ModInt;
+pub use self::primes::{factorize, is_prime, primes_up_to, sieve, smallest_prime_factor};
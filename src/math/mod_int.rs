@@ -0,0 +1,179 @@
+//! This module defines an integer type with arithmetic
+//! modulo a compile-time constant.
+
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An integer modulo `M`, always kept in the range `0..M`.
+/// Intermediate results are computed in `u128`, so any `M`
+/// that fits in `u64` is supported.
+///
+/// # Examples
+/// ```
+/// use ralgo::math::ModInt;
+/// type Mint = ModInt<1_000_000_007>;
+/// let a = Mint::new(-1);
+/// assert_eq!(a + Mint::new(2), Mint::new(1));
+/// assert_eq!(Mint::new(2).pow(10), Mint::new(1024));
+/// assert_eq!(Mint::new(3) * Mint::new(3).inv(), Mint::new(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u64> {
+    val: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    /// Return `val` modulo `M`; negative values are wrapped
+    /// around, so `ModInt::<7>::new(-1)` equals 6.
+    pub fn new(val: i64) -> ModInt<M> {
+        ModInt {
+            val: (val as i128).rem_euclid(M as i128) as u64,
+        }
+    }
+
+    /// Return the representative of given instance in `0..M`.
+    pub fn value(&self) -> u64 {
+        self.val
+    }
+
+    /// Return `self` raised to the power `exp` in O(log exp)
+    /// multiplications.
+    pub fn pow(&self, mut exp: u64) -> ModInt<M> {
+        let mut base = *self;
+        let mut result = ModInt::from(1u64);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Return the multiplicative inverse of `self` computed as
+    /// `self^(M-2)` by Fermat's little theorem. `M` must be prime,
+    /// otherwise the result is meaningless.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is zero.
+    ///
+    pub fn inv(&self) -> ModInt<M> {
+        assert!(self.val != 0, "zero has no multiplicative inverse");
+        self.pow(M - 2)
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(val: u64) -> ModInt<M> {
+        ModInt { val: val % M }
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(val: i64) -> ModInt<M> {
+        ModInt::new(val)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = ModInt<M>;
+
+    fn add(self, other: ModInt<M>) -> ModInt<M> {
+        ModInt {
+            val: ((self.val as u128 + other.val as u128) % M as u128) as u64,
+        }
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = ModInt<M>;
+
+    fn sub(self, other: ModInt<M>) -> ModInt<M> {
+        self + -other
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = ModInt<M>;
+
+    fn mul(self, other: ModInt<M>) -> ModInt<M> {
+        ModInt {
+            val: ((self.val as u128 * other.val as u128) % M as u128) as u64,
+        }
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = ModInt<M>;
+
+    fn neg(self) -> ModInt<M> {
+        ModInt {
+            val: if self.val == 0 { 0 } else { M - self.val },
+        }
+    }
+}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModInt;
+
+    #[test]
+    fn arithmetic_works() {
+        type Mint = ModInt<7>;
+        assert_eq!(Mint::new(5) + Mint::new(4), Mint::new(2));
+        assert_eq!(Mint::new(2) - Mint::new(5), Mint::new(4));
+        assert_eq!(Mint::new(3) * Mint::new(5), Mint::new(1));
+        assert_eq!(-Mint::new(3), Mint::new(4));
+        assert_eq!(-Mint::new(0), Mint::new(0));
+        assert_eq!(Mint::new(-1), Mint::new(6));
+        assert_eq!(Mint::new(-15).value(), 6);
+        assert_eq!(Mint::from(100u64), Mint::from(2i64));
+        assert_eq!(Mint::new(12).to_string(), "5");
+    }
+
+    #[test]
+    fn large_modulus_does_not_overflow() {
+        const P: u64 = 18_446_744_073_709_551_557; // the largest prime below 2^64
+        type Mint = ModInt<P>;
+        let a = Mint::from(P - 1);
+        assert_eq!(a + a, Mint::from(P - 2));
+        assert_eq!(a * a, Mint::from(1u64));
+        assert_eq!(a * a.inv(), Mint::from(1u64));
+        assert_eq!(Mint::new(i64::MIN).value(), P - (1 << 63));
+    }
+
+    #[test]
+    fn inverse_works() {
+        assert_eq!(ModInt::<7>::new(3).inv(), ModInt::new(5));
+        for x in 1..13 {
+            assert_eq!(ModInt::<13>::new(x) * ModInt::new(x).inv(), ModInt::new(1));
+        }
+    }
+
+    #[test]
+    fn powers_of_two_work() {
+        assert_eq!(ModInt::<1_000_000_007>::new(2).pow(0), ModInt::new(1));
+        assert_eq!(
+            ModInt::<1_000_000_007>::new(2).pow(30),
+            ModInt::new(1 << 30)
+        );
+        assert_eq!(
+            ModInt::<1_000_000_007>::new(2).pow(31),
+            ModInt::new(147_483_634)
+        );
+        assert_eq!(
+            ModInt::<998_244_353>::new(2).pow(998_244_352),
+            ModInt::new(1)
+        );
+        assert_eq!(ModInt::<11>::new(2).pow(10), ModInt::new(1));
+        assert_eq!(ModInt::<11>::new(2).pow(5), ModInt::new(10));
+    }
+}
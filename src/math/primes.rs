@@ -0,0 +1,207 @@
+//! This module contains prime sieves, factorization with
+//! a smallest-prime-factor table and the deterministic
+//! Miller–Rabin primality test.
+
+/// Return the table `is_prime` of length `limit + 1`, where
+/// `is_prime[i]` tells whether `i` is prime.
+///
+/// Implements the sieve of Eratosthenes in O(n log log n).
+///
+/// # Examples
+/// ```
+/// use ralgo::math::sieve;
+/// assert_eq!(sieve(5), vec![false, false, true, true, false, true]);
+/// ```
+pub fn sieve(limit: usize) -> Vec<bool> {
+    let mut is_prime = vec![true; limit + 1];
+    is_prime[0] = false;
+    if limit >= 1 {
+        is_prime[1] = false;
+    }
+    let mut p = 2;
+    while p * p <= limit {
+        if is_prime[p] {
+            for multiple in (p * p..=limit).step_by(p) {
+                is_prime[multiple] = false;
+            }
+        }
+        p += 1;
+    }
+    is_prime
+}
+
+/// Return all primes `<= limit` in increasing order.
+///
+/// # Examples
+/// ```
+/// use ralgo::math::primes_up_to;
+/// assert_eq!(primes_up_to(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+pub fn primes_up_to(limit: usize) -> Vec<usize> {
+    sieve(limit)
+        .iter()
+        .enumerate()
+        .filter(|&(_, &prime)| prime)
+        .map(|(p, _)| p)
+        .collect()
+}
+
+/// Return the table `spf` of length `limit + 1`, where `spf[i]` is
+/// the smallest prime factor of `i` for `i >= 2` (`spf[0] = 0` and
+/// `spf[1] = 1`).
+///
+/// Implements the linear sieve: every composite is crossed out
+/// exactly once, by its smallest prime factor, in O(n).
+///
+/// # Examples
+/// ```
+/// use ralgo::math::smallest_prime_factor;
+/// assert_eq!(smallest_prime_factor(9), vec![0, 1, 2, 3, 2, 5, 2, 7, 2, 3]);
+/// ```
+pub fn smallest_prime_factor(limit: usize) -> Vec<usize> {
+    let mut spf = vec![0; limit + 1];
+    if limit >= 1 {
+        spf[1] = 1;
+    }
+    let mut primes = Vec::new();
+    for i in 2..=limit {
+        if spf[i] == 0 {
+            spf[i] = i;
+            primes.push(i);
+        }
+        for &p in primes.iter() {
+            if p > spf[i] || i * p > limit {
+                break;
+            }
+            spf[i * p] = p;
+        }
+    }
+    spf
+}
+
+/// Return the prime factors of `n` with multiplicity, in
+/// non-decreasing order (empty for `n = 1`). Runs in O(log n).
+///
+/// # Arguments
+///
+/// * `n` - the number to factorize;
+/// * `spf` - the table returned by `smallest_prime_factor`.
+///
+/// # Panics
+///
+/// If `n` is zero or `n >= spf.len()`.
+///
+/// # Examples
+/// ```
+/// use ralgo::math::{factorize, smallest_prime_factor};
+/// let spf = smallest_prime_factor(100);
+/// assert_eq!(factorize(90, &spf), vec![2, 3, 3, 5]);
+/// ```
+pub fn factorize(mut n: usize, spf: &[usize]) -> Vec<usize> {
+    assert!(n > 0, "zero can not be factorized");
+    assert!(n < spf.len(), "{} is out of range of the factor table", n);
+    let mut factors = Vec::new();
+    while n > 1 {
+        factors.push(spf[n]);
+        n /= spf[n];
+    }
+    factors
+}
+
+/// Return `true` if `n` is prime.
+///
+/// Implements the Miller–Rabin test with the first twelve primes
+/// as witnesses, which is deterministic for all 64-bit integers.
+///
+/// # Examples
+/// ```
+/// use ralgo::math::is_prime;
+/// assert!(is_prime(1_000_000_007));
+/// assert!(!is_prime(561));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for &p in WITNESSES.iter() {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let mul = |a: u64, b: u64| (a as u128 * b as u128 % n as u128) as u64;
+    let pow = |mut base: u64, mut exp: u64| {
+        let mut result = 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul(result, base);
+            }
+            base = mul(base, base);
+            exp >>= 1;
+        }
+        result
+    };
+
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+    WITNESSES.iter().all(|&a| {
+        let mut x = pow(a, odd);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..shift {
+            x = mul(x, x);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{factorize, is_prime, primes_up_to, sieve, smallest_prime_factor};
+
+    #[test]
+    fn sieve_works() {
+        assert_eq!(sieve(100).iter().filter(|&&p| p).count(), 25);
+        assert_eq!(sieve(0), vec![false]);
+        assert_eq!(sieve(1), vec![false, false]);
+        assert_eq!(primes_up_to(104_729).len(), 10_000);
+        assert_eq!(primes_up_to(104_729)[9_999], 104_729);
+        assert_eq!(primes_up_to(1), vec![]);
+    }
+
+    #[test]
+    fn factorization_works() {
+        let spf = smallest_prime_factor(1000);
+        assert_eq!(factorize(12, &spf), vec![2, 2, 3]);
+        assert_eq!(factorize(1, &spf), vec![]);
+        assert_eq!(factorize(997, &spf), vec![997]);
+        assert_eq!(factorize(1000, &spf), vec![2, 2, 2, 5, 5, 5]);
+        for n in 1..=1000 {
+            let factors = factorize(n, &spf);
+            assert_eq!(factors.iter().product::<usize>(), n);
+            assert!(factors.iter().all(|&p| is_prime(p as u64)));
+        }
+    }
+
+    #[test]
+    fn miller_rabin_matches_sieve() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(104_729));
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(3_215_031_751)); // strong pseudoprime to bases 2, 3, 5, 7
+        assert!(!is_prime(1_000_000_007 * 998_244_353));
+
+        let table = sieve(10_000);
+        for (n, &prime) in table.iter().enumerate() {
+            assert_eq!(is_prime(n as u64), prime);
+        }
+    }
+}
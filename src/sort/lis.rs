@@ -0,0 +1,152 @@
+//! This module contains the longest increasing subsequence
+//! computed with patience sorting and binary search.
+
+/// Return the length of the longest strictly increasing
+/// subsequence of `array` in O(n log n).
+///
+/// Elements are dealt onto piles: each goes onto the leftmost pile
+/// whose top is not less than it, or starts a new pile. Pile tops
+/// stay sorted, so the pile is found by binary search, and the
+/// number of piles is the answer.
+///
+/// # Examples
+/// ```
+/// use ralgo::sort::lis_length;
+/// assert_eq!(lis_length(&[10, 9, 2, 5, 3, 7, 101, 18]), 4);
+/// assert_eq!(lis_length(&[1, 1, 1]), 1);
+/// ```
+pub fn lis_length<T: Ord>(array: &[T]) -> usize {
+    let mut tops: Vec<&T> = Vec::new();
+    for x in array {
+        let pile = tops.partition_point(|&top| top < x);
+        if pile == tops.len() {
+            tops.push(x);
+        } else {
+            tops[pile] = x;
+        }
+    }
+    tops.len()
+}
+
+/// Return the length of the longest non-decreasing subsequence
+/// of `array` in O(n log n). Unlike `lis_length`, equal elements
+/// may follow each other.
+///
+/// # Examples
+/// ```
+/// use ralgo::sort::lis_length_non_decreasing;
+/// assert_eq!(lis_length_non_decreasing(&[1, 1, 1]), 3);
+/// assert_eq!(lis_length_non_decreasing(&[3, 1, 2, 2, 1]), 3);
+/// ```
+pub fn lis_length_non_decreasing<T: Ord>(array: &[T]) -> usize {
+    let mut tops: Vec<&T> = Vec::new();
+    for x in array {
+        let pile = tops.partition_point(|&top| top <= x);
+        if pile == tops.len() {
+            tops.push(x);
+        } else {
+            tops[pile] = x;
+        }
+    }
+    tops.len()
+}
+
+/// Return a longest strictly increasing subsequence of `array`
+/// in O(n log n). Every element remembers the top of the previous
+/// pile at the time it was placed, which links it to a subsequence
+/// one shorter ending before it.
+///
+/// # Examples
+/// ```
+/// use ralgo::sort::lis;
+/// assert_eq!(lis(&[10, 9, 2, 5, 3, 7, 101, 18]), vec![2, 3, 7, 18]);
+/// ```
+pub fn lis<T: Ord + Clone>(array: &[T]) -> Vec<T> {
+    let mut tops: Vec<usize> = Vec::new();
+    let mut prev = vec![usize::MAX; array.len()];
+    for (i, x) in array.iter().enumerate() {
+        let pile = tops.partition_point(|&top| array[top] < *x);
+        if pile > 0 {
+            prev[i] = tops[pile - 1];
+        }
+        if pile == tops.len() {
+            tops.push(i);
+        } else {
+            tops[pile] = i;
+        }
+    }
+
+    let mut result = Vec::with_capacity(tops.len());
+    let mut i = tops.last().cloned().unwrap_or(usize::MAX);
+    while i != usize::MAX {
+        result.push(array[i].clone());
+        i = prev[i];
+    }
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lis, lis_length, lis_length_non_decreasing};
+    use graph::random::XorShift;
+
+    /// The O(n²) dynamic programming over subsequence ends.
+    fn naive(array: &[i32], strict: bool) -> usize {
+        let mut best = vec![1; array.len()];
+        for i in 0..array.len() {
+            for j in 0..i {
+                if array[j] < array[i] || (!strict && array[j] == array[i]) {
+                    best[i] = best[i].max(best[j] + 1);
+                }
+            }
+        }
+        best.into_iter().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn known_examples_work() {
+        let array = [10, 9, 2, 5, 3, 7, 101, 18];
+        assert_eq!(lis_length(&array), 4);
+        assert_eq!(lis(&array).len(), 4);
+        assert_eq!(lis_length::<i32>(&[]), 0);
+        assert_eq!(lis::<i32>(&[]), vec![]);
+        assert_eq!(lis(&["b", "a", "c"]).len(), 2);
+    }
+
+    #[test]
+    fn monotone_arrays_work() {
+        let increasing: Vec<i32> = (0..50).collect();
+        assert_eq!(lis_length(&increasing), 50);
+        assert_eq!(lis(&increasing), increasing);
+        let decreasing: Vec<i32> = (0..50).rev().collect();
+        assert_eq!(lis_length(&decreasing), 1);
+        assert_eq!(lis_length_non_decreasing(&decreasing), 1);
+    }
+
+    #[test]
+    fn equal_elements_work() {
+        let equal = [7; 10];
+        assert_eq!(lis_length(&equal), 1);
+        assert_eq!(lis(&equal), vec![7]);
+        assert_eq!(lis_length_non_decreasing(&equal), 10);
+    }
+
+    #[test]
+    fn random_arrays_match_naive() {
+        let mut rng = XorShift::new(109);
+        for _ in 0..300 {
+            let array: Vec<i32> = (0..rng.next_below(30))
+                .map(|_| rng.next_below(10) as i32)
+                .collect();
+            assert_eq!(lis_length(&array), naive(&array, true));
+            assert_eq!(lis_length_non_decreasing(&array), naive(&array, false));
+
+            let subsequence = lis(&array);
+            assert_eq!(subsequence.len(), naive(&array, true));
+            assert!(subsequence.windows(2).all(|w| w[0] < w[1]));
+            let mut rest = array.iter();
+            assert!(subsequence.iter().all(|x| rest.any(|y| y == x)));
+        }
+    }
+}
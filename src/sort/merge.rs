@@ -12,7 +12,7 @@ pub fn mergesort<T: Ord + Copy>(array: &mut [T]) {
     }
 
     // Copy `array` into vector
-    let mut aux: Vec<T> = array.iter().map(|x| *x).collect();
+    let mut aux: Vec<T> = array.to_vec();
 
     let mut primary = &mut *array;
     let mut secondary = &mut aux[..];
@@ -30,21 +30,19 @@ pub fn mergesort<T: Ord + Copy>(array: &mut [T]) {
     }
 }
 
+/// Merge consecutive pairs of sorted chunks of length `step` from
+/// `input` into `output`. A trailing chunk without a pair is copied
+/// to the output as is.
 fn merge_intervals<T: Ord + Copy>(input: &[T], output: &mut [T], step: usize) {
     let mut input_chunks = input.chunks(step);
     let mut output_chunks = output.chunks_mut(2 * step);
 
-    loop {
-        let first = match input_chunks.next() {
-            Some(chunk) => chunk,
-            None => break,
-        };
-        let second = match input_chunks.next() {
-            Some(chunk) => chunk,
-            None => break,
-        };
+    while let Some(first) = input_chunks.next() {
         let write_to = output_chunks.next().unwrap();
-        merge(first, second, write_to);
+        match input_chunks.next() {
+            Some(second) => merge(first, second, write_to),
+            None => write_to.copy_from_slice(first),
+        }
     }
 }
 
@@ -57,10 +55,7 @@ fn merge<T: Ord + Copy>(first: &[T], second: &[T], output: &mut [T]) {
         if left.is_none() {
             *result = *right.unwrap();
             right = second_iter.next();
-        } else if right.is_none() {
-            *result = *left.unwrap();
-            left = first_iter.next();
-        } else if left.unwrap().cmp(right.unwrap()) == Ordering::Less {
+        } else if right.is_none() || left.unwrap().cmp(right.unwrap()) == Ordering::Less {
             *result = *left.unwrap();
             left = first_iter.next();
         } else {
@@ -70,9 +65,74 @@ fn merge<T: Ord + Copy>(first: &[T], second: &[T], output: &mut [T]) {
     }
 }
 
+/// The bottom-up mergesort implementation for elements that
+/// are `Clone` but not `Copy`, such as `String` or `Vec<T>`.
+/// The sort is stable.
+///
+/// # Examples
+/// ```
+/// use ralgo::sort::mergesort_clone;
+/// let mut words = vec!["pear".to_string(), "fig".to_string(), "apple".to_string()];
+/// mergesort_clone(&mut words);
+/// assert_eq!(words, vec!["apple", "fig", "pear"]);
+/// ```
+pub fn mergesort_clone<T: Ord + Clone>(array: &mut [T]) {
+    if array.len() < 2 {
+        return;
+    }
+
+    let mut aux: Vec<T> = array.to_vec();
+
+    let mut primary = &mut *array;
+    let mut secondary = &mut aux[..];
+    let mut step = 1;
+    let mut swapped = false;
+    while step < primary.len() {
+        merge_intervals_clone(primary, secondary, step);
+        std::mem::swap(&mut primary, &mut secondary);
+        swapped = !swapped;
+        step *= 2;
+    }
+
+    if swapped {
+        secondary.clone_from_slice(primary);
+    }
+}
+
+/// `merge_intervals` for `Clone` elements. A trailing chunk without
+/// a pair is cloned to the output as is.
+fn merge_intervals_clone<T: Ord + Clone>(input: &[T], output: &mut [T], step: usize) {
+    let mut input_chunks = input.chunks(step);
+    let mut output_chunks = output.chunks_mut(2 * step);
+
+    while let Some(first) = input_chunks.next() {
+        let write_to = output_chunks.next().unwrap();
+        match input_chunks.next() {
+            Some(second) => merge_clone(first, second, write_to),
+            None => write_to.clone_from_slice(first),
+        }
+    }
+}
+
+/// Stable `merge` for `Clone` elements: of two equal elements
+/// the one from `first` goes first.
+fn merge_clone<T: Ord + Clone>(first: &[T], second: &[T], output: &mut [T]) {
+    let (mut i, mut j) = (0, 0);
+    for result in output.iter_mut() {
+        if j == second.len() || (i < first.len() && first[i] <= second[j]) {
+            *result = first[i].clone();
+            i += 1;
+        } else {
+            *result = second[j].clone();
+            j += 1;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{merge, merge_intervals, mergesort};
+    use super::{merge, merge_intervals, mergesort, mergesort_clone};
+    use std::cmp::Ordering;
 
     #[test]
     fn merge_works() {
@@ -104,6 +164,9 @@ mod tests {
         test_merge_intervals(vec![1, 4, 2, 3], 2, vec![1, 2, 3, 4]);
         test_merge_intervals(vec![3, 4, 1, 2], 2, vec![1, 2, 3, 4]);
         test_merge_intervals(vec![4, 3, 2, 1], 1, vec![3, 4, 1, 2]);
+        test_merge_intervals(vec![5, 3, 1], 1, vec![3, 5, 1]);
+        test_merge_intervals(vec![2, 6, 1, 5, 3, 4], 2, vec![1, 2, 5, 6, 3, 4]);
+        test_merge_intervals(vec![1, 2, 4, 6, 3], 4, vec![1, 2, 3, 4, 6]);
     }
 
     #[test]
@@ -123,4 +186,75 @@ mod tests {
         test_mergesort(vec![5, 3, 7, 4, 2, 2, 2, 3]);
         test_mergesort(vec![9, 8, 7, 6, 5, 4, 3, 2, 1]);
     }
+
+    #[test]
+    fn mergesort_keeps_unpaired_chunks() {
+        let mut input = [5, 3, 1];
+        mergesort(&mut input);
+        assert_eq!(input, [1, 3, 5]);
+
+        // The auxiliary buffer starts as a copy of the input, so only
+        // distinct values reveal a lost trailing chunk.
+        for len in 1..40 {
+            let mut input: Vec<i32> = (0..len).rev().collect();
+            mergesort(&mut input);
+            assert_eq!(input, (0..len).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn mergesort_clone_sorts_strings() {
+        fn test_mergesort_clone(input: &[&str]) {
+            let mut input: Vec<String> = input.iter().map(|s| s.to_string()).collect();
+            let mut copy = input.clone();
+            copy.sort();
+            mergesort_clone(&mut input);
+            assert_eq!(input, copy);
+        }
+
+        test_mergesort_clone(&[]);
+        test_mergesort_clone(&["b"]);
+        test_mergesort_clone(&["b", "a"]);
+        test_mergesort_clone(&["pear", "fig", "apple", "kiwi", "fig", "banana"]);
+        test_mergesort_clone(&["e", "d", "c", "b", "a", "", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn mergesort_clone_sorts_vectors() {
+        let mut input = vec![vec![3, 1], vec![], vec![2], vec![3], vec![1, 2, 3], vec![2]];
+        let mut copy = input.clone();
+        copy.sort();
+        mergesort_clone(&mut input);
+        assert_eq!(input, copy);
+    }
+
+    #[test]
+    fn mergesort_clone_is_stable() {
+        // Compares words by length only, so equal keys are distinguishable.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        struct ByLen(String);
+
+        impl PartialOrd for ByLen {
+            fn partial_cmp(&self, other: &ByLen) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for ByLen {
+            fn cmp(&self, other: &ByLen) -> Ordering {
+                self.0.len().cmp(&other.0.len())
+            }
+        }
+
+        let words = [
+            "bb", "a", "cc", "d", "eee", "ff", "g", "hhh", "ii", "j", "kk",
+        ];
+        let mut input: Vec<ByLen> = words.iter().map(|w| ByLen(w.to_string())).collect();
+        let mut copy = input.clone();
+        copy.sort();
+        mergesort_clone(&mut input);
+        let sorted: Vec<String> = input.into_iter().map(|w| w.0).collect();
+        let expected: Vec<String> = copy.into_iter().map(|w| w.0).collect();
+        assert_eq!(sorted, expected);
+    }
 }
@@ -1,3 +1,5 @@
+pub mod lis;
 pub mod merge;
 
-pub use self::merge::mergesort;
+pub use self::lis::{lis, lis_length, lis_length_non_decreasing};
+pub use self::merge::{mergesort, mergesort_clone};
@@ -0,0 +1,234 @@
+//! This module contains the Aho–Corasick automaton for
+//! finding all occurrences of several patterns in a text
+//! in a single pass.
+
+use std::collections::VecDeque;
+
+const NONE: usize = usize::MAX;
+
+/// The Aho–Corasick automaton over bytes. The trie of the patterns
+/// is completed into a deterministic automaton, so every byte of
+/// the text is processed with a single table lookup.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::AhoCorasick;
+/// let automaton = AhoCorasick::build(&[b"he", b"she", b"hers"]);
+/// let matches: Vec<(usize, usize, usize)> = automaton.search(b"ushers").collect();
+/// assert_eq!(matches, vec![(1, 4, 1), (2, 4, 0), (2, 6, 2)]);
+/// assert_eq!(automaton.count_matches(b"ushers"), 3);
+/// ```
+pub struct AhoCorasick {
+    goto: Vec<[usize; 256]>,
+    dict_link: Vec<usize>,
+    outputs: Vec<Vec<usize>>,
+    match_count: Vec<usize>,
+    pattern_len: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Return the automaton recognizing given patterns. Failure links
+    /// are computed by a breadth-first traversal of the trie.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - the patterns to look for; an empty pattern
+    ///   matches at every position of the text.
+    ///
+    pub fn build(patterns: &[&[u8]]) -> AhoCorasick {
+        let mut goto = vec![[NONE; 256]];
+        let mut outputs = vec![Vec::new()];
+        for (index, pattern) in patterns.iter().enumerate() {
+            let mut node = 0;
+            for &c in pattern.iter() {
+                if goto[node][c as usize] == NONE {
+                    goto[node][c as usize] = goto.len();
+                    goto.push([NONE; 256]);
+                    outputs.push(Vec::new());
+                }
+                node = goto[node][c as usize];
+            }
+            outputs[node].push(index);
+        }
+
+        let n_nodes = goto.len();
+        let mut fail = vec![0; n_nodes];
+        let mut dict_link = vec![NONE; n_nodes];
+        let mut match_count = vec![0; n_nodes];
+        match_count[0] = outputs[0].len();
+
+        let mut queue = VecDeque::new();
+        for next in goto[0].iter_mut() {
+            match *next {
+                NONE => *next = 0,
+                child => queue.push_back(child),
+            }
+        }
+        while let Some(node) = queue.pop_front() {
+            let link = fail[node];
+            dict_link[node] = if outputs[link].is_empty() {
+                dict_link[link]
+            } else {
+                link
+            };
+            match_count[node] = outputs[node].len() + match_count[link];
+
+            let fallback = goto[link];
+            for (next, &other) in goto[node].iter_mut().zip(fallback.iter()) {
+                match *next {
+                    NONE => *next = other,
+                    child => {
+                        fail[child] = other;
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+
+        AhoCorasick {
+            goto,
+            dict_link,
+            outputs,
+            match_count,
+            pattern_len: patterns.iter().map(|p| p.len()).collect(),
+        }
+    }
+
+    /// Return an iterator over all occurrences of the patterns in
+    /// `text` as triples `(start, end, pattern_index)`, where
+    /// `text[start..end]` equals the pattern. Overlapping occurrences
+    /// are all reported, ordered by `end` and then from the longest
+    /// pattern to the shortest.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - the text to search in.
+    ///
+    pub fn search<'a>(&'a self, text: &'a [u8]) -> Matches<'a> {
+        Matches {
+            automaton: self,
+            text,
+            node: 0,
+            pos: 0,
+            cursor: 0,
+            index: 0,
+        }
+    }
+
+    /// Return the number of occurrences of the patterns in `text`
+    /// without enumerating them.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - the text to search in.
+    ///
+    pub fn count_matches(&self, text: &[u8]) -> usize {
+        let mut node = 0;
+        let mut count = self.match_count[0];
+        for &c in text {
+            node = self.goto[node][c as usize];
+            count += self.match_count[node];
+        }
+        count
+    }
+}
+
+/// The iterator returned by `AhoCorasick::search`.
+pub struct Matches<'a> {
+    automaton: &'a AhoCorasick,
+    text: &'a [u8],
+    node: usize,
+    pos: usize,
+    cursor: usize,
+    index: usize,
+}
+
+impl<'a> Iterator for Matches<'a> {
+    type Item = (usize, usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize, usize)> {
+        let automaton = self.automaton;
+        loop {
+            if self.cursor != NONE {
+                if let Some(&pattern) = automaton.outputs[self.cursor].get(self.index) {
+                    self.index += 1;
+                    let start = self.pos - automaton.pattern_len[pattern];
+                    return Some((start, self.pos, pattern));
+                }
+                self.cursor = automaton.dict_link[self.cursor];
+                self.index = 0;
+                continue;
+            }
+            if self.pos == self.text.len() {
+                return None;
+            }
+            self.node = automaton.goto[self.node][self.text[self.pos] as usize];
+            self.pos += 1;
+            self.cursor = self.node;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AhoCorasick;
+
+    fn naive(text: &[u8], patterns: &[&[u8]]) -> Vec<(usize, usize, usize)> {
+        let mut matches = Vec::new();
+        for (index, pattern) in patterns.iter().enumerate() {
+            for start in 0..(text.len() + 1).saturating_sub(pattern.len()) {
+                if text[start..].starts_with(pattern) {
+                    matches.push((start, start + pattern.len(), index));
+                }
+            }
+        }
+        matches.sort();
+        matches
+    }
+
+    fn search(patterns: &[&[u8]], text: &[u8]) -> Vec<(usize, usize, usize)> {
+        let automaton = AhoCorasick::build(patterns);
+        let mut matches: Vec<(usize, usize, usize)> = automaton.search(text).collect();
+        assert_eq!(automaton.count_matches(text), matches.len());
+        matches.sort();
+        matches
+    }
+
+    #[test]
+    fn single_pattern_works() {
+        let text = b"abababcabab";
+        assert_eq!(search(&[b"abab"], text), naive(text, &[b"abab"]));
+        assert_eq!(search(&[b"abab"], text).len(), 3);
+    }
+
+    #[test]
+    fn overlapping_and_suffix_patterns_work() {
+        let patterns: &[&[u8]] = &[b"a", b"ab", b"bab", b"bc", b"bca", b"c", b"caa"];
+        let text = b"abccab";
+        assert_eq!(search(patterns, text), naive(text, patterns));
+
+        let patterns: &[&[u8]] = &[b"announce", b"nce", b"ce", b"e", b"nounce"];
+        let text = b"announcement of announces";
+        assert_eq!(search(patterns, text), naive(text, patterns));
+    }
+
+    #[test]
+    fn no_matches_work() {
+        assert_eq!(search(&[b"abc", b"d"], b""), vec![]);
+        assert_eq!(search(&[b"abc", b"d"], b"abababcbcb"), vec![(4, 7, 0)]);
+        assert_eq!(search(&[b"xyz", b"q"], b"abababcbcb"), vec![]);
+        assert_eq!(search(&[], b"abc"), vec![]);
+    }
+
+    #[test]
+    fn duplicate_and_empty_patterns_work() {
+        assert_eq!(
+            search(&[b"ab", b"ab"], b"abab"),
+            vec![(0, 2, 0), (0, 2, 1), (2, 4, 0), (2, 4, 1)]
+        );
+        assert_eq!(
+            search(&[b"", b"b"], b"ab"),
+            vec![(0, 0, 0), (1, 1, 0), (1, 2, 1), (2, 2, 0)]
+        );
+    }
+}
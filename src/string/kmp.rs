@@ -0,0 +1,164 @@
+//! This module contains the Knuth–Morris–Pratt substring search
+//! and the failure (prefix) function it is built on.
+
+/// Return the failure function of `pattern`: `fail[i]` is the length
+/// of the longest proper prefix of `pattern[..=i]` that is also its
+/// suffix. Runs in O(m).
+///
+/// # Examples
+/// ```
+/// use ralgo::string::compute_failure;
+/// assert_eq!(compute_failure(b"abacaba"), vec![0, 0, 1, 0, 1, 2, 3]);
+/// ```
+pub fn compute_failure(pattern: &[u8]) -> Vec<usize> {
+    let mut fail = vec![0; pattern.len()];
+    let mut k = 0;
+    for i in 1..pattern.len() {
+        while k > 0 && pattern[i] != pattern[k] {
+            k = fail[k - 1];
+        }
+        if pattern[i] == pattern[k] {
+            k += 1;
+        }
+        fail[i] = k;
+    }
+    fail
+}
+
+/// Return the starting positions of all (possibly overlapping)
+/// occurrences of `pattern` in `text` in increasing order. An empty
+/// pattern occurs at every position `0..=text.len()`.
+///
+/// Runs in O(n + m).
+///
+/// # Arguments
+///
+/// * `text` - the text to search in;
+/// * `pattern` - the substring to look for.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::kmp_search;
+/// assert_eq!(kmp_search(b"aaaa", b"aa"), vec![0, 1, 2]);
+/// ```
+pub fn kmp_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let mut positions = Vec::new();
+    for_each_match(text, pattern, |end| positions.push(end - pattern.len()));
+    positions
+}
+
+/// Return the number of (possibly overlapping) occurrences of
+/// `pattern` in `text`. Runs in O(n + m) without allocating
+/// space for the positions.
+///
+/// # Arguments
+///
+/// * `text` - the text to search in;
+/// * `pattern` - the substring to look for.
+///
+pub fn kmp_count(text: &[u8], pattern: &[u8]) -> usize {
+    let mut count = 0;
+    for_each_match(text, pattern, |_| count += 1);
+    count
+}
+
+/// Return the minimal period of `s`: the smallest `p > 0` such
+/// that `s[i] == s[i + p]` for all valid `i` (0 for an empty string).
+///
+/// # Examples
+/// ```
+/// use ralgo::string::period;
+/// assert_eq!(period(b"abcabca"), 3);
+/// assert_eq!(period(b"abcd"), 4);
+/// ```
+pub fn period(s: &[u8]) -> usize {
+    match compute_failure(s).last() {
+        Some(&border) => s.len() - border,
+        None => 0,
+    }
+}
+
+/// Call `on_match` with the end position of every occurrence
+/// of `pattern` in `text`.
+fn for_each_match<F: FnMut(usize)>(text: &[u8], pattern: &[u8], mut on_match: F) {
+    let m = pattern.len();
+    if m == 0 {
+        (0..=text.len()).for_each(on_match);
+        return;
+    }
+    let fail = compute_failure(pattern);
+    let mut k = 0;
+    for (i, &c) in text.iter().enumerate() {
+        while k > 0 && c != pattern[k] {
+            k = fail[k - 1];
+        }
+        if c == pattern[k] {
+            k += 1;
+        }
+        if k == m {
+            on_match(i + 1);
+            k = fail[k - 1];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_failure, kmp_count, kmp_search, period};
+
+    fn find_all(text: &str, pattern: &str) -> Vec<usize> {
+        let mut positions = Vec::new();
+        let mut start = 0;
+        while let Some(pos) = text[start..].find(pattern) {
+            positions.push(start + pos);
+            start += pos + 1;
+        }
+        positions
+    }
+
+    #[test]
+    fn overlapping_occurrences_work() {
+        assert_eq!(kmp_search(b"aaaa", b"aa"), vec![0, 1, 2]);
+        assert_eq!(kmp_count(b"aaaa", b"aa"), 3);
+        assert_eq!(kmp_search(b"abababab", b"abab"), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn edge_cases_work() {
+        assert_eq!(kmp_search(b"abcdef", b"xyz"), vec![]);
+        assert_eq!(kmp_search(b"ab", b"abc"), vec![]);
+        assert_eq!(kmp_count(b"ab", b"abc"), 0);
+        assert_eq!(kmp_search(b"abc", b"abc"), vec![0]);
+        assert_eq!(kmp_search(b"", b"a"), vec![]);
+        assert_eq!(kmp_search(b"ab", b""), vec![0, 1, 2]);
+        assert_eq!(compute_failure(b""), vec![]);
+    }
+
+    #[test]
+    fn search_matches_str_find() {
+        let text = "the cat sat on the mat with another cat, cats and a catalogue";
+        for pattern in ["cat", "at", "the", "a", " ", "cats", "dog", "e m"].iter() {
+            assert_eq!(
+                kmp_search(text.as_bytes(), pattern.as_bytes()),
+                find_all(text, pattern)
+            );
+            assert_eq!(
+                kmp_search(text.as_bytes(), pattern.as_bytes())
+                    .first()
+                    .cloned(),
+                text.find(pattern)
+            );
+        }
+    }
+
+    #[test]
+    fn period_works() {
+        assert_eq!(period(b""), 0);
+        assert_eq!(period(b"a"), 1);
+        assert_eq!(period(b"aaaa"), 1);
+        assert_eq!(period(b"abab"), 2);
+        assert_eq!(period(b"ababa"), 2);
+        assert_eq!(period(b"aabaaba"), 3);
+        assert_eq!(period(b"abcab"), 3);
+    }
+}
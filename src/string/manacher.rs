@@ -0,0 +1,130 @@
+//! This module contains Manacher's algorithm for finding
+//! the palindromes centered at every position of a string.
+
+/// Return the Manacher array of `s`. The string is virtually
+/// transformed into `#s[0]#s[1]#...#s[n-1]#` of length `2n + 1`,
+/// and `p[i]` is the radius of the longest palindrome of the
+/// transformed string centered at `i`. This radius equals the length
+/// of the corresponding palindrome of `s`, which starts at
+/// `(i - p[i]) / 2`. Runs in O(n).
+///
+/// # Examples
+/// ```
+/// use ralgo::string::manacher;
+/// assert_eq!(manacher(b"aba"), vec![0, 1, 0, 3, 0, 1, 0]);
+/// ```
+pub fn manacher(s: &[u8]) -> Vec<usize> {
+    let m = 2 * s.len() + 1;
+    let at = |j: usize| if j % 2 == 1 { Some(s[j / 2]) } else { None };
+
+    let mut p = vec![0; m];
+    // The palindrome centered at `center` reaches furthest right, up to `right`.
+    let (mut center, mut right) = (0, 0);
+    for i in 0..m {
+        let mut k = if i < right {
+            p[2 * center - i].min(right - i)
+        } else {
+            0
+        };
+        while k < i && i + k + 1 < m && at(i - k - 1) == at(i + k + 1) {
+            k += 1;
+        }
+        p[i] = k;
+        if i + k > right {
+            center = i;
+            right = i + k;
+        }
+    }
+    p
+}
+
+/// Return the bounds `(start, end)` of the leftmost longest
+/// palindromic substring `s[start..end]` (`(0, 0)` for an empty `s`).
+///
+/// # Examples
+/// ```
+/// use ralgo::string::longest_palindrome_substr;
+/// assert_eq!(longest_palindrome_substr(b"xabbaab"), (1, 5));
+/// ```
+pub fn longest_palindrome_substr(s: &[u8]) -> (usize, usize) {
+    let p = manacher(s);
+    let mut best = 0;
+    for (i, &radius) in p.iter().enumerate() {
+        if radius > p[best] {
+            best = i;
+        }
+    }
+    let start = (best - p[best]) / 2;
+    (start, start + p[best])
+}
+
+/// Return the number of palindromic substrings of `s`, counting
+/// every occurrence separately. Runs in O(n).
+///
+/// # Examples
+/// ```
+/// use ralgo::string::count_palindromic_substrings;
+/// // "a", "b", "a", "aba"
+/// assert_eq!(count_palindromic_substrings(b"aba"), 4);
+/// ```
+pub fn count_palindromic_substrings(s: &[u8]) -> usize {
+    manacher(s).iter().map(|&radius| radius.div_ceil(2)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_palindromic_substrings, longest_palindrome_substr, manacher};
+    use graph::random::XorShift;
+
+    fn is_palindrome(s: &[u8]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    #[test]
+    fn whole_string_palindromes_work() {
+        assert_eq!(longest_palindrome_substr(b"racecar"), (0, 7));
+        assert_eq!(longest_palindrome_substr(b"abacaba"), (0, 7));
+        assert_eq!(longest_palindrome_substr(b"aabaa"), (0, 5));
+        assert_eq!(longest_palindrome_substr(b"xracecary"), (1, 8));
+        assert_eq!(longest_palindrome_substr(b"abcd"), (0, 1));
+    }
+
+    #[test]
+    fn counts_work() {
+        assert_eq!(count_palindromic_substrings(b"abcdef"), 6);
+        assert_eq!(count_palindromic_substrings(b"aaaaa"), 5 * 6 / 2);
+        assert_eq!(count_palindromic_substrings(b"aabaa"), 9);
+    }
+
+    #[test]
+    fn empty_string_works() {
+        assert_eq!(manacher(b""), vec![0]);
+        assert_eq!(longest_palindrome_substr(b""), (0, 0));
+        assert_eq!(count_palindromic_substrings(b""), 0);
+    }
+
+    #[test]
+    fn random_strings_match_naive() {
+        let mut rng = XorShift::new(11);
+        for _ in 0..200 {
+            let s: Vec<u8> = (0..rng.next_below(30))
+                .map(|_| b'a' + rng.next_below(2) as u8)
+                .collect();
+            let n = s.len();
+            let mut count = 0;
+            let mut longest = 0;
+            for i in 0..n {
+                for j in i + 1..=n {
+                    if is_palindrome(&s[i..j]) {
+                        count += 1;
+                        longest = longest.max(j - i);
+                    }
+                }
+            }
+            assert_eq!(count_palindromic_substrings(&s), count);
+            let (start, end) = longest_palindrome_substr(&s);
+            assert_eq!(end - start, longest);
+            assert!(is_palindrome(&s[start..end]));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod aho_corasick;
+pub mod kmp;
+pub mod manacher;
+pub mod rolling_hash;
+pub mod suffix_array;
+pub mod trie;
+pub mod zfunction;
+
+pub use self::aho_corasick::AhoCorasick;
+pub use self::kmp::{compute_failure, kmp_count, kmp_search, period};
+pub use self::manacher::{count_palindromic_substrings, longest_palindrome_substr, manacher};
+pub use self::rolling_hash::{rabin_karp_search, search_multiple, RollingHash};
+pub use self::suffix_array::{build_lcp_array, build_suffix_array, suffix_array_search};
+pub use self::trie::{longest_common_prefix, Trie};
+pub use self::zfunction::{z_function, z_search};
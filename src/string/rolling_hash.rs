@@ -0,0 +1,221 @@
+//! This module contains polynomial rolling hashes of byte strings
+//! and the Rabin–Karp substring search built on top of them.
+
+use std::collections::HashMap;
+
+const BASE: u64 = 131;
+const MODULUS: u64 = (1 << 61) - 1;
+
+/// Polynomial hashes of all prefixes of a text, allowing to hash
+/// any of its substrings in O(1). The hash of `s` is
+/// `s[0] * base^(len-1) + s[1] * base^(len-2) + ... + s[len-1]`
+/// modulo `modulus`.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::RollingHash;
+/// let hash = RollingHash::new(b"abcabc", 131, 1_000_000_007);
+/// assert_eq!(hash.window_hash(0, 3), hash.window_hash(3, 3));
+/// assert_ne!(hash.window_hash(0, 3), hash.window_hash(1, 3));
+/// ```
+pub struct RollingHash {
+    prefix: Vec<u64>,
+    power: Vec<u64>,
+    modulus: u64,
+}
+
+impl RollingHash {
+    /// Return a new instance of RollingHash in O(n).
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - the text whose substrings will be hashed;
+    /// * `base` - the base of the polynomial;
+    /// * `modulus` - the modulus of the hash, preferably a large prime.
+    ///
+    /// # Panics
+    ///
+    /// If `modulus` is zero.
+    ///
+    pub fn new(text: &[u8], base: u64, modulus: u64) -> RollingHash {
+        assert!(modulus > 0, "modulus must be positive");
+        // Residues may exceed `u64::MAX / 2`, so sums and products
+        // are reduced in u128.
+        let m = modulus as u128;
+        let base = base as u128 % m;
+        let mut prefix = vec![0; text.len() + 1];
+        let mut power = vec![1 % modulus; text.len() + 1];
+        for (i, &c) in text.iter().enumerate() {
+            prefix[i + 1] = ((prefix[i] as u128 * base + c as u128) % m) as u64;
+            power[i + 1] = (power[i] as u128 * base % m) as u64;
+        }
+        RollingHash {
+            prefix,
+            power,
+            modulus,
+        }
+    }
+
+    /// Return the hash of `text[start..start + len]`. The hash of
+    /// an empty window is 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - the first position of the window;
+    /// * `len` - the length of the window.
+    ///
+    /// # Panics
+    ///
+    /// If the window does not fit in the text.
+    ///
+    pub fn window_hash(&self, start: usize, len: usize) -> u64 {
+        let m = self.modulus as u128;
+        let shifted = self.prefix[start] as u128 * self.power[len] as u128 % m;
+        ((self.prefix[start + len] as u128 + m - shifted) % m) as u64
+    }
+}
+
+/// Return the starting positions of all occurrences of `pattern`
+/// in `text` in increasing order. Every hash match is verified, so
+/// collisions never produce false positives.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::rabin_karp_search;
+/// assert_eq!(rabin_karp_search(b"abracadabra", b"abra"), vec![0, 7]);
+/// ```
+pub fn rabin_karp_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    search_with(text, &[pattern], BASE, MODULUS).pop().unwrap()
+}
+
+/// Return, for every pattern, the starting positions of all its
+/// occurrences in `text` in increasing order. Patterns of the same
+/// length are looked up together in a hash map, so the text is
+/// scanned once per distinct pattern length.
+///
+/// # Arguments
+///
+/// * `text` - the text to search in;
+/// * `patterns` - the substrings to look for.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::search_multiple;
+/// assert_eq!(
+///     search_multiple(b"abcbc", &[b"bc", b"c", b"cb"]),
+///     vec![vec![1, 3], vec![2, 4], vec![2]]
+/// );
+/// ```
+pub fn search_multiple(text: &[u8], patterns: &[&[u8]]) -> Vec<Vec<usize>> {
+    search_with(text, patterns, BASE, MODULUS)
+}
+
+/// `search_multiple` with explicit hash parameters.
+fn search_with(text: &[u8], patterns: &[&[u8]], base: u64, modulus: u64) -> Vec<Vec<usize>> {
+    let text_hash = RollingHash::new(text, base, modulus);
+    let mut by_len: HashMap<usize, HashMap<u64, Vec<usize>>> = HashMap::new();
+    for (index, pattern) in patterns.iter().enumerate() {
+        let hash = RollingHash::new(pattern, base, modulus).window_hash(0, pattern.len());
+        by_len
+            .entry(pattern.len())
+            .or_default()
+            .entry(hash)
+            .or_default()
+            .push(index);
+    }
+
+    let mut positions = vec![Vec::new(); patterns.len()];
+    for (&len, by_hash) in by_len.iter() {
+        if len > text.len() {
+            continue;
+        }
+        for start in 0..=text.len() - len {
+            if let Some(indices) = by_hash.get(&text_hash.window_hash(start, len)) {
+                for &index in indices {
+                    if &text[start..start + len] == patterns[index] {
+                        positions[index].push(start);
+                    }
+                }
+            }
+        }
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rabin_karp_search, search_multiple, search_with, RollingHash};
+    use graph::random::XorShift;
+    use string::AhoCorasick;
+
+    fn naive_hash(s: &[u8], base: u64, modulus: u64) -> u64 {
+        s.iter().fold(0, |h, &c| {
+            ((h as u128 * base as u128 + c as u128) % modulus as u128) as u64
+        })
+    }
+
+    #[test]
+    fn window_hash_matches_naive() {
+        let text = b"the quick brown fox jumps over the lazy dog";
+        let moduli = [
+            (131, 1_000_000_007),
+            (256, 97),
+            (31, (1 << 61) - 1),
+            (u64::MAX - 1, u64::MAX - 58),
+            (u64::MAX, u64::MAX),
+        ];
+        for &(base, modulus) in moduli.iter() {
+            let hash = RollingHash::new(text, base, modulus);
+            for start in 0..=text.len() {
+                for len in 0..=text.len() - start {
+                    assert_eq!(
+                        hash.window_hash(start, len),
+                        naive_hash(&text[start..start + len], base, modulus)
+                    );
+                }
+            }
+        }
+        assert_eq!(RollingHash::new(b"", 131, 7).window_hash(0, 0), 0);
+        assert_eq!(RollingHash::new(b"abc", 131, 7).window_hash(2, 0), 0);
+    }
+
+    #[test]
+    fn search_multiple_matches_aho_corasick() {
+        let mut rng = XorShift::new(3);
+        for _ in 0..100 {
+            let text: Vec<u8> = (0..rng.next_below(50))
+                .map(|_| b'a' + rng.next_below(3) as u8)
+                .collect();
+            let patterns: Vec<Vec<u8>> = (0..1 + rng.next_below(5))
+                .map(|_| {
+                    (0..1 + rng.next_below(4))
+                        .map(|_| b'a' + rng.next_below(3) as u8)
+                        .collect()
+                })
+                .collect();
+            let patterns: Vec<&[u8]> = patterns.iter().map(|p| &p[..]).collect();
+
+            let mut expected = vec![Vec::new(); patterns.len()];
+            for (start, _, index) in AhoCorasick::build(&patterns).search(&text) {
+                expected[index].push(start);
+            }
+            for positions in expected.iter_mut() {
+                positions.sort();
+            }
+            assert_eq!(search_multiple(&text, &patterns), expected);
+        }
+    }
+
+    #[test]
+    fn collisions_are_verified() {
+        // With modulus 1 every window collides with every pattern.
+        let text = b"abcabcab";
+        assert_eq!(
+            search_with(text, &[b"abc", b"cab", b"xyz"], 131, 1),
+            vec![vec![0, 3], vec![2, 5], vec![]]
+        );
+        assert_eq!(rabin_karp_search(text, b"bca"), vec![1, 4]);
+        assert_eq!(rabin_karp_search(text, b"abcabcabc"), vec![]);
+        assert_eq!(rabin_karp_search(b"ab", b""), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,311 @@
+//! This module contains the construction of suffix arrays with
+//! the SA-IS (induced sorting) algorithm, the LCP array with
+//! Kasai's algorithm and substring search over a suffix array.
+
+use std::ops::Range;
+
+/// Return the suffix array of `s`: the starting positions of all
+/// suffixes of `s` in lexicographic order, so that
+/// `s[sa[0]..] < s[sa[1]..] < ...`.
+///
+/// Implements the linear-time SA-IS algorithm.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::build_suffix_array;
+/// assert_eq!(build_suffix_array(b"banana"), vec![5, 3, 1, 0, 4, 2]);
+/// ```
+pub fn build_suffix_array(s: &[u8]) -> Vec<usize> {
+    let s: Vec<usize> = s.iter().map(|&c| c as usize).collect();
+    sa_is(&s, 255)
+}
+
+/// Return the LCP array of `s`: `lcp[i]` is the length of the longest
+/// common prefix of suffixes `s[sa[i]..]` and `s[sa[i + 1]..]`.
+/// The returned array has `s.len() - 1` elements (none for empty `s`).
+///
+/// Implements Kasai's algorithm in O(n).
+///
+/// # Arguments
+///
+/// * `s` - the string;
+/// * `sa` - the suffix array of `s`.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::{build_lcp_array, build_suffix_array};
+/// let sa = build_suffix_array(b"banana");
+/// assert_eq!(build_lcp_array(b"banana", &sa), vec![1, 3, 0, 0, 2]);
+/// ```
+pub fn build_lcp_array(s: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+
+    let mut lcp = vec![0; n - 1];
+    let mut h: usize = 0;
+    for i in 0..n {
+        h = h.saturating_sub(1);
+        if rank[i] == 0 {
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while i + h < n && j + h < n && s[i + h] == s[j + h] {
+            h += 1;
+        }
+        lcp[rank[i] - 1] = h;
+    }
+    lcp
+}
+
+/// Return the range of indices into `sa` of the suffixes that start
+/// with `pattern`; `sa[range]` are all occurrences of `pattern` in `s`.
+/// Uses binary search in O(m log n).
+///
+/// # Arguments
+///
+/// * `s` - the string;
+/// * `sa` - the suffix array of `s`;
+/// * `pattern` - the substring to look for.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::{build_suffix_array, suffix_array_search};
+/// let sa = build_suffix_array(b"banana");
+/// let range = suffix_array_search(b"banana", &sa, b"ana");
+/// assert_eq!(&sa[range], &[3, 1]);
+/// ```
+pub fn suffix_array_search(s: &[u8], sa: &[usize], pattern: &[u8]) -> Range<usize> {
+    let prefix = |p: usize| {
+        let suffix = &s[p..];
+        &suffix[..suffix.len().min(pattern.len())]
+    };
+    let start = sa.partition_point(|&p| prefix(p) < pattern);
+    let end = sa.partition_point(|&p| prefix(p) <= pattern);
+    start..end
+}
+
+/// SA-IS over the alphabet `0..=upper`.
+fn sa_is(s: &[usize], upper: usize) -> Vec<usize> {
+    let n = s.len();
+    match n {
+        0 => return Vec::new(),
+        1 => return vec![0],
+        2 => return if s[0] < s[1] { vec![0, 1] } else { vec![1, 0] },
+        _ => {}
+    }
+
+    // `is_s[i]` tells whether suffix `i` is S-type (smaller than
+    // the next suffix) or L-type.
+    let mut is_s = vec![false; n];
+    for i in (0..n - 1).rev() {
+        is_s[i] = if s[i] == s[i + 1] {
+            is_s[i + 1]
+        } else {
+            s[i] < s[i + 1]
+        };
+    }
+
+    // Bucket boundaries: L-type suffixes go to the start of the bucket
+    // of their first character, S-type ones to its end.
+    let mut sum_l = vec![0; upper + 1];
+    let mut sum_s = vec![0; upper + 1];
+    for i in 0..n {
+        if !is_s[i] {
+            sum_s[s[i]] += 1;
+        } else {
+            sum_l[s[i] + 1] += 1;
+        }
+    }
+    for c in 0..=upper {
+        sum_s[c] += sum_l[c];
+        if c < upper {
+            sum_l[c + 1] += sum_s[c];
+        }
+    }
+    let buckets = Buckets {
+        s,
+        is_s: &is_s,
+        sum_l: &sum_l,
+        sum_s: &sum_s,
+    };
+
+    // Leftmost S-type positions split the string into LMS substrings.
+    let mut lms_index = vec![usize::MAX; n + 1];
+    let mut lms = Vec::new();
+    for i in 1..n {
+        if !is_s[i - 1] && is_s[i] {
+            lms_index[i] = lms.len();
+            lms.push(i);
+        }
+    }
+    let m = lms.len();
+
+    let mut sa = vec![0; n];
+    buckets.induce(&lms, &mut sa);
+
+    if m > 0 {
+        let mut sorted_lms: Vec<usize> = sa
+            .iter()
+            .cloned()
+            .filter(|&v| lms_index[v] != usize::MAX)
+            .collect();
+
+        // Name the LMS substrings by their rank and sort them recursively.
+        let mut reduced = vec![0; m];
+        let mut name = 0;
+        reduced[lms_index[sorted_lms[0]]] = 0;
+        for i in 1..m {
+            let mut l = sorted_lms[i - 1];
+            let mut r = sorted_lms[i];
+            let end_l = lms.get(lms_index[l] + 1).cloned().unwrap_or(n);
+            let end_r = lms.get(lms_index[r] + 1).cloned().unwrap_or(n);
+            let same = if end_l - l != end_r - r {
+                false
+            } else {
+                while l < end_l && s[l] == s[r] {
+                    l += 1;
+                    r += 1;
+                }
+                l != n && s[l] == s[r]
+            };
+            if !same {
+                name += 1;
+            }
+            reduced[lms_index[sorted_lms[i]]] = name;
+        }
+
+        let reduced_sa = sa_is(&reduced, name);
+        for (i, &p) in reduced_sa.iter().enumerate() {
+            sorted_lms[i] = lms[p];
+        }
+        buckets.induce(&sorted_lms, &mut sa);
+    }
+    sa
+}
+
+/// The data needed for the induced sorting step of SA-IS.
+struct Buckets<'a> {
+    s: &'a [usize],
+    is_s: &'a [bool],
+    sum_l: &'a [usize],
+    sum_s: &'a [usize],
+}
+
+impl<'a> Buckets<'a> {
+    /// Place the LMS suffixes in the given order, then induce the
+    /// order of L-type and S-type suffixes from them.
+    fn induce(&self, lms: &[usize], sa: &mut [usize]) {
+        let s = self.s;
+        let n = s.len();
+        for x in sa.iter_mut() {
+            *x = usize::MAX;
+        }
+
+        let mut buf = self.sum_s.to_vec();
+        for &d in lms {
+            if d != n {
+                sa[buf[s[d]]] = d;
+                buf[s[d]] += 1;
+            }
+        }
+
+        buf.copy_from_slice(self.sum_l);
+        sa[buf[s[n - 1]]] = n - 1;
+        buf[s[n - 1]] += 1;
+        for i in 0..n {
+            let v = sa[i];
+            if v != usize::MAX && v >= 1 && !self.is_s[v - 1] {
+                sa[buf[s[v - 1]]] = v - 1;
+                buf[s[v - 1]] += 1;
+            }
+        }
+
+        buf.copy_from_slice(self.sum_l);
+        for i in (0..n).rev() {
+            let v = sa[i];
+            if v != usize::MAX && v >= 1 && self.is_s[v - 1] {
+                buf[s[v - 1] + 1] -= 1;
+                sa[buf[s[v - 1] + 1]] = v - 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_lcp_array, build_suffix_array, suffix_array_search};
+    use graph::random::XorShift;
+
+    fn naive_suffix_array(s: &[u8]) -> Vec<usize> {
+        let mut sa: Vec<usize> = (0..s.len()).collect();
+        sa.sort_by(|&a, &b| s[a..].cmp(&s[b..]));
+        sa
+    }
+
+    fn naive_lcp_array(s: &[u8], sa: &[usize]) -> Vec<usize> {
+        sa.windows(2)
+            .map(|w| {
+                s[w[0]..]
+                    .iter()
+                    .zip(s[w[1]..].iter())
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            })
+            .collect()
+    }
+
+    fn random_string(rng: &mut XorShift, len: usize, alphabet: usize) -> Vec<u8> {
+        (0..len)
+            .map(|_| b'a' + rng.next_below(alphabet) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn known_examples_work() {
+        assert_eq!(build_suffix_array(b""), vec![]);
+        assert_eq!(build_suffix_array(b"x"), vec![0]);
+        assert_eq!(build_suffix_array(b"aaaaa"), vec![4, 3, 2, 1, 0]);
+        assert_eq!(build_suffix_array(b"abcdef"), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(build_suffix_array(b"banana"), vec![5, 3, 1, 0, 4, 2]);
+        assert_eq!(
+            build_lcp_array(b"aaaaa", &build_suffix_array(b"aaaaa")),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn random_strings_match_naive() {
+        let mut rng = XorShift::new(17);
+        for _ in 0..300 {
+            let len = rng.next_below(60);
+            let alphabet = 1 + rng.next_below(4);
+            let s = random_string(&mut rng, len, alphabet);
+            let sa = build_suffix_array(&s);
+            assert_eq!(sa, naive_suffix_array(&s));
+            assert_eq!(build_lcp_array(&s, &sa), naive_lcp_array(&s, &sa));
+        }
+    }
+
+    #[test]
+    fn search_works() {
+        let s = b"mississippi";
+        let sa = build_suffix_array(s);
+        let occurrences = |pattern: &[u8]| {
+            let mut found = sa[suffix_array_search(s, &sa, pattern)].to_vec();
+            found.sort();
+            found
+        };
+        assert_eq!(occurrences(b"ssi"), vec![2, 5]);
+        assert_eq!(occurrences(b"i"), vec![1, 4, 7, 10]);
+        assert_eq!(occurrences(b"mississippi"), vec![0]);
+        assert_eq!(occurrences(b"mississippis"), vec![]);
+        assert_eq!(occurrences(b"x"), vec![]);
+        assert_eq!(occurrences(b"").len(), s.len());
+    }
+}
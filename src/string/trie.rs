@@ -0,0 +1,243 @@
+//! This module defines a trie (prefix tree) of byte strings
+//! with its nodes stored in a single arena vector.
+
+/// A node of the trie. Child indices point into the arena;
+/// zero means "no child", since the root is never a child.
+struct TrieNode {
+    children: [usize; 256],
+    terminal: bool,
+}
+
+impl TrieNode {
+    fn new() -> TrieNode {
+        TrieNode {
+            children: [0; 256],
+            terminal: false,
+        }
+    }
+}
+
+/// A set of byte strings supporting prefix queries.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::Trie;
+/// let mut trie = Trie::new();
+/// trie.insert(b"tea");
+/// trie.insert(b"ten");
+/// trie.insert(b"to");
+/// assert!(trie.contains(b"ten"));
+/// assert!(!trie.contains(b"te"));
+/// assert!(trie.starts_with(b"te"));
+/// let words: Vec<Vec<u8>> = trie.words_with_prefix(b"te").collect();
+/// assert_eq!(words, vec![b"tea".to_vec(), b"ten".to_vec()]);
+/// ```
+pub struct Trie {
+    nodes: Vec<TrieNode>,
+}
+
+impl Trie {
+    /// Return a new empty Trie.
+    pub fn new() -> Trie {
+        Trie {
+            nodes: vec![TrieNode::new()],
+        }
+    }
+
+    /// Add `key` to the trie. Adding a key twice has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the string to add.
+    ///
+    pub fn insert(&mut self, key: &[u8]) {
+        let mut node = 0;
+        for &c in key {
+            if self.nodes[node].children[c as usize] == 0 {
+                self.nodes[node].children[c as usize] = self.nodes.len();
+                self.nodes.push(TrieNode::new());
+            }
+            node = self.nodes[node].children[c as usize];
+        }
+        self.nodes[node].terminal = true;
+    }
+
+    /// Return `true` if `key` was inserted into the trie.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the string in question.
+    ///
+    pub fn contains(&self, key: &[u8]) -> bool {
+        self.find(key).is_some_and(|node| self.nodes[node].terminal)
+    }
+
+    /// Return `true` if some inserted key starts with `prefix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - the prefix in question.
+    ///
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        // Every node but the root lies on the path of some key.
+        self.find(prefix)
+            .is_some_and(|node| node != 0 || self.nodes.len() > 1 || self.nodes[0].terminal)
+    }
+
+    /// Return an iterator over all inserted keys that start with
+    /// `prefix`, in lexicographic order.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - the prefix in question.
+    ///
+    pub fn words_with_prefix<'a>(&'a self, prefix: &[u8]) -> WordsWithPrefix<'a> {
+        WordsWithPrefix {
+            trie: self,
+            stack: self
+                .find(prefix)
+                .map(|node| (node, prefix.to_vec()))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// Return the node reached by following `key` from the root.
+    fn find(&self, key: &[u8]) -> Option<usize> {
+        let mut node = 0;
+        for &c in key {
+            node = self.nodes[node].children[c as usize];
+            if node == 0 {
+                return None;
+            }
+        }
+        Some(node)
+    }
+}
+
+impl Default for Trie {
+    fn default() -> Trie {
+        Trie::new()
+    }
+}
+
+/// The iterator returned by `Trie::words_with_prefix`.
+pub struct WordsWithPrefix<'a> {
+    trie: &'a Trie,
+    stack: Vec<(usize, Vec<u8>)>,
+}
+
+impl<'a> Iterator for WordsWithPrefix<'a> {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Vec<u8>> {
+        while let Some((node, word)) = self.stack.pop() {
+            let children = &self.trie.nodes[node].children;
+            for c in (0..256).rev() {
+                if children[c] != 0 {
+                    let mut child_word = word.clone();
+                    child_word.push(c as u8);
+                    self.stack.push((children[c], child_word));
+                }
+            }
+            if self.trie.nodes[node].terminal {
+                return Some(word);
+            }
+        }
+        None
+    }
+}
+
+/// Return the longest common prefix of all `keys`
+/// (empty if there are no keys).
+///
+/// # Examples
+/// ```
+/// use ralgo::string::longest_common_prefix;
+/// assert_eq!(longest_common_prefix(&[b"flower", b"flow", b"flight"]), b"fl");
+/// ```
+pub fn longest_common_prefix(keys: &[&[u8]]) -> Vec<u8> {
+    let first = match keys.first() {
+        Some(first) => first,
+        None => return Vec::new(),
+    };
+    let len = keys[1..].iter().fold(first.len(), |len, key| {
+        first[..len]
+            .iter()
+            .zip(key.iter())
+            .take_while(|(a, b)| a == b)
+            .count()
+    });
+    first[..len].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{longest_common_prefix, Trie};
+
+    fn words(trie: &Trie, prefix: &[u8]) -> Vec<String> {
+        trie.words_with_prefix(prefix)
+            .map(|w| String::from_utf8(w).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn insert_and_query_work() {
+        let mut trie = Trie::new();
+        for word in ["car", "cart", "care", "cat", "dog", "car"].iter() {
+            trie.insert(word.as_bytes());
+        }
+        assert!(trie.contains(b"car"));
+        assert!(trie.contains(b"cart"));
+        assert!(!trie.contains(b"ca"));
+        assert!(!trie.contains(b"cars"));
+        assert!(trie.starts_with(b"ca"));
+        assert!(trie.starts_with(b""));
+        assert!(!trie.starts_with(b"cow"));
+
+        assert_eq!(words(&trie, b"car"), vec!["car", "care", "cart"]);
+        assert_eq!(words(&trie, b""), vec!["car", "care", "cart", "cat", "dog"]);
+        assert_eq!(words(&trie, b"x"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn empty_trie_works() {
+        let mut trie = Trie::new();
+        assert!(!trie.contains(b""));
+        assert!(!trie.contains(b"a"));
+        assert!(!trie.starts_with(b""));
+        assert!(!trie.starts_with(b"a"));
+        assert_eq!(trie.words_with_prefix(b"").count(), 0);
+
+        trie.insert(b"");
+        assert!(trie.contains(b""));
+        assert!(trie.starts_with(b""));
+        assert!(!trie.starts_with(b"a"));
+        assert_eq!(words(&trie, b""), vec![""]);
+    }
+
+    #[test]
+    fn single_characters_work() {
+        let mut trie = Trie::new();
+        for &c in b"zyxab" {
+            trie.insert(&[c]);
+        }
+        assert!(trie.contains(b"x"));
+        assert!(!trie.contains(b"c"));
+        assert!(trie.starts_with(b""));
+        assert_eq!(words(&trie, b""), vec!["a", "b", "x", "y", "z"]);
+        assert_eq!(words(&trie, b"y"), vec!["y"]);
+    }
+
+    #[test]
+    fn longest_common_prefix_works() {
+        assert_eq!(
+            longest_common_prefix(&[b"interview", b"internet", b"interval"]),
+            b"inter"
+        );
+        assert_eq!(longest_common_prefix(&[b"abc", b"xyz"]), b"");
+        assert_eq!(longest_common_prefix(&[b"abc", b"ab", b""]), b"");
+        assert_eq!(longest_common_prefix(&[b"same"]), b"same");
+        assert_eq!(longest_common_prefix(&[]), b"");
+    }
+}
@@ -0,0 +1,118 @@
+//! This module contains the Z-function of a string and
+//! substring search built on top of it.
+
+/// Return the Z-array of `s`: `z[i]` is the length of the longest
+/// common prefix of `s` and `s[i..]`. By convention `z[0] = s.len()`.
+///
+/// Runs in O(n) by reusing the rightmost window `s[l..r]` known
+/// to match a prefix of `s`.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::z_function;
+/// assert_eq!(z_function(b"aabxaa"), vec![6, 1, 0, 0, 2, 1]);
+/// ```
+pub fn z_function(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = z[i - l].min(r - i);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+    z
+}
+
+/// Return the starting positions of all (possibly overlapping)
+/// occurrences of `pattern` in `text` in increasing order. An empty
+/// pattern occurs at every position `0..=text.len()`.
+///
+/// Computes the Z-function of `pattern + [0] + text` in O(n + m).
+///
+/// # Arguments
+///
+/// * `text` - the text to search in;
+/// * `pattern` - the substring to look for.
+///
+/// # Examples
+/// ```
+/// use ralgo::string::z_search;
+/// assert_eq!(z_search(b"abababa", b"aba"), vec![0, 2, 4]);
+/// ```
+pub fn z_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let mut s = Vec::with_capacity(m + 1 + text.len());
+    s.extend_from_slice(pattern);
+    s.push(0);
+    s.extend_from_slice(text);
+
+    // The separator bounds matches unless it occurs in the pattern
+    // itself, so compare against the full pattern length.
+    z_function(&s)
+        .iter()
+        .skip(m + 1)
+        .enumerate()
+        .filter(|&(_, &len)| len >= m)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{z_function, z_search};
+    use graph::random::XorShift;
+
+    fn naive_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        (0..(text.len() + 1).saturating_sub(pattern.len()))
+            .filter(|&i| text[i..].starts_with(pattern))
+            .collect()
+    }
+
+    #[test]
+    fn known_z_arrays_work() {
+        assert_eq!(z_function(b"aabxaa"), vec![6, 1, 0, 0, 2, 1]);
+        assert_eq!(z_function(b"abacaba"), vec![7, 0, 1, 0, 3, 0, 1]);
+        assert_eq!(z_function(b""), vec![]);
+        assert_eq!(z_function(b"x"), vec![1]);
+    }
+
+    #[test]
+    fn identical_characters_work() {
+        assert_eq!(z_function(b"aaaaa"), vec![5, 4, 3, 2, 1]);
+        assert_eq!(z_search(b"aaaaa", b"aa"), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn search_matches_naive() {
+        assert_eq!(z_search(b"abc", b"abcd"), vec![]);
+        assert_eq!(z_search(b"abc", b"abc"), vec![0]);
+        assert_eq!(z_search(b"abc", b""), vec![0, 1, 2, 3]);
+        assert_eq!(z_search(b"a\0a\0a", b"a\0a"), vec![0, 2]);
+
+        let mut rng = XorShift::new(5);
+        for _ in 0..300 {
+            let text: Vec<u8> = (0..rng.next_below(40))
+                .map(|_| rng.next_below(3) as u8)
+                .collect();
+            let pattern: Vec<u8> = (0..1 + rng.next_below(4))
+                .map(|_| rng.next_below(3) as u8)
+                .collect();
+            assert_eq!(z_search(&text, &pattern), naive_search(&text, &pattern));
+        }
+    }
+}